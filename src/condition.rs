@@ -0,0 +1,96 @@
+//! Estimate the condition number of matrices and their factorizations.
+//!
+//! The condition number `κ(A) = ||A|| * ||A^-1||` of a square matrix
+//! `A` bounds how much the solution to `A * x = b` can change when
+//! `b` changes. Matrices with a large condition number are
+//! ill-conditioned, and solutions computed with them should not be
+//! trusted.
+//!
+//! LAPACK estimates the reciprocal condition number (`rcond`) `1 /
+//! κ(A)` in either the 1-norm or the infinity-norm, without forming
+//! the inverse. A reciprocal condition number near the machine
+//! epsilon indicates that `A` is singular to working precision.
+#![deny(missing_docs)]
+
+use impl_prelude::*;
+use lapack::c::{strcon, dtrcon, ctrcon, ztrcon};
+
+/// Norm used for estimating condition numbers.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConditionNorm {
+    /// 1-norm (maximum absolute column sum)
+    One = b'O',
+
+    /// Infinity-norm (maximum absolute row sum)
+    Infinity = b'I',
+}
+
+/// Error for condition number estimations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConditionError {
+    /// The layout of the matrix is not compatible
+    BadLayout,
+
+    /// The matrix is not square.
+    NotSquare,
+
+    /// Implementation error, please submit as bug.
+    IllegalParameter(i32),
+}
+
+/// Trait defined on scalars to support condition number estimation.
+///
+/// The condition numbers of LU and Cholesky factorizations are
+/// available through `LUFactors::rcond` and `CholeskyFactors::rcond`.
+pub trait Condition: LinxalImplScalar {
+    /// Return an estimate of the reciprocal condition number of a
+    /// triangular matrix.
+    ///
+    /// Only the triangular portion of the matrix specified by `uplo`
+    /// is read.
+    fn compute_triangular<D>(mat: &ArrayBase<D, Ix2>,
+                             uplo: Symmetric,
+                             norm: ConditionNorm)
+                             -> Result<Self::RealPart, ConditionError>
+        where D: Data<Elem = Self>;
+}
+
+macro_rules! impl_condition {
+    ($cond_type:ty, $tri_cond_func:ident) => (
+        impl Condition for $cond_type {
+            fn compute_triangular<D>(mat: &ArrayBase<D, Ix2>,
+                                     uplo: Symmetric,
+                                     norm: ConditionNorm)
+                                     -> Result<Self::RealPart, ConditionError>
+                where D: Data<Elem = Self> {
+                let dim = mat.dim();
+                if dim.0 != dim.1 {
+                    return Err(ConditionError::NotSquare);
+                }
+
+                let (slice, layout, lda) = match slice_and_layout(mat) {
+                    None => return Err(ConditionError::BadLayout),
+                    Some(x) => x,
+                };
+
+                let mut rcond = 0.0;
+                let info = unsafe {
+                    $tri_cond_func(layout, norm as u8, uplo as u8, b'N', dim.0 as i32,
+                                   slice, lda as i32, &mut rcond)
+                };
+
+                if info == 0 {
+                    Ok(rcond)
+                } else {
+                    Err(ConditionError::IllegalParameter(-info))
+                }
+            }
+        }
+    )
+}
+
+impl_condition!(f32, strcon);
+impl_condition!(f64, dtrcon);
+impl_condition!(c32, ctrcon);
+impl_condition!(c64, ztrcon);
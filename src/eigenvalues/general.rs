@@ -1,7 +1,8 @@
 //! Compute eigenvalues and eigenvectors of general, non-symmetric matrices.
-use lapack::c::{sgeev, dgeev, cgeev, zgeev};
+use lapack::c::{sgeev, dgeev, cgeev, zgeev, sgeevx, dgeevx, cgeevx, zgeevx};
 use impl_prelude::*;
-use super::types::{EigenError, Solution};
+use super::types::{EigenError, Solution, GeneralizedSolution, Balance, ExpertEigenSolution};
+use num_traits::Float;
 
 /// Scalar trait for computing eigenvalues.
 ///
@@ -13,6 +14,10 @@ pub trait Eigen: LinxalImplScalar {
     ///
     /// The entries in the input matrix `mat` are modified when
     /// calculating the eigenvalues.
+    ///
+    /// For real scalars, the eigenvectors of complex eigenvalues are
+    /// packed into real columns. Use `Solution::right_vectors_complex`
+    /// (or `left_vectors_complex`) to unpack them.
     fn compute_into<D>(mat: ArrayBase<D, Ix2>,
                        compute_left: bool,
                        compute_right: bool)
@@ -30,11 +35,132 @@ pub trait Eigen: LinxalImplScalar {
         let new_mat = Array::from_shape_vec(mat.dim(), vec).unwrap();
         Self::compute_into(new_mat, compute_left, compute_right)
     }
+
+    /// Return the eigenvalues, optionally the left and/or right
+    /// eigenvectors, and the reciprocal condition numbers of the
+    /// eigenvalues and right eigenvectors of a general matrix, after
+    /// balancing the matrix as specified by `balance`.
+    ///
+    /// The condition numbers require both the left and right
+    /// eigenvectors, so both are always computed, but only the
+    /// requested ones are returned.
+    ///
+    /// The entries in the input matrix `mat` are modified when
+    /// calculating the eigenvalues.
+    fn compute_expert_into<D>(mat: ArrayBase<D, Ix2>,
+                              balance: Balance,
+                              compute_left: bool,
+                              compute_right: bool)
+                              -> Result<ExpertEigenSolution<Self>, EigenError>
+        where D: DataOwned<Elem = Self> + DataMut<Elem = Self>;
+
+    /// Return the eigenvalues, optionally the eigenvectors, and the
+    /// condition numbers of a general matrix. (see
+    /// `Self::compute_expert_into`).
+    fn compute_expert<D>(mat: &ArrayBase<D, Ix2>,
+                         balance: Balance,
+                         compute_left: bool,
+                         compute_right: bool)
+                         -> Result<ExpertEigenSolution<Self>, EigenError>
+        where D: Data<Elem = Self>
+    {
+        Self::compute_expert_into(mat.to_owned(), balance, compute_left, compute_right)
+    }
+
+    /// Return the eigenvectors `vectors`, as computed alongside the
+    /// eigenvalues `values`, as complex vectors matched
+    /// column-for-column to `values`.
+    ///
+    /// For real scalars, LAPACK stores the eigenvectors of a complex
+    /// conjugate pair of eigenvalues in two consecutive real columns,
+    /// holding the real and imaginary parts of the eigenvector of the
+    /// eigenvalue with positive imaginary part. For complex scalars,
+    /// the vectors are returned unchanged.
+    fn complex_vectors<D1, D2>(values: &ArrayBase<D1, Ix1>,
+                               vectors: &ArrayBase<D2, Ix2>)
+                               -> Array<Self::Complex, Ix2>
+        where D1: Data<Elem = Self::Complex>,
+              D2: Data<Elem = Self>;
+}
+
+impl<T: Eigen> Solution<T, T::Complex> {
+    /// Return the left eigenvectors as complex vectors, matched
+    /// column-for-column to `values`.
+    ///
+    /// See `Eigen::complex_vectors`.
+    pub fn left_vectors_complex(&self) -> Option<Array<T::Complex, Ix2>> {
+        self.left_vectors.as_ref().map(|v| T::complex_vectors(&self.values, v))
+    }
+
+    /// Return the right eigenvectors as complex vectors, matched
+    /// column-for-column to `values`.
+    ///
+    /// See `Eigen::complex_vectors`.
+    pub fn right_vectors_complex(&self) -> Option<Array<T::Complex, Ix2>> {
+        self.right_vectors.as_ref().map(|v| T::complex_vectors(&self.values, v))
+    }
+}
+
+impl<T: Eigen> GeneralizedSolution<T, T::Complex> {
+    /// Return the left eigenvectors as complex vectors, matched
+    /// column-for-column to `alpha` and `beta`.
+    ///
+    /// See `Eigen::complex_vectors`.
+    pub fn left_vectors_complex(&self) -> Option<Array<T::Complex, Ix2>> {
+        self.left_vectors.as_ref().map(|v| T::complex_vectors(&self.alpha, v))
+    }
+
+    /// Return the right eigenvectors as complex vectors, matched
+    /// column-for-column to `alpha` and `beta`.
+    ///
+    /// See `Eigen::complex_vectors`.
+    pub fn right_vectors_complex(&self) -> Option<Array<T::Complex, Ix2>> {
+        self.right_vectors.as_ref().map(|v| T::complex_vectors(&self.alpha, v))
+    }
+}
+
+/// Return the solution of an empty (0 x 0) problem.
+fn empty_expert_solution<T: LinxalImplScalar>(compute_left: bool, compute_right: bool)
+                                              -> ExpertEigenSolution<T> {
+    let vectors = |compute| if compute { Some(Array::default((0, 0))) } else { None };
+    ExpertEigenSolution {
+        solution: Solution {
+            values: Array::default(0),
+            left_vectors: vectors(compute_left),
+            right_vectors: vectors(compute_right),
+        },
+        permutation: Vec::new(),
+        scale: Array::default(0),
+        ilo: 0,
+        ihi: 0,
+        norm: T::RealPart::zero(),
+        rcond_values: Array::default(0),
+        rcond_vectors: Array::default(0),
+    }
+}
+
+/// Split the `scale` output of `?gebal` into the balancing
+/// permutation (0-based) and the scale factors, given the 0-based
+/// range `ilo..ihi` of rows and columns that were not isolated.
+fn split_balancing<F: Float>(scale: Vec<F>, ilo: usize, ihi: usize) -> (Vec<usize>, Array<F, Ix1>) {
+    let mut permutation = Vec::with_capacity(scale.len());
+    let mut factors = Vec::with_capacity(scale.len());
+    for (j, s) in scale.into_iter().enumerate() {
+        if j >= ilo && j < ihi {
+            permutation.push(j);
+            factors.push(s);
+        } else {
+            let p = s.to_usize().expect("Invalid balancing permutation from LAPACK. Please report.");
+            permutation.push(p - 1);
+            factors.push(F::one());
+        }
+    }
+    (permutation, ArrayBase::from_vec(factors))
 }
 
 /// Macro for implementing the Eigen trait on real-valued matrices.
 macro_rules! impl_eigen_real {
-    ($impl_type:ident, $eigv_type:ident, $func:ident)  => (
+    ($impl_type:ident, $eigv_type:ident, $func:ident, $expert_func:ident)  => (
         impl Eigen for $impl_type {
             fn compute_into<D>(mut mat: ArrayBase<D, Ix2>,
                                compute_left: bool, compute_right: bool) ->
@@ -81,16 +207,112 @@ macro_rules! impl_eigen_real {
                     Err(EigenError::Failed)
                 }
             }
+
+            fn compute_expert_into<D>(mut mat: ArrayBase<D, Ix2>,
+                                      balance: Balance,
+                                      compute_left: bool,
+                                      compute_right: bool)
+                                      -> Result<ExpertEigenSolution<Self>, EigenError>
+                where D: DataMut<Elem=Self> + DataOwned<Elem=Self> {
+
+                let dim = mat.dim();
+                if dim.0 != dim.1 {
+                    return Err(EigenError::NotSquare);
+                }
+                let n = dim.0;
+                if n == 0 {
+                    return Ok(empty_expert_solution(compute_left, compute_right));
+                }
+
+                let (data_slice, layout, ld) = match slice_and_layout_mut(&mut mat) {
+                    Some(s) => s,
+                    None => return Err(EigenError::BadLayout)
+                };
+
+                let mut vl = matrix_with_layout(dim, layout);
+                let mut vr = matrix_with_layout(dim, layout);
+
+                let mut values_real = vec![0.0; n];
+                let mut values_imag = vec![0.0; n];
+                let (mut ilo, mut ihi) = (0, 0);
+                let mut scale = vec![0.0; n];
+                let mut norm = 0.0;
+                let mut rcond_values = vec![0.0; n];
+                let mut rcond_vectors = vec![0.0; n];
+
+                let info = unsafe {
+                    $expert_func(layout, balance as u8, b'V', b'V', b'B', n as i32,
+                                 data_slice, ld as i32, &mut values_real, &mut values_imag,
+                                 vl.as_slice_mut().unwrap(), n as i32,
+                                 vr.as_slice_mut().unwrap(), n as i32,
+                                 &mut ilo, &mut ihi, &mut scale, &mut norm,
+                                 &mut rcond_values, &mut rcond_vectors)
+                };
+
+                if info == 0 {
+                    let (ilo, ihi) = (ilo as usize - 1, ihi as usize);
+                    let (permutation, scale) = split_balancing(scale, ilo, ihi);
+                    let vals: Vec<_> = values_real.iter().zip(values_imag.iter())
+                        .map(|(x, y)| Self::Complex::new(*x, *y)).collect();
+                    Ok(ExpertEigenSolution {
+                        solution: Solution {
+                            values: ArrayBase::from_vec(vals),
+                            left_vectors: if compute_left { Some(vl) } else { None },
+                            right_vectors: if compute_right { Some(vr) } else { None }
+                        },
+                        permutation: permutation,
+                        scale: scale,
+                        ilo: ilo,
+                        ihi: ihi,
+                        norm: norm,
+                        rcond_values: ArrayBase::from_vec(rcond_values),
+                        rcond_vectors: ArrayBase::from_vec(rcond_vectors),
+                    })
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    Err(EigenError::Failed)
+                }
+            }
+
+            fn complex_vectors<D1, D2>(values: &ArrayBase<D1, Ix1>,
+                                       vectors: &ArrayBase<D2, Ix2>)
+                                       -> Array<Self::Complex, Ix2>
+                where D1: Data<Elem = Self::Complex>,
+                      D2: Data<Elem = Self> {
+                let (n, k) = vectors.dim();
+                let mut cv = Array::default((n, k));
+
+                let mut j = 0;
+                while j < k {
+                    if values[j].im == 0.0 || j + 1 == k {
+                        for i in 0..n {
+                            cv[(i, j)] = $eigv_type::new(vectors[(i, j)], 0.0);
+                        }
+                        j += 1;
+                    } else {
+                        // The conjugate pair shares the two columns.
+                        for i in 0..n {
+                            let v = $eigv_type::new(vectors[(i, j)], vectors[(i, j + 1)]);
+                            cv[(i, j)] = v;
+                            cv[(i, j + 1)] = v.conj();
+                        }
+                        j += 2;
+                    }
+                }
+
+                cv
+            }
         }
     )
 }
 
-impl_eigen_real!(f32, c32, sgeev);
-impl_eigen_real!(f64, c64, dgeev);
+impl_eigen_real!(f32, c32, sgeev, sgeevx);
+impl_eigen_real!(f64, c64, dgeev, dgeevx);
 
 /// Macro for implementing the Eigen trait on complex-valued matrices.
 macro_rules! impl_eigen_complex {
-    ($impl_type:ident, $func:ident)  => (
+    ($impl_type:ident, $func:ident, $expert_func:ident)  => (
         impl Eigen for $impl_type {
 
             fn compute_into<D>(mut mat: ArrayBase<D, Ix2>,
@@ -137,12 +359,84 @@ macro_rules! impl_eigen_complex {
                     Err(EigenError::Failed)
                 }
             }
+
+            fn compute_expert_into<D>(mut mat: ArrayBase<D, Ix2>,
+                                      balance: Balance,
+                                      compute_left: bool,
+                                      compute_right: bool)
+                                      -> Result<ExpertEigenSolution<Self>, EigenError>
+                where D: DataMut<Elem=Self> + DataOwned<Elem=Self> {
+
+                let dim = mat.dim();
+                if dim.0 != dim.1 {
+                    return Err(EigenError::NotSquare);
+                }
+                let n = dim.0;
+                if n == 0 {
+                    return Ok(empty_expert_solution(compute_left, compute_right));
+                }
+
+                let (data_slice, layout, ld) = match slice_and_layout_mut(&mut mat) {
+                    Some(s) => s,
+                    None => return Err(EigenError::BadLayout)
+                };
+
+                let mut vl = matrix_with_layout(dim, layout);
+                let mut vr = matrix_with_layout(dim, layout);
+
+                let mut values = Array::default(n);
+                let (mut ilo, mut ihi) = (0, 0);
+                let mut scale = vec![0.0; n];
+                let mut norm = 0.0;
+                let mut rcond_values = vec![0.0; n];
+                let mut rcond_vectors = vec![0.0; n];
+
+                let info = unsafe {
+                    $expert_func(layout, balance as u8, b'V', b'V', b'B', n as i32,
+                                 data_slice, ld as i32, values.as_slice_mut().unwrap(),
+                                 vl.as_slice_mut().unwrap(), n as i32,
+                                 vr.as_slice_mut().unwrap(), n as i32,
+                                 &mut ilo, &mut ihi, &mut scale, &mut norm,
+                                 &mut rcond_values, &mut rcond_vectors)
+                };
+
+                if info == 0 {
+                    let (ilo, ihi) = (ilo as usize - 1, ihi as usize);
+                    let (permutation, scale) = split_balancing(scale, ilo, ihi);
+                    Ok(ExpertEigenSolution {
+                        solution: Solution {
+                            values: values,
+                            left_vectors: if compute_left { Some(vl) } else { None },
+                            right_vectors: if compute_right { Some(vr) } else { None }
+                        },
+                        permutation: permutation,
+                        scale: scale,
+                        ilo: ilo,
+                        ihi: ihi,
+                        norm: norm,
+                        rcond_values: ArrayBase::from_vec(rcond_values),
+                        rcond_vectors: ArrayBase::from_vec(rcond_vectors),
+                    })
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    Err(EigenError::Failed)
+                }
+            }
+
+            fn complex_vectors<D1, D2>(_: &ArrayBase<D1, Ix1>,
+                                       vectors: &ArrayBase<D2, Ix2>)
+                                       -> Array<Self::Complex, Ix2>
+                where D1: Data<Elem = Self::Complex>,
+                      D2: Data<Elem = Self> {
+                vectors.to_owned()
+            }
         }
     )
 }
 
-impl_eigen_complex!(c32, cgeev);
-impl_eigen_complex!(c64, zgeev);
+impl_eigen_complex!(c32, cgeev, cgeevx);
+impl_eigen_complex!(c64, zgeev, zgeevx);
 
 #[cfg(test)]
 mod tests {}
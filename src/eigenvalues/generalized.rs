@@ -0,0 +1,218 @@
+//! Compute eigenvalues and eigenvectors of the generalized
+//! eigenvalue problem for general, non-symmetric matrices.
+//!
+//! The generalized eigenvalue problem is to find solutions
+//! $\left(x, \lambda\right)$ to the problem:
+//!
+//! $$A \cdot x = \lambda \cdot B \cdot x$$
+//!
+//! for square matrices \\(A\\) and \\(B\\). When \\(B\\) is singular,
+//! some of the eigenvalues may be infinite, so each eigenvalue is
+//! returned as a pair `(alpha, beta)` with \\(\lambda = \alpha /
+//! \beta\\).
+
+use lapack::c::{sggev, dggev, cggev, zggev};
+use impl_prelude::*;
+use super::types::{EigenError, GeneralizedSolution};
+
+/// Scalar trait for computing generalized eigenvalues.
+pub trait GeneralizedEigen: LinxalImplScalar {
+    /// Return the generalized eigenvalues and, optionally, the left
+    /// and/or right eigenvectors of the pair of general matrices `a`
+    /// and `b`.
+    ///
+    /// The entries in the input matrices are modified when
+    /// calculating the eigenvalues. The layouts of `a` and `b` must
+    /// match.
+    ///
+    /// For real scalars, the eigenvectors are stored in the same
+    /// packed format as `Eigen::compute_into`: a complex conjugate
+    /// pair of eigenvalues occupies two consecutive columns, holding
+    /// the real and imaginary parts of the eigenvector of the first
+    /// eigenvalue of the pair. Use
+    /// `GeneralizedSolution::right_vectors_complex` (or
+    /// `left_vectors_complex`) to unpack them.
+    fn compute_into<D1, D2>(a: ArrayBase<D1, Ix2>,
+                            b: ArrayBase<D2, Ix2>,
+                            compute_left: bool,
+                            compute_right: bool)
+                            -> Result<GeneralizedSolution<Self, Self::Complex>, EigenError>
+        where D1: DataOwned<Elem = Self> + DataMut<Elem = Self>,
+              D2: DataOwned<Elem = Self> + DataMut<Elem = Self>;
+
+    /// Return the generalized eigenvalues and, optionally, the
+    /// eigenvectors of the pair of general matrices `a` and `b`.
+    fn compute<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                       b: &ArrayBase<D2, Ix2>,
+                       compute_left: bool,
+                       compute_right: bool)
+                       -> Result<GeneralizedSolution<Self, Self::Complex>, EigenError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>
+    {
+        let new_a = a.to_owned();
+        let new_b = copy_with_matching_layout(b, &new_a);
+        Self::compute_into(new_a, new_b, compute_left, compute_right)
+    }
+}
+
+/// Return the solution of an empty (0 x 0) problem.
+fn empty_solution<T: LinxalImplScalar>(compute_left: bool, compute_right: bool)
+                                       -> GeneralizedSolution<T, T::Complex> {
+    let vectors = |compute| if compute { Some(Array::default((0, 0))) } else { None };
+    GeneralizedSolution {
+        alpha: Array::default(0),
+        beta: Array::default(0),
+        left_vectors: vectors(compute_left),
+        right_vectors: vectors(compute_right),
+    }
+}
+
+/// Macro for implementing the GeneralizedEigen trait on real-valued
+/// matrices.
+macro_rules! impl_gen_eigen_real {
+    ($impl_type:ident, $func:ident) => (
+        impl GeneralizedEigen for $impl_type {
+            fn compute_into<D1, D2>(mut a: ArrayBase<D1, Ix2>,
+                                    mut b: ArrayBase<D2, Ix2>,
+                                    compute_left: bool, compute_right: bool)
+                                    -> Result<GeneralizedSolution<Self, Self::Complex>, EigenError>
+                where D1: DataOwned<Elem = Self> + DataMut<Elem = Self>,
+                      D2: DataOwned<Elem = Self> + DataMut<Elem = Self> {
+
+                let dim = a.dim();
+                if dim.0 != dim.1 {
+                    return Err(EigenError::NotSquare);
+                }
+                if b.dim() != dim {
+                    return Err(EigenError::InconsistentDimensions);
+                }
+                let n = dim.0;
+                if n == 0 {
+                    return Ok(empty_solution(compute_left, compute_right));
+                }
+
+                let (a_slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                    Some(s) => s,
+                    None => return Err(EigenError::BadLayout)
+                };
+                let (b_slice, ldb) = match slice_and_layout_matching_mut(&mut b, layout) {
+                    Some(s) => s,
+                    None => return Err(EigenError::InconsistentLayout)
+                };
+
+                // The LAPACKE bindings require non-empty outputs, even
+                // when they are not referenced.
+                let mut vl = matrix_with_layout(if compute_left { dim } else { (1, 1) }, layout);
+                let mut vr = matrix_with_layout(if compute_right { dim } else { (1, 1) }, layout);
+
+                let mut alpha_real = vec![0.0; n];
+                let mut alpha_imag = vec![0.0; n];
+                let mut beta = vec![0.0; n];
+
+                let vl_opt = if compute_left { b'V' } else { b'N' };
+                let vr_opt = if compute_right { b'V' } else { b'N' };
+                let ldv = n as i32;
+
+                let info = unsafe {
+                    $func(layout, vl_opt, vr_opt, n as i32,
+                          a_slice, lda as i32, b_slice, ldb as i32,
+                          &mut alpha_real[0], &mut alpha_imag[0], &mut beta[0],
+                          &mut vl.as_slice_mut().unwrap()[0], ldv,
+                          &mut vr.as_slice_mut().unwrap()[0], ldv)
+                };
+
+                if info == 0 {
+                    let alpha: Vec<_> = alpha_real.iter().zip(alpha_imag.iter())
+                        .map(|(x, y)| Self::Complex::new(*x, *y)).collect();
+                    let beta: Vec<_> = beta.iter()
+                        .map(|x| Self::Complex::new(*x, 0.0)).collect();
+                    Ok(GeneralizedSolution {
+                        alpha: ArrayBase::from_vec(alpha),
+                        beta: ArrayBase::from_vec(beta),
+                        left_vectors: if compute_left { Some(vl) } else { None },
+                        right_vectors: if compute_right { Some(vr) } else { None }})
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    Err(EigenError::Failed)
+                }
+            }
+        }
+    )
+}
+
+impl_gen_eigen_real!(f32, sggev);
+impl_gen_eigen_real!(f64, dggev);
+
+/// Macro for implementing the GeneralizedEigen trait on
+/// complex-valued matrices.
+macro_rules! impl_gen_eigen_complex {
+    ($impl_type:ident, $func:ident) => (
+        impl GeneralizedEigen for $impl_type {
+            fn compute_into<D1, D2>(mut a: ArrayBase<D1, Ix2>,
+                                    mut b: ArrayBase<D2, Ix2>,
+                                    compute_left: bool, compute_right: bool)
+                                    -> Result<GeneralizedSolution<Self, Self::Complex>, EigenError>
+                where D1: DataOwned<Elem = Self> + DataMut<Elem = Self>,
+                      D2: DataOwned<Elem = Self> + DataMut<Elem = Self> {
+
+                let dim = a.dim();
+                if dim.0 != dim.1 {
+                    return Err(EigenError::NotSquare);
+                }
+                if b.dim() != dim {
+                    return Err(EigenError::InconsistentDimensions);
+                }
+                let n = dim.0;
+                if n == 0 {
+                    return Ok(empty_solution(compute_left, compute_right));
+                }
+
+                let (a_slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                    Some(s) => s,
+                    None => return Err(EigenError::BadLayout)
+                };
+                let (b_slice, ldb) = match slice_and_layout_matching_mut(&mut b, layout) {
+                    Some(s) => s,
+                    None => return Err(EigenError::InconsistentLayout)
+                };
+
+                // The LAPACKE bindings require non-empty outputs, even
+                // when they are not referenced.
+                let mut vl = matrix_with_layout(if compute_left { dim } else { (1, 1) }, layout);
+                let mut vr = matrix_with_layout(if compute_right { dim } else { (1, 1) }, layout);
+
+                let mut alpha = vec![Self::zero(); n];
+                let mut beta = vec![Self::zero(); n];
+
+                let vl_opt = if compute_left { b'V' } else { b'N' };
+                let vr_opt = if compute_right { b'V' } else { b'N' };
+                let ldv = n as i32;
+
+                let info = unsafe {
+                    $func(layout, vl_opt, vr_opt, n as i32,
+                          a_slice, lda as i32, b_slice, ldb as i32,
+                          &mut alpha[0], &mut beta[0],
+                          &mut vl.as_slice_mut().unwrap()[0], ldv,
+                          &mut vr.as_slice_mut().unwrap()[0], ldv)
+                };
+
+                if info == 0 {
+                    Ok(GeneralizedSolution {
+                        alpha: ArrayBase::from_vec(alpha),
+                        beta: ArrayBase::from_vec(beta),
+                        left_vectors: if compute_left { Some(vl) } else { None },
+                        right_vectors: if compute_right { Some(vr) } else { None }})
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    Err(EigenError::Failed)
+                }
+            }
+        }
+    )
+}
+
+impl_gen_eigen_complex!(c32, cggev);
+impl_gen_eigen_complex!(c64, zggev);
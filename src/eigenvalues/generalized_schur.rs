@@ -0,0 +1,415 @@
+//! Compute the generalized Schur decomposition of a pair of square
+//! matrices.
+//!
+//! The pair `(A, B)` is factored into the products `A = Q * S * Z^H`
+//! and `B = Q * T * Z^H`, such that
+//!
+//! - `Q` and `Z` are unitary (orthogonal, for real matrices).
+//!
+//! - `T` is upper triangular, and `S` is upper triangular for complex
+//! matrices. For real matrices, `S` is upper quasi-triangular, with 1
+//! x 1 blocks for real eigenvalues and 2 x 2 blocks for complex
+//! conjugate pairs of eigenvalues.
+//!
+//! The generalized eigenvalues `alpha / beta` of the pencil `A -
+//! lambda * B` are the ratios of the diagonal (blocks) of `S` and
+//! `T`. An eigenvalue is infinite when `beta` is zero, which happens
+//! when `B` is singular. The leading `k` columns of `Z` span a
+//! deflating subspace of the pencil for the `k` leading eigenvalues,
+//! which can be chosen by reordering the decomposition.
+
+use lapack::c::{sgges, dgges, cgges, zgges, stgsen, dtgsen, ctgsen, ztgsen};
+use impl_prelude::*;
+use util::external::conj_t;
+use super::types::EigenError;
+
+/// Representation of the generalized Schur decomposition `A = Q * S *
+/// Z^H`, `B = Q * T * Z^H` of a pair of square matrices.
+#[derive(Debug, Clone)]
+pub struct GeneralizedSchurFactors<T: GeneralizedSchur> {
+    s: Array<T, Ix2>,
+    t: Array<T, Ix2>,
+    q: Array<T, Ix2>,
+    z: Array<T, Ix2>,
+    alpha: Array<T::Complex, Ix1>,
+    beta: Array<T::Complex, Ix1>,
+}
+
+impl<T: GeneralizedSchur> GeneralizedSchurFactors<T> {
+    /// Return the (quasi-)triangular Schur form `S` of `A`.
+    pub fn s(&self) -> &Array<T, Ix2> {
+        &self.s
+    }
+
+    /// Return the triangular Schur form `T` of `B`.
+    pub fn t(&self) -> &Array<T, Ix2> {
+        &self.t
+    }
+
+    /// Return the unitary matrix of left Schur vectors `Q`.
+    pub fn q(&self) -> &Array<T, Ix2> {
+        &self.q
+    }
+
+    /// Return the unitary matrix of right Schur vectors `Z`.
+    pub fn z(&self) -> &Array<T, Ix2> {
+        &self.z
+    }
+
+    /// Return the numerators of the generalized eigenvalues, in the
+    /// order in which they appear on the diagonal of `S`.
+    pub fn alpha(&self) -> &Array<T::Complex, Ix1> {
+        &self.alpha
+    }
+
+    /// Return the denominators of the generalized eigenvalues, in the
+    /// order in which they appear on the diagonal of `T`.
+    pub fn beta(&self) -> &Array<T::Complex, Ix1> {
+        &self.beta
+    }
+
+    /// Return the factors `S`, `T`, `Q` and `Z`, consuming the
+    /// decomposition.
+    pub fn into_parts(self) -> (Array<T, Ix2>, Array<T, Ix2>, Array<T, Ix2>, Array<T, Ix2>) {
+        (self.s, self.t, self.q, self.z)
+    }
+
+    /// Reconstruct the original matrices `A` and `B` from the
+    /// decomposition.
+    pub fn reconstruct(&self) -> (Array<T, Ix2>, Array<T, Ix2>) {
+        let z_h = conj_t(&self.z);
+        (self.q.dot(&self.s).dot(&z_h), self.q.dot(&self.t).dot(&z_h))
+    }
+
+    /// Reorder the decomposition so that the eigenvalues for which
+    /// `select(alpha, beta)` returns true appear in the leading
+    /// diagonal blocks of `S` and `T`, and return the number `k` of
+    /// such eigenvalues.
+    ///
+    /// Afterwards, the first `k` columns of `Z` form an orthonormal
+    /// basis for the right deflating subspace of the pencil
+    /// corresponding to the selected eigenvalues.
+    ///
+    /// For real matrices, a complex conjugate pair of eigenvalues is
+    /// selected if either of the two is selected.
+    pub fn reorder<F>(&mut self, select: F) -> Result<usize, EigenError>
+        where F: Fn(T::Complex, T::Complex) -> bool
+    {
+        let selected: Vec<i32> = self.alpha.iter().zip(self.beta.iter())
+            .map(|(&a, &b)| select(a, b) as i32).collect();
+        let (k, alpha, beta) = try!(T::compute_reorder_mut(&mut self.s, &mut self.t,
+                                                           &mut self.q, &mut self.z, &selected));
+        self.alpha = alpha;
+        self.beta = beta;
+        Ok(k)
+    }
+}
+
+/// Trait defined on scalars to support the generalized Schur
+/// decomposition.
+pub trait GeneralizedSchur: LinxalImplScalar {
+    /// Return the generalized Schur decomposition of the square
+    /// matrices `A` and `B`, consuming the inputs.
+    ///
+    /// The layouts of `A` and `B` must match.
+    fn compute_into(a: Array<Self, Ix2>, b: Array<Self, Ix2>)
+                    -> Result<GeneralizedSchurFactors<Self>, EigenError>;
+
+    /// Return the generalized Schur decomposition of the square
+    /// matrices `A` and `B`.
+    fn compute<D1, D2>(a: &ArrayBase<D1, Ix2>, b: &ArrayBase<D2, Ix2>)
+                       -> Result<GeneralizedSchurFactors<Self>, EigenError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>
+    {
+        let a = a.to_owned();
+        let b = copy_with_matching_layout(b, &a);
+        Self::compute_into(a, b)
+    }
+
+    /// Reorder the generalized Schur decomposition in place, so that
+    /// the eigenvalues for which `select` is non-zero lead the
+    /// diagonals of `s` and `t`. Return the number of selected
+    /// eigenvalues and the reordered `alpha` and `beta`.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_reorder_mut(s: &mut Array<Self, Ix2>,
+                           t: &mut Array<Self, Ix2>,
+                           q: &mut Array<Self, Ix2>,
+                           z: &mut Array<Self, Ix2>,
+                           select: &[i32])
+                           -> Result<(usize, Array<Self::Complex, Ix1>, Array<Self::Complex, Ix1>),
+                                     EigenError>;
+}
+
+/// Return the decomposition of an empty (0 x 0) pair.
+fn empty_factors<T: GeneralizedSchur>() -> GeneralizedSchurFactors<T> {
+    GeneralizedSchurFactors {
+        s: Array::default((0, 0)),
+        t: Array::default((0, 0)),
+        q: Array::default((0, 0)),
+        z: Array::default((0, 0)),
+        alpha: Array::default(0),
+        beta: Array::default(0),
+    }
+}
+
+/// Check the dimensions of the factors passed to
+/// `GeneralizedSchur::compute_reorder_mut`, and return `n`.
+fn reorder_dim<T>(s: &Array<T, Ix2>, t: &Array<T, Ix2>, q: &Array<T, Ix2>, z: &Array<T, Ix2>,
+                  select: &[i32]) -> Result<usize, EigenError> {
+    let dim = s.dim();
+    if dim.0 != dim.1 {
+        return Err(EigenError::NotSquare);
+    }
+    if t.dim() != dim || q.dim() != dim || z.dim() != dim || select.len() != dim.0 {
+        return Err(EigenError::InconsistentDimensions);
+    }
+    Ok(dim.0)
+}
+
+macro_rules! impl_gen_schur_real {
+    ($impl_type:ident, $eigv_type:ident, $schur_func:ident, $reorder_func:ident) => (
+        impl GeneralizedSchur for $impl_type {
+            fn compute_into(mut a: Array<Self, Ix2>, mut b: Array<Self, Ix2>)
+                            -> Result<GeneralizedSchurFactors<Self>, EigenError> {
+                let dim = a.dim();
+                if dim.0 != dim.1 {
+                    return Err(EigenError::NotSquare);
+                }
+                if b.dim() != dim {
+                    return Err(EigenError::InconsistentDimensions);
+                }
+                let n = dim.0;
+                if n == 0 {
+                    return Ok(empty_factors());
+                }
+
+                let mut alpha_real = vec![0.0; n];
+                let mut alpha_imag = vec![0.0; n];
+                let mut beta = vec![0.0; n];
+                let mut sdim = 0;
+
+                let (q, z, info) = {
+                    let (a_slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                        Some(x) => x,
+                        None => return Err(EigenError::BadLayout),
+                    };
+                    let (b_slice, ldb) = match slice_and_layout_matching_mut(&mut b, layout) {
+                        Some(x) => x,
+                        None => return Err(EigenError::InconsistentLayout),
+                    };
+                    let mut q = matrix_with_layout((n, n), layout);
+                    let mut z = matrix_with_layout((n, n), layout);
+
+                    let info = unsafe {
+                        $schur_func(layout, b'V', b'V', b'N', None, n as i32,
+                                    a_slice, lda as i32, b_slice, ldb as i32, &mut sdim,
+                                    &mut alpha_real[0], &mut alpha_imag[0], &mut beta[0],
+                                    q.as_slice_mut().unwrap(), n as i32,
+                                    z.as_slice_mut().unwrap(), n as i32)
+                    };
+                    (q, z, info)
+                };
+
+                if info == 0 {
+                    let alpha = alpha_real.iter().zip(alpha_imag.iter())
+                        .map(|(&x, &y)| $eigv_type::new(x, y)).collect();
+                    let beta = beta.iter().map(|&x| $eigv_type::new(x, 0.0)).collect();
+                    Ok(GeneralizedSchurFactors {
+                        s: a,
+                        t: b,
+                        q: q,
+                        z: z,
+                        alpha: ArrayBase::from_vec(alpha),
+                        beta: ArrayBase::from_vec(beta),
+                    })
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    Err(EigenError::Failed)
+                }
+            }
+
+            fn compute_reorder_mut(s: &mut Array<Self, Ix2>,
+                                   t: &mut Array<Self, Ix2>,
+                                   q: &mut Array<Self, Ix2>,
+                                   z: &mut Array<Self, Ix2>,
+                                   select: &[i32])
+                                   -> Result<(usize, Array<Self::Complex, Ix1>,
+                                              Array<Self::Complex, Ix1>), EigenError> {
+                let n = try!(reorder_dim(s, t, q, z, select));
+                if n == 0 {
+                    return Ok((0, Array::default(0), Array::default(0)));
+                }
+
+                let mut alpha_real = vec![0.0; n];
+                let mut alpha_imag = vec![0.0; n];
+                let mut beta = vec![0.0; n];
+                let mut m = 0;
+                let (mut pl, mut pr, mut dif) = ([0.0], [0.0], [0.0; 2]);
+
+                let info = {
+                    let (s_slice, layout, lds) = match slice_and_layout_mut(s) {
+                        Some(x) => x,
+                        None => return Err(EigenError::BadLayout),
+                    };
+                    let (t_slice, ldt) = match slice_and_layout_matching_mut(t, layout) {
+                        Some(x) => x,
+                        None => return Err(EigenError::InconsistentLayout),
+                    };
+                    let (q_slice, ldq) = match slice_and_layout_matching_mut(q, layout) {
+                        Some(x) => x,
+                        None => return Err(EigenError::InconsistentLayout),
+                    };
+                    let (z_slice, ldz) = match slice_and_layout_matching_mut(z, layout) {
+                        Some(x) => x,
+                        None => return Err(EigenError::InconsistentLayout),
+                    };
+
+                    unsafe {
+                        $reorder_func(layout, 0, 1, 1, select, n as i32,
+                                      s_slice, lds as i32, t_slice, ldt as i32,
+                                      &mut alpha_real[0], &mut alpha_imag[0], &mut beta[0],
+                                      &mut q_slice[0], ldq as i32, z_slice, ldz as i32,
+                                      &mut m, &mut pl, &mut pr, &mut dif[0])
+                    }
+                };
+
+                if info == 0 {
+                    let alpha = alpha_real.iter().zip(alpha_imag.iter())
+                        .map(|(&x, &y)| $eigv_type::new(x, y)).collect();
+                    let beta = beta.iter().map(|&x| $eigv_type::new(x, 0.0)).collect();
+                    Ok((m as usize, ArrayBase::from_vec(alpha), ArrayBase::from_vec(beta)))
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    // The eigenvalues were too close to be swapped.
+                    Err(EigenError::Failed)
+                }
+            }
+        }
+    )
+}
+
+impl_gen_schur_real!(f32, c32, sgges, stgsen);
+impl_gen_schur_real!(f64, c64, dgges, dtgsen);
+
+macro_rules! impl_gen_schur_complex {
+    ($impl_type:ident, $schur_func:ident, $reorder_func:ident) => (
+        impl GeneralizedSchur for $impl_type {
+            fn compute_into(mut a: Array<Self, Ix2>, mut b: Array<Self, Ix2>)
+                            -> Result<GeneralizedSchurFactors<Self>, EigenError> {
+                let dim = a.dim();
+                if dim.0 != dim.1 {
+                    return Err(EigenError::NotSquare);
+                }
+                if b.dim() != dim {
+                    return Err(EigenError::InconsistentDimensions);
+                }
+                let n = dim.0;
+                if n == 0 {
+                    return Ok(empty_factors());
+                }
+
+                let mut alpha = vec![Self::zero(); n];
+                let mut beta = vec![Self::zero(); n];
+                let mut sdim = 0;
+
+                let (q, z, info) = {
+                    let (a_slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                        Some(x) => x,
+                        None => return Err(EigenError::BadLayout),
+                    };
+                    let (b_slice, ldb) = match slice_and_layout_matching_mut(&mut b, layout) {
+                        Some(x) => x,
+                        None => return Err(EigenError::InconsistentLayout),
+                    };
+                    let mut q = matrix_with_layout((n, n), layout);
+                    let mut z = matrix_with_layout((n, n), layout);
+
+                    let info = unsafe {
+                        $schur_func(layout, b'V', b'V', b'N', None, n as i32,
+                                    a_slice, lda as i32, b_slice, ldb as i32, &mut sdim,
+                                    &mut alpha[0], &mut beta[0],
+                                    q.as_slice_mut().unwrap(), n as i32,
+                                    z.as_slice_mut().unwrap(), n as i32)
+                    };
+                    (q, z, info)
+                };
+
+                if info == 0 {
+                    Ok(GeneralizedSchurFactors {
+                        s: a,
+                        t: b,
+                        q: q,
+                        z: z,
+                        alpha: ArrayBase::from_vec(alpha),
+                        beta: ArrayBase::from_vec(beta),
+                    })
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    Err(EigenError::Failed)
+                }
+            }
+
+            fn compute_reorder_mut(s: &mut Array<Self, Ix2>,
+                                   t: &mut Array<Self, Ix2>,
+                                   q: &mut Array<Self, Ix2>,
+                                   z: &mut Array<Self, Ix2>,
+                                   select: &[i32])
+                                   -> Result<(usize, Array<Self::Complex, Ix1>,
+                                              Array<Self::Complex, Ix1>), EigenError> {
+                let n = try!(reorder_dim(s, t, q, z, select));
+                if n == 0 {
+                    return Ok((0, Array::default(0), Array::default(0)));
+                }
+
+                let mut alpha = vec![Self::zero(); n];
+                let mut beta = vec![Self::zero(); n];
+                let mut m = 0;
+                let (mut pl, mut pr, mut dif) = ([0.0], [0.0], [0.0; 2]);
+
+                let info = {
+                    let (s_slice, layout, lds) = match slice_and_layout_mut(s) {
+                        Some(x) => x,
+                        None => return Err(EigenError::BadLayout),
+                    };
+                    let (t_slice, ldt) = match slice_and_layout_matching_mut(t, layout) {
+                        Some(x) => x,
+                        None => return Err(EigenError::InconsistentLayout),
+                    };
+                    let (q_slice, ldq) = match slice_and_layout_matching_mut(q, layout) {
+                        Some(x) => x,
+                        None => return Err(EigenError::InconsistentLayout),
+                    };
+                    let (z_slice, ldz) = match slice_and_layout_matching_mut(z, layout) {
+                        Some(x) => x,
+                        None => return Err(EigenError::InconsistentLayout),
+                    };
+
+                    unsafe {
+                        $reorder_func(layout, 0, 1, 1, select, n as i32,
+                                      s_slice, lds as i32, t_slice, ldt as i32,
+                                      &mut alpha[0], &mut beta[0],
+                                      &mut q_slice[0], ldq as i32, z_slice, ldz as i32,
+                                      &mut m, &mut pl, &mut pr, &mut dif[0])
+                    }
+                };
+
+                if info == 0 {
+                    Ok((m as usize, ArrayBase::from_vec(alpha), ArrayBase::from_vec(beta)))
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    // The eigenvalues were too close to be swapped.
+                    Err(EigenError::Failed)
+                }
+            }
+        }
+    )
+}
+
+impl_gen_schur_complex!(c32, cgges, ctgsen);
+impl_gen_schur_complex!(c64, zgges, ztgsen);
@@ -10,9 +10,19 @@
 
 
 pub mod general;
+pub mod generalized;
+pub mod generalized_schur;
 pub mod symmetric;
+pub mod symmetric_generalized;
+pub mod schur;
+pub mod tridiagonal;
 pub mod types;
 
-pub use self::types::{Solution, EigenError};
+pub use self::types::{Solution, GeneralizedSolution, ExpertEigenSolution, Balance, EigenError};
 pub use self::general::{Eigen};
-pub use self::symmetric::{SymEigen};
+pub use self::generalized::GeneralizedEigen;
+pub use self::generalized_schur::{GeneralizedSchur, GeneralizedSchurFactors};
+pub use self::schur::{Schur, SchurFactors};
+pub use self::symmetric::{SymEigen, SymEigenMethod, EigenRange};
+pub use self::tridiagonal::TridiagonalEigen;
+pub use self::symmetric_generalized::{SymGeneralizedEigen, GeneralizedProblem};
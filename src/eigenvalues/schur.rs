@@ -0,0 +1,294 @@
+//! Compute the Schur decomposition of a general square matrix.
+//!
+//! A square matrix `A` is factored into the product `Z` * `T` *
+//! `Z^H`, such that
+//!
+//! - `Z` is unitary (orthogonal, for real matrices), and its columns
+//! are the Schur vectors of `A`.
+//!
+//! - `T` is upper triangular for complex matrices. For real matrices,
+//! `T` is upper quasi-triangular, with 1 x 1 blocks for real
+//! eigenvalues and 2 x 2 blocks for complex conjugate pairs of
+//! eigenvalues.
+//!
+//! The eigenvalues of `A` appear on the diagonal (blocks) of `T`.
+//! Unlike eigenvectors, the Schur vectors are always well-conditioned
+//! and the leading `k` columns of `Z` span an invariant subspace of
+//! `A` for the `k` leading eigenvalues, which can be chosen by
+//! reordering the decomposition.
+
+use lapack::c::{sgees, dgees, cgees, zgees, strsen, dtrsen, ctrsen, ztrsen};
+use impl_prelude::*;
+use util::external::conj_t;
+use super::types::EigenError;
+
+/// Representation of the Schur decomposition `A = Z * T * Z^H` of a
+/// square matrix.
+#[derive(Debug, Clone)]
+pub struct SchurFactors<T: Schur> {
+    t: Array<T, Ix2>,
+    z: Array<T, Ix2>,
+    values: Array<T::Complex, Ix1>,
+}
+
+impl<T: Schur> SchurFactors<T> {
+    /// Return the (quasi-)triangular Schur form `T`.
+    pub fn t(&self) -> &Array<T, Ix2> {
+        &self.t
+    }
+
+    /// Return the unitary matrix of Schur vectors `Z`.
+    pub fn z(&self) -> &Array<T, Ix2> {
+        &self.z
+    }
+
+    /// Return the eigenvalues, in the order in which they appear on
+    /// the diagonal of `T`.
+    pub fn values(&self) -> &Array<T::Complex, Ix1> {
+        &self.values
+    }
+
+    /// Return the factors `T` and `Z`, consuming the decomposition.
+    pub fn into_parts(self) -> (Array<T, Ix2>, Array<T, Ix2>) {
+        (self.t, self.z)
+    }
+
+    /// Reconstruct the original matrix `A` from the decomposition.
+    pub fn reconstruct(&self) -> Array<T, Ix2> {
+        self.z.dot(&self.t).dot(&conj_t(&self.z))
+    }
+
+    /// Reorder the decomposition so that the eigenvalues for which
+    /// `select` returns true appear in the leading diagonal block of
+    /// `T`, and return the number `k` of such eigenvalues.
+    ///
+    /// Afterwards, the first `k` columns of `Z` form an orthonormal
+    /// basis for the invariant subspace of `A` corresponding to the
+    /// selected eigenvalues.
+    ///
+    /// For real matrices, a complex conjugate pair of eigenvalues is
+    /// selected if either of the two is selected.
+    pub fn reorder<F>(&mut self, select: F) -> Result<usize, EigenError>
+        where F: Fn(T::Complex) -> bool
+    {
+        let selected: Vec<i32> = self.values.iter().map(|&v| select(v) as i32).collect();
+        let (k, values) = try!(T::compute_reorder_mut(&mut self.t, &mut self.z, &selected));
+        self.values = values;
+        Ok(k)
+    }
+}
+
+/// Trait defined on scalars to support the Schur decomposition.
+pub trait Schur: LinxalImplScalar {
+    /// Return the Schur decomposition of the square matrix `A`,
+    /// consuming the input.
+    fn compute_into(a: Array<Self, Ix2>) -> Result<SchurFactors<Self>, EigenError>;
+
+    /// Return the Schur decomposition of the square matrix `A`.
+    fn compute<D>(a: &ArrayBase<D, Ix2>) -> Result<SchurFactors<Self>, EigenError>
+        where D: Data<Elem = Self>
+    {
+        Self::compute_into(a.to_owned())
+    }
+
+    /// Reorder the Schur decomposition in place, so that the
+    /// eigenvalues for which `select` is non-zero lead the diagonal of
+    /// `t`. Return the number of selected eigenvalues and the
+    /// reordered eigenvalues.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_reorder_mut(t: &mut Array<Self, Ix2>,
+                           z: &mut Array<Self, Ix2>,
+                           select: &[i32])
+                           -> Result<(usize, Array<Self::Complex, Ix1>), EigenError>;
+}
+
+macro_rules! impl_schur_real {
+    ($impl_type:ident, $eigv_type:ident, $schur_func:ident, $reorder_func:ident) => (
+        impl Schur for $impl_type {
+            fn compute_into(mut a: Array<Self, Ix2>) -> Result<SchurFactors<Self>, EigenError> {
+                let dim = a.dim();
+                if dim.0 != dim.1 {
+                    return Err(EigenError::NotSquare);
+                }
+                let n = dim.0;
+                if n == 0 {
+                    return Ok(SchurFactors { t: a, z: Array::default((0, 0)), values: Array::default(0) });
+                }
+
+                let mut wr = vec![0.0; n];
+                let mut wi = vec![0.0; n];
+                let mut sdim = 0;
+
+                let (z, info) = {
+                    let (slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                        Some(x) => x,
+                        None => return Err(EigenError::BadLayout),
+                    };
+                    let mut z = matrix_with_layout((n, n), layout);
+
+                    let info = unsafe {
+                        $schur_func(layout, b'V', b'N', None, n as i32, slice, lda as i32,
+                                    &mut sdim, &mut wr, &mut wi,
+                                    z.as_slice_mut().unwrap(), n as i32)
+                    };
+                    (z, info)
+                };
+
+                if info == 0 {
+                    let values = wr.iter().zip(wi.iter()).map(|(&x, &y)| $eigv_type::new(x, y)).collect();
+                    Ok(SchurFactors { t: a, z: z, values: ArrayBase::from_vec(values) })
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    Err(EigenError::Failed)
+                }
+            }
+
+            fn compute_reorder_mut(t: &mut Array<Self, Ix2>,
+                                   z: &mut Array<Self, Ix2>,
+                                   select: &[i32])
+                                   -> Result<(usize, Array<Self::Complex, Ix1>), EigenError> {
+                let dim = t.dim();
+                if dim.0 != dim.1 {
+                    return Err(EigenError::NotSquare);
+                }
+                let n = dim.0;
+                if z.dim() != dim || select.len() != n {
+                    return Err(EigenError::InconsistentDimensions);
+                }
+                if n == 0 {
+                    return Ok((0, Array::default(0)));
+                }
+
+                let mut wr = vec![0.0; n];
+                let mut wi = vec![0.0; n];
+                let mut m = 0;
+                let (mut s, mut sep) = ([0.0], [0.0]);
+
+                let info = {
+                    let (t_slice, layout, ldt) = match slice_and_layout_mut(t) {
+                        Some(x) => x,
+                        None => return Err(EigenError::BadLayout),
+                    };
+                    let (z_slice, ldz) = match slice_and_layout_matching_mut(z, layout) {
+                        Some(x) => x,
+                        None => return Err(EigenError::InconsistentLayout),
+                    };
+
+                    unsafe {
+                        $reorder_func(layout, b'N', b'V', select, n as i32, t_slice, ldt as i32,
+                                      &mut z_slice[0], ldz as i32, &mut wr, &mut wi,
+                                      &mut m, &mut s, &mut sep)
+                    }
+                };
+
+                if info == 0 {
+                    let values = wr.iter().zip(wi.iter()).map(|(&x, &y)| $eigv_type::new(x, y)).collect();
+                    Ok((m as usize, ArrayBase::from_vec(values)))
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    // The eigenvalues were too close to be swapped.
+                    Err(EigenError::Failed)
+                }
+            }
+        }
+    )
+}
+
+impl_schur_real!(f32, c32, sgees, strsen);
+impl_schur_real!(f64, c64, dgees, dtrsen);
+
+macro_rules! impl_schur_complex {
+    ($impl_type:ident, $schur_func:ident, $reorder_func:ident) => (
+        impl Schur for $impl_type {
+            fn compute_into(mut a: Array<Self, Ix2>) -> Result<SchurFactors<Self>, EigenError> {
+                let dim = a.dim();
+                if dim.0 != dim.1 {
+                    return Err(EigenError::NotSquare);
+                }
+                let n = dim.0;
+                if n == 0 {
+                    return Ok(SchurFactors { t: a, z: Array::default((0, 0)), values: Array::default(0) });
+                }
+
+                let mut values = Array::default(n);
+                let mut sdim = 0;
+
+                let (z, info) = {
+                    let (slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                        Some(x) => x,
+                        None => return Err(EigenError::BadLayout),
+                    };
+                    let mut z = matrix_with_layout((n, n), layout);
+
+                    let info = unsafe {
+                        $schur_func(layout, b'V', b'N', None, n as i32, slice, lda as i32,
+                                    &mut sdim, values.as_slice_mut().unwrap(),
+                                    z.as_slice_mut().unwrap(), n as i32)
+                    };
+                    (z, info)
+                };
+
+                if info == 0 {
+                    Ok(SchurFactors { t: a, z: z, values: values })
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    Err(EigenError::Failed)
+                }
+            }
+
+            fn compute_reorder_mut(t: &mut Array<Self, Ix2>,
+                                   z: &mut Array<Self, Ix2>,
+                                   select: &[i32])
+                                   -> Result<(usize, Array<Self::Complex, Ix1>), EigenError> {
+                let dim = t.dim();
+                if dim.0 != dim.1 {
+                    return Err(EigenError::NotSquare);
+                }
+                let n = dim.0;
+                if z.dim() != dim || select.len() != n {
+                    return Err(EigenError::InconsistentDimensions);
+                }
+                if n == 0 {
+                    return Ok((0, Array::default(0)));
+                }
+
+                let mut values = Array::default(n);
+                let mut m = 0;
+                let (mut s, mut sep) = ([0.0], [0.0]);
+
+                let info = {
+                    let (t_slice, layout, ldt) = match slice_and_layout_mut(t) {
+                        Some(x) => x,
+                        None => return Err(EigenError::BadLayout),
+                    };
+                    let (z_slice, ldz) = match slice_and_layout_matching_mut(z, layout) {
+                        Some(x) => x,
+                        None => return Err(EigenError::InconsistentLayout),
+                    };
+
+                    unsafe {
+                        $reorder_func(layout, b'N', b'V', select, n as i32, t_slice, ldt as i32,
+                                      &mut z_slice[0], ldz as i32, values.as_slice_mut().unwrap(),
+                                      &mut m, &mut s, &mut sep)
+                    }
+                };
+
+                if info == 0 {
+                    Ok((m as usize, values))
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    // The eigenvalues were too close to be swapped.
+                    Err(EigenError::Failed)
+                }
+            }
+        }
+    )
+}
+
+impl_schur_complex!(c32, cgees, ctrsen);
+impl_schur_complex!(c64, zgees, ztrsen);
@@ -3,15 +3,75 @@
 //! Symmetric (or Hermitian, for complex) matrices are guaranteed to
 //! have real eigenvalues.
 
-use lapack::c::{ssyev, dsyev, cheev, zheev};
+use lapack::c::{ssyev, dsyev, cheev, zheev, ssyevd, dsyevd, cheevd, zheevd};
+use lapack::c::{ssyevr, dsyevr, cheevr, zheevr, Layout};
 use super::types::{Solution, EigenError};
 use impl_prelude::*;
 
+const SYM_EIGEN_QR_LIMIT: usize = 100;
+
+/// Driver used to compute the eigenvalues of a symmetric matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymEigenMethod {
+    /// Choose a driver based on the size of the problem and whether
+    /// eigenvectors are required.
+    Auto,
+
+    /// Implicit QL or QR iteration (`?syev`).
+    QRIteration,
+
+    /// Divide and conquer (`?syevd`). Much faster than QR iteration
+    /// when computing the eigenvectors of large matrices, at the cost
+    /// of more workspace.
+    DivideAndConquer,
+
+    /// Multiple relatively robust representations (`?syevr`).
+    MRRR,
+}
+
+/// Choose a method based on the problem.
+pub(crate) fn select_sym_eigen_method(n: usize, with_vectors: bool) -> SymEigenMethod {
+    // Without eigenvectors, every driver reduces to the same
+    // root-free QR iteration on the tridiagonal matrix, so there is
+    // no reason to pay for the extra workspace.
+    if with_vectors && n > SYM_EIGEN_QR_LIMIT {
+        SymEigenMethod::DivideAndConquer
+    } else {
+        SymEigenMethod::QRIteration
+    }
+}
+
+/// Subset of the eigenvalues of a symmetric matrix to compute.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EigenRange<T> {
+    /// Compute all of the eigenvalues.
+    All,
+
+    /// Compute the eigenvalues in the half-open interval `[vl, vu)`.
+    Values(T, T),
+
+    /// Compute the eigenvalues with indices `il` through `iu`
+    /// (inclusive, and starting from 0), in ascending order.
+    Indices(usize, usize),
+}
+
 /// Scalar trait for computing eigenvalues of a symmetric matrix.
 ///
 /// In order to extract eigenvalues or eigenvectors from a matrix,
 /// that matrix with must have  entries implementing the `Eigen` trait.
 pub trait SymEigen: LinxalImplScalar {
+    /// Return the real eigenvalues of a symmetric matrix, using the
+    /// driver specified by `method`.
+    ///
+    /// If `with_vectors` is true, the right eigenvectors of 'V' are
+    /// stored in the input matrix.
+    fn compute_with_method_mut<D>(mat: &mut ArrayBase<D, Ix2>,
+                                  uplo: Symmetric,
+                                  with_vectors: bool,
+                                  method: SymEigenMethod)
+                                  -> Result<Array<Self::RealPart, Ix1>, EigenError>
+        where D: DataMut<Elem = Self>;
+
     /// Return the real eigenvalues of a symmetric matrix.
     ///
     /// If `with_vectors` is true, the right eigenvectors of 'V' are
@@ -20,7 +80,10 @@ pub trait SymEigen: LinxalImplScalar {
                       uplo: Symmetric,
                       with_vectors: bool)
                       -> Result<Array<Self::RealPart, Ix1>, EigenError>
-        where D: DataMut<Elem = Self>;
+        where D: DataMut<Elem = Self>
+    {
+        Self::compute_with_method_mut(mat, uplo, with_vectors, SymEigenMethod::Auto)
+    }
 
     /// Return the real eigenvalues of a symmetric matrix.
     fn compute_into<D>(mut mat: ArrayBase<D, Ix2>,
@@ -40,14 +103,74 @@ pub trait SymEigen: LinxalImplScalar {
                   uplo: Symmetric,
                   with_vectors: bool)
                   -> Result<Solution<Self, Self::RealPart>, EigenError>
-        where D: Data<Elem = Self>;
+        where D: Data<Elem = Self>
+    {
+        Self::compute_with_method(mat, uplo, with_vectors, SymEigenMethod::Auto)
+    }
+
+    /// Return the eigenvalues and, optionally, the eigenvectors of a
+    /// symmetric matrix, using the driver specified by `method`.
+    ///
+    /// # Remarks
+    ///
+    /// The input matrix is copied before the calculation takes place.
+    fn compute_with_method<D>(mat: &ArrayBase<D, Ix2>,
+                              uplo: Symmetric,
+                              with_vectors: bool,
+                              method: SymEigenMethod)
+                              -> Result<Solution<Self, Self::RealPart>, EigenError>
+        where D: Data<Elem = Self>
+    {
+        let vec: Vec<Self> = mat.iter().cloned().collect();
+        let mut new_mat = Array::from_shape_vec(mat.dim(), vec).unwrap();
+        let values = try!(Self::compute_with_method_mut(&mut new_mat, uplo, with_vectors, method));
+        Ok(Solution {
+            values: values,
+            left_vectors: None,
+            right_vectors: if with_vectors { Some(new_mat) } else { None }
+        })
+    }
+
+    /// Return the selected real eigenvalues of a symmetric matrix,
+    /// and optionally the corresponding eigenvectors, in ascending
+    /// order.
+    ///
+    /// Uses the MRRR algorithm, which only computes the requested
+    /// portion of the spectrum. The contents of `mat` are destroyed.
+    fn compute_range_mut<D>(mat: &mut ArrayBase<D, Ix2>,
+                            uplo: Symmetric,
+                            range: EigenRange<Self::RealPart>,
+                            with_vectors: bool)
+                            -> Result<Solution<Self, Self::RealPart>, EigenError>
+        where D: DataMut<Elem = Self>;
+
+    /// Return the selected real eigenvalues of a symmetric matrix,
+    /// and optionally the corresponding eigenvectors.
+    ///
+    /// See `Self::compute_range_mut`.
+    ///
+    /// # Remarks
+    ///
+    /// The input matrix is copied before the calculation takes place.
+    fn compute_range<D>(mat: &ArrayBase<D, Ix2>,
+                        uplo: Symmetric,
+                        range: EigenRange<Self::RealPart>,
+                        with_vectors: bool)
+                        -> Result<Solution<Self, Self::RealPart>, EigenError>
+        where D: Data<Elem = Self>
+    {
+        Self::compute_range_mut(&mut mat.to_owned(), uplo, range, with_vectors)
+    }
 }
 
 macro_rules! impl_sym_eigen {
-    ($impl_type:ident, $eigen_type:ident, $func:ident) => (
+    ($impl_type:ident, $eigen_type:ident, $func:ident, $dc_func:ident, $range_func:ident) => (
         impl SymEigen for $impl_type {
-            fn compute_mut<D>(mat: &mut ArrayBase<D, Ix2>, uplo: Symmetric, with_vectors: bool) ->
-                Result<Array<Self::RealPart, Ix1>, EigenError>
+            fn compute_with_method_mut<D>(mat: &mut ArrayBase<D, Ix2>,
+                                          uplo: Symmetric,
+                                          with_vectors: bool,
+                                          method: SymEigenMethod)
+                                          -> Result<Array<Self::RealPart, Ix1>, EigenError>
                 where D: DataMut<Elem=Self>
             {
                 let dim = mat.dim();
@@ -55,6 +178,21 @@ macro_rules! impl_sym_eigen {
                     return Err(EigenError::NotSquare);
                 }
 
+                let method = match method {
+                    SymEigenMethod::Auto => select_sym_eigen_method(dim.0, with_vectors),
+                    m => m,
+                };
+
+                // The MRRR driver does not compute the eigenvectors in
+                // place.
+                if method == SymEigenMethod::MRRR {
+                    let sol = try!(Self::compute_range_mut(mat, uplo, EigenRange::All, with_vectors));
+                    if let Some(z) = sol.right_vectors {
+                        mat.assign(&z);
+                    }
+                    return Ok(sol.values);
+                }
+
                 let n = dim.0 as i32;
 
                 let (data_slice, layout, ld) = match slice_and_layout_mut(mat) {
@@ -66,8 +204,16 @@ macro_rules! impl_sym_eigen {
                 let job = if with_vectors { b'V' } else { b'N' };
 
                 let info = unsafe {
-                    $func(layout, job, uplo as u8, n, data_slice,
-                          ld as i32, values.as_slice_mut().unwrap())
+                    match method {
+                        SymEigenMethod::DivideAndConquer => {
+                            $dc_func(layout, job, uplo as u8, n, data_slice,
+                                     ld as i32, values.as_slice_mut().unwrap())
+                        },
+                        _ => {
+                            $func(layout, job, uplo as u8, n, data_slice,
+                                  ld as i32, values.as_slice_mut().unwrap())
+                        }
+                    }
                 };
 
                 if info  == 0 {
@@ -79,29 +225,101 @@ macro_rules! impl_sym_eigen {
                 }
             }
 
-            fn compute<D>(mat: &ArrayBase<D, Ix2>,
-                          uplo: Symmetric,
-                          with_vectors: bool) -> Result<Solution<Self, Self::RealPart>, EigenError>
-                where D: Data<Elem=Self> {
-                let vec: Vec<Self> = mat.iter().cloned().collect();
-                let mut new_mat = Array::from_shape_vec(mat.dim(), vec).unwrap();
-                let r = Self::compute_mut(&mut new_mat, uplo, with_vectors);
-                r.map(|values| {
-                    Solution {
-                        values: values,
-                        left_vectors: None,
-                        right_vectors: if with_vectors { Some(new_mat) } else { None }
+            fn compute_range_mut<D>(mat: &mut ArrayBase<D, Ix2>,
+                                    uplo: Symmetric,
+                                    range: EigenRange<Self::RealPart>,
+                                    with_vectors: bool)
+                                    -> Result<Solution<Self, Self::RealPart>, EigenError>
+                where D: DataMut<Elem=Self>
+            {
+                let dim = mat.dim();
+                if dim.0 != dim.1 {
+                    return Err(EigenError::NotSquare);
+                }
+                let n = dim.0;
+
+                // `vl`, `vu`, `il` and `iu` are only referenced for
+                // the corresponding range.
+                let (range_opt, vl, vu, il, iu, max_values) = match range {
+                    EigenRange::All => (b'A', 0.0, 0.0, 0, 0, n),
+                    EigenRange::Values(vl, vu) => {
+                        if !(vl < vu) {
+                            return Err(EigenError::InvalidRange);
+                        }
+                        // LAPACK selects the interval `(vl, vu]`.
+                        (b'V', vl.next_down(), vu.next_down(), 0, 0, n)
+                    },
+                    EigenRange::Indices(il, iu) => {
+                        if il > iu || iu >= n {
+                            return Err(EigenError::InvalidRange);
+                        }
+                        (b'I', 0.0, 0.0, il + 1, iu + 1, iu - il + 1)
                     }
-                })
+                };
+
+                if n == 0 {
+                    return Ok(Solution {
+                        values: Array::default(0),
+                        left_vectors: None,
+                        right_vectors: if with_vectors { Some(Array::default((0, 0))) } else { None }
+                    });
+                }
+
+                let (data_slice, layout, ld) = match slice_and_layout_mut(mat) {
+                    Some(x) => x,
+                    None => return Err(EigenError::BadLayout)
+                };
+
+                let mut values = vec![0.0; n];
+                let mut isuppz = vec![0; 2 * max_values];
+                let mut num_found = 0;
+
+                // When the vectors are not needed, `z` is not
+                // referenced, but the leading dimension must still be
+                // consistent with the range.
+                let mut z = matrix_with_layout(if with_vectors { (n, max_values) } else { (1, 1) }, layout);
+                let ldz = match (layout, with_vectors) {
+                    (Layout::RowMajor, _) => max_values,
+                    (Layout::ColumnMajor, true) => n,
+                    (Layout::ColumnMajor, false) => 1,
+                };
+                let job = if with_vectors { b'V' } else { b'N' };
+
+                let info = unsafe {
+                    $range_func(layout, job, range_opt, uplo as u8, n as i32,
+                                data_slice, ld as i32, vl, vu, il as i32, iu as i32,
+                                0.0, &mut num_found, &mut values,
+                                z.as_slice_mut().unwrap(), ldz as i32, &mut isuppz)
+                };
+
+                if info == 0 {
+                    let m = num_found as usize;
+                    values.truncate(m);
+                    let vectors = if with_vectors {
+                        Some(if m == max_values { z } else { z.slice(s![.., ..m as isize]).to_owned() })
+                    } else {
+                        None
+                    };
+
+                    Ok(Solution {
+                        values: ArrayBase::from_vec(values),
+                        left_vectors: None,
+                        right_vectors: vectors
+                    })
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    Err(EigenError::Failed)
+                }
             }
         }
     )
 }
 
-impl_sym_eigen!(f32, f32, ssyev);
-impl_sym_eigen!(c32, f32, cheev);
-impl_sym_eigen!(f64, f64, dsyev);
-impl_sym_eigen!(c64, f64, zheev);
+impl_sym_eigen!(f32, f32, ssyev, ssyevd, ssyevr);
+impl_sym_eigen!(c32, f32, cheev, cheevd, cheevr);
+impl_sym_eigen!(f64, f64, dsyev, dsyevd, dsyevr);
+impl_sym_eigen!(c64, f64, zheev, zheevd, zheevr);
 
 #[cfg(test)]
 mod tests {}
@@ -0,0 +1,141 @@
+//! Compute eigenvalues and eigenvectors of symmetric-definite
+//! generalized eigenvalue problems.
+//!
+//! For a symmetric (or Hermitian) matrix \\(A\\) and a symmetric (or
+//! Hermitian) positive definite matrix \\(B\\), the generalized
+//! problems
+//!
+//! $$A \cdot x = \lambda \cdot B \cdot x, \quad A \cdot B \cdot x = \lambda \cdot x, \quad B \cdot A \cdot x = \lambda \cdot x$$
+//!
+//! are guaranteed to have real eigenvalues.
+
+use lapack::c::{ssygv, dsygv, chegv, zhegv};
+use super::types::{Solution, EigenError};
+use impl_prelude::*;
+
+/// Form of a symmetric-definite generalized eigenvalue problem.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GeneralizedProblem {
+    /// The problem `A x = lambda B x`.
+    ///
+    /// The eigenvectors are normalized so that `Z^H B Z = I`.
+    AxLambdaBx = 1,
+
+    /// The problem `A B x = lambda x`.
+    ///
+    /// The eigenvectors are normalized so that `Z^H B Z = I`.
+    ABxLambdaX = 2,
+
+    /// The problem `B A x = lambda x`.
+    ///
+    /// The eigenvectors are normalized so that `Z^H B^-1 Z = I`.
+    BAxLambdaX = 3,
+}
+
+/// Scalar trait for computing eigenvalues of a symmetric-definite
+/// generalized eigenvalue problem.
+pub trait SymGeneralizedEigen: LinxalImplScalar {
+    /// Return the real eigenvalues of a symmetric-definite
+    /// generalized problem.
+    ///
+    /// Only the portions of `a` and `b` specified by `uplo` are
+    /// read. If `with_vectors` is true, the eigenvectors are stored
+    /// in `a`. In either case, `b` is overwritten by its Cholesky
+    /// factor.
+    fn compute_mut<D1, D2>(a: &mut ArrayBase<D1, Ix2>,
+                           b: &mut ArrayBase<D2, Ix2>,
+                           uplo: Symmetric,
+                           problem: GeneralizedProblem,
+                           with_vectors: bool)
+                           -> Result<Array<Self::RealPart, Ix1>, EigenError>
+        where D1: DataMut<Elem = Self>,
+              D2: DataMut<Elem = Self>;
+
+    /// Return the eigenvalues and, optionally, the eigenvectors of a
+    /// symmetric-definite generalized problem.
+    ///
+    /// # Remarks
+    ///
+    /// The input matrices are copied before the calculation takes
+    /// place.
+    fn compute<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                       b: &ArrayBase<D2, Ix2>,
+                       uplo: Symmetric,
+                       problem: GeneralizedProblem,
+                       with_vectors: bool)
+                       -> Result<Solution<Self, Self::RealPart>, EigenError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>
+    {
+        let mut new_a = a.to_owned();
+        let mut new_b = copy_with_matching_layout(b, &new_a);
+        let values = try!(Self::compute_mut(&mut new_a, &mut new_b, uplo, problem, with_vectors));
+        Ok(Solution {
+            values: values,
+            left_vectors: None,
+            right_vectors: if with_vectors { Some(new_a) } else { None },
+        })
+    }
+}
+
+macro_rules! impl_sym_gen_eigen {
+    ($impl_type:ident, $func:ident) => (
+        impl SymGeneralizedEigen for $impl_type {
+            fn compute_mut<D1, D2>(a: &mut ArrayBase<D1, Ix2>,
+                                   b: &mut ArrayBase<D2, Ix2>,
+                                   uplo: Symmetric,
+                                   problem: GeneralizedProblem,
+                                   with_vectors: bool)
+                                   -> Result<Array<Self::RealPart, Ix1>, EigenError>
+                where D1: DataMut<Elem = Self>,
+                      D2: DataMut<Elem = Self>
+            {
+                let dim = a.dim();
+                if dim.0 != dim.1 {
+                    return Err(EigenError::NotSquare);
+                }
+                if b.dim() != dim {
+                    return Err(EigenError::InconsistentDimensions);
+                }
+
+                let n = dim.0 as i32;
+
+                let (a_slice, layout, lda) = match slice_and_layout_mut(a) {
+                    Some(x) => x,
+                    None => return Err(EigenError::BadLayout)
+                };
+                let (b_slice, ldb) = match slice_and_layout_matching_mut(b, layout) {
+                    Some(x) => x,
+                    None => return Err(EigenError::InconsistentLayout)
+                };
+
+                let mut values = Array::default(n as Ix);
+                let job = if with_vectors { b'V' } else { b'N' };
+
+                let info = unsafe {
+                    $func(layout, problem as i32, job, uplo as u8, n,
+                          a_slice, lda as i32, b_slice, ldb as i32,
+                          values.as_slice_mut().unwrap())
+                };
+
+                if info == 0 {
+                    Ok(values)
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else if info > n {
+                    // The leading minor of order `info - n` of `B` is
+                    // not positive definite.
+                    Err(EigenError::NotPositiveDefinite)
+                } else {
+                    Err(EigenError::Failed)
+                }
+            }
+        }
+    )
+}
+
+impl_sym_gen_eigen!(f32, ssygv);
+impl_sym_gen_eigen!(f64, dsygv);
+impl_sym_gen_eigen!(c32, chegv);
+impl_sym_gen_eigen!(c64, zhegv);
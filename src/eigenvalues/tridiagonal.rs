@@ -0,0 +1,221 @@
+//! Compute eigenvalues and eigenvectors of real symmetric tridiagonal
+//! matrices.
+//!
+//! The matrix is given by its diagonal and off-diagonal, and is never
+//! formed explicitly. A symmetric (or Hermitian) matrix can be reduced
+//! to this form with `factorization::Tridiagonal`.
+
+use lapack::c::{sstev, dstev, sstedc, dstedc, sstevr, dstevr, Layout};
+use super::types::{Solution, EigenError};
+use super::symmetric::{SymEigenMethod, EigenRange, select_sym_eigen_method};
+use impl_prelude::*;
+
+/// Scalar trait for computing eigenvalues of a real symmetric
+/// tridiagonal matrix.
+///
+/// The tridiagonal matrix is always real, so the eigenvalues and
+/// eigenvectors have type `Self::RealPart`.
+pub trait TridiagonalEigen: LinxalImplScalar {
+    /// Return the eigenvalues (in ascending order) and, optionally,
+    /// the eigenvectors of the symmetric tridiagonal matrix with
+    /// diagonal `diag` and off-diagonal `off_diag`, using the driver
+    /// specified by `method`.
+    ///
+    /// `off_diag` must have one fewer element than `diag`.
+    fn compute_with_method<D1, D2>(diag: &ArrayBase<D1, Ix1>,
+                                   off_diag: &ArrayBase<D2, Ix1>,
+                                   with_vectors: bool,
+                                   method: SymEigenMethod)
+                                   -> Result<Solution<Self::RealPart, Self::RealPart>, EigenError>
+        where D1: Data<Elem = Self::RealPart>,
+              D2: Data<Elem = Self::RealPart>;
+
+    /// Return the eigenvalues (in ascending order) and, optionally,
+    /// the eigenvectors of the symmetric tridiagonal matrix with
+    /// diagonal `diag` and off-diagonal `off_diag`.
+    fn compute<D1, D2>(diag: &ArrayBase<D1, Ix1>,
+                       off_diag: &ArrayBase<D2, Ix1>,
+                       with_vectors: bool)
+                       -> Result<Solution<Self::RealPart, Self::RealPart>, EigenError>
+        where D1: Data<Elem = Self::RealPart>,
+              D2: Data<Elem = Self::RealPart>
+    {
+        Self::compute_with_method(diag, off_diag, with_vectors, SymEigenMethod::Auto)
+    }
+
+    /// Return the selected eigenvalues (in ascending order) and,
+    /// optionally, the corresponding eigenvectors of the symmetric
+    /// tridiagonal matrix with diagonal `diag` and off-diagonal
+    /// `off_diag`.
+    ///
+    /// Uses the MRRR algorithm, which only computes the requested
+    /// portion of the spectrum.
+    fn compute_range<D1, D2>(diag: &ArrayBase<D1, Ix1>,
+                             off_diag: &ArrayBase<D2, Ix1>,
+                             range: EigenRange<Self::RealPart>,
+                             with_vectors: bool)
+                             -> Result<Solution<Self::RealPart, Self::RealPart>, EigenError>
+        where D1: Data<Elem = Self::RealPart>,
+              D2: Data<Elem = Self::RealPart>;
+}
+
+/// Check the dimensions of the tridiagonal matrix, and return its
+/// order.
+fn tridiagonal_dim<T, D1, D2>(diag: &ArrayBase<D1, Ix1>, off_diag: &ArrayBase<D2, Ix1>)
+                              -> Result<usize, EigenError>
+    where D1: Data<Elem = T>,
+          D2: Data<Elem = T>
+{
+    let n = diag.dim();
+    if off_diag.dim() != n.saturating_sub(1) {
+        return Err(EigenError::InconsistentDimensions);
+    }
+    Ok(n)
+}
+
+/// Return the solution of an empty (0 x 0) problem.
+fn empty_solution<T: LinxalImplScalar>(with_vectors: bool) -> Solution<T, T> {
+    Solution {
+        values: Array::default(0),
+        left_vectors: None,
+        right_vectors: if with_vectors { Some(Array::default((0, 0))) } else { None },
+    }
+}
+
+macro_rules! impl_tridiagonal_eigen {
+    ($impl_type:ident, $real_type:ident, $func:ident, $dc_func:ident, $range_func:ident) => (
+        impl TridiagonalEigen for $impl_type {
+            fn compute_with_method<D1, D2>(diag: &ArrayBase<D1, Ix1>,
+                                           off_diag: &ArrayBase<D2, Ix1>,
+                                           with_vectors: bool,
+                                           method: SymEigenMethod)
+                                           -> Result<Solution<$real_type, $real_type>, EigenError>
+                where D1: Data<Elem = $real_type>,
+                      D2: Data<Elem = $real_type>
+            {
+                let n = try!(tridiagonal_dim(diag, off_diag));
+
+                let method = match method {
+                    SymEigenMethod::Auto => select_sym_eigen_method(n, with_vectors),
+                    m => m,
+                };
+                if method == SymEigenMethod::MRRR {
+                    return Self::compute_range(diag, off_diag, EigenRange::All, with_vectors);
+                }
+                if n == 0 {
+                    return Ok(empty_solution(with_vectors));
+                }
+
+                let mut d = diag.to_vec();
+                let mut e = off_diag.to_vec();
+
+                // The LAPACKE bindings check the leading dimension of
+                // `z`, even when it is not referenced.
+                let mut z = Array::zeros(if with_vectors { (n, n) } else { (1, 1) });
+
+                let info = unsafe {
+                    match method {
+                        SymEigenMethod::DivideAndConquer => {
+                            // 'I' computes the eigenvectors of the
+                            // tridiagonal matrix itself.
+                            let job = if with_vectors { b'I' } else { b'N' };
+                            $dc_func(Layout::RowMajor, job, n as i32, &mut d, &mut e,
+                                     z.as_slice_mut().unwrap(), n as i32)
+                        },
+                        _ => {
+                            let job = if with_vectors { b'V' } else { b'N' };
+                            $func(Layout::RowMajor, job, n as i32, &mut d, &mut e,
+                                  z.as_slice_mut().unwrap(), n as i32)
+                        }
+                    }
+                };
+
+                if info == 0 {
+                    Ok(Solution {
+                        values: ArrayBase::from_vec(d),
+                        left_vectors: None,
+                        right_vectors: if with_vectors { Some(z) } else { None },
+                    })
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    Err(EigenError::Failed)
+                }
+            }
+
+            fn compute_range<D1, D2>(diag: &ArrayBase<D1, Ix1>,
+                                     off_diag: &ArrayBase<D2, Ix1>,
+                                     range: EigenRange<$real_type>,
+                                     with_vectors: bool)
+                                     -> Result<Solution<$real_type, $real_type>, EigenError>
+                where D1: Data<Elem = $real_type>,
+                      D2: Data<Elem = $real_type>
+            {
+                let n = try!(tridiagonal_dim(diag, off_diag));
+
+                // `vl`, `vu`, `il` and `iu` are only referenced for
+                // the corresponding range.
+                let (range_opt, vl, vu, il, iu, max_values) = match range {
+                    EigenRange::All => (b'A', 0.0, 0.0, 0, 0, n),
+                    EigenRange::Values(vl, vu) => {
+                        if !(vl < vu) {
+                            return Err(EigenError::InvalidRange);
+                        }
+                        // LAPACK selects the interval `(vl, vu]`.
+                        (b'V', vl.next_down(), vu.next_down(), 0, 0, n)
+                    },
+                    EigenRange::Indices(il, iu) => {
+                        if il > iu || iu >= n {
+                            return Err(EigenError::InvalidRange);
+                        }
+                        (b'I', 0.0, 0.0, il + 1, iu + 1, iu - il + 1)
+                    }
+                };
+
+                if n == 0 {
+                    return Ok(empty_solution(with_vectors));
+                }
+
+                let mut d = diag.to_vec();
+                let mut e = off_diag.to_vec();
+                let mut values = vec![0.0; n];
+                let mut isuppz = vec![0; 2 * max_values];
+                let mut num_found = 0;
+
+                let mut z = Array::zeros(if with_vectors { (n, max_values) } else { (1, 1) });
+                let job = if with_vectors { b'V' } else { b'N' };
+
+                let info = unsafe {
+                    $range_func(Layout::RowMajor, job, range_opt, n as i32, &mut d, &mut e,
+                                vl, vu, il as i32, iu as i32, 0.0, &mut num_found, &mut values,
+                                z.as_slice_mut().unwrap(), max_values as i32, &mut isuppz)
+                };
+
+                if info == 0 {
+                    let m = num_found as usize;
+                    values.truncate(m);
+                    let vectors = if with_vectors {
+                        Some(if m == max_values { z } else { z.slice(s![.., ..m as isize]).to_owned() })
+                    } else {
+                        None
+                    };
+
+                    Ok(Solution {
+                        values: ArrayBase::from_vec(values),
+                        left_vectors: None,
+                        right_vectors: vectors,
+                    })
+                } else if info < 0 {
+                    Err(EigenError::IllegalParameter(-info))
+                } else {
+                    Err(EigenError::Failed)
+                }
+            }
+        }
+    )
+}
+
+impl_tridiagonal_eigen!(f32, f32, sstev, sstedc, sstevr);
+impl_tridiagonal_eigen!(f64, f64, dstev, dstedc, dstevr);
+impl_tridiagonal_eigen!(c32, f32, sstev, sstedc, sstevr);
+impl_tridiagonal_eigen!(c64, f64, dstev, dstedc, dstevr);
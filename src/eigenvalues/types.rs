@@ -11,6 +11,13 @@ pub enum EigenError {
     /// The input matrix does not have a conforming layout.
     BadLayout,
 
+    /// The dimensions of the input matrices don't match.
+    InconsistentDimensions,
+
+    /// The layouts of the input matrices don't match. (i.e. one is
+    /// column-major and the other is row-major.)
+    InconsistentLayout,
+
     /// The parameter to the LAPACKE method was invalid.
     ///
     /// This error should not occur in user-facing code.
@@ -18,6 +25,13 @@ pub enum EigenError {
 
     /// Eigenvalues could not be found.
     Failed,
+
+    /// The requested range of eigenvalues is empty or out of bounds.
+    InvalidRange,
+
+    /// The matrix `B` of a symmetric-definite generalized problem is
+    /// not positive definite.
+    NotPositiveDefinite,
 }
 
 /// Solution to an eigenvalue problem.
@@ -39,3 +53,109 @@ pub struct Solution<IV, EV> {
     /// in `right_vectors`.
     pub right_vectors: Option<Array<IV, Ix2>>,
 }
+
+/// Solution to a generalized eigenvalue problem.
+///
+/// Each eigenvalue is represented as the pair `(alpha, beta)`, with
+/// the eigenvalue being the ratio `alpha / beta`. An eigenvalue with
+/// `beta` equal to zero is infinite, which occurs when the matrix `B`
+/// is singular.
+#[derive(Debug)]
+pub struct GeneralizedSolution<IV, EV> {
+    /// Numerators of the eigenvalues.
+    pub alpha: Array<EV, Ix1>,
+
+    /// Denominators of the eigenvalues.
+    pub beta: Array<EV, Ix1>,
+
+    /// Eigenvectors for the left-eigenvalue problem ($y^H A = \lambda y^H B$)
+    pub left_vectors: Option<Array<IV, Ix2>>,
+
+    /// Eigenvectors for the right-eigenvalue problem ($Ax = \lambda Bx$)
+    pub right_vectors: Option<Array<IV, Ix2>>,
+}
+
+impl<IV, EV: LinxalImplScalar> GeneralizedSolution<IV, EV> {
+    /// Return the eigenvalues `alpha / beta`, where infinite
+    /// eigenvalues are `None`.
+    ///
+    /// Only eigenvalues with `beta` exactly zero are treated as
+    /// infinite. When `B` is nearly singular, `beta` should instead
+    /// be compared against an appropriate tolerance.
+    pub fn values(&self) -> Vec<Option<EV>> {
+        self.alpha.iter().zip(self.beta.iter()).map(|(&a, &b)| {
+            if b.mag() == EV::RealPart::zero() {
+                None
+            } else {
+                Some(a / b)
+            }
+        }).collect()
+    }
+}
+
+/// Balancing applied to a general matrix before computing its
+/// eigenvalues.
+///
+/// Balancing can improve the accuracy of the eigenvalues and
+/// eigenvectors of badly scaled matrices.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Balance {
+    /// Do not balance the matrix.
+    None = b'N',
+
+    /// Permute the matrix to isolate eigenvalues, where possible.
+    Permute = b'P',
+
+    /// Scale the rows and columns to make them closer in norm.
+    Scale = b'S',
+
+    /// Both permute and scale the matrix.
+    Both = b'B',
+}
+
+/// Solution to an eigenvalue problem from `Eigen::compute_expert`.
+///
+/// In addition to the eigenvalues and eigenvectors, contains the
+/// balancing applied to the matrix and the reciprocal condition
+/// numbers of the eigenvalues and right eigenvectors. A small
+/// reciprocal condition number indicates that the corresponding
+/// eigenvalue or eigenvector is sensitive to perturbations of the
+/// matrix.
+#[derive(Debug)]
+pub struct ExpertEigenSolution<T: LinxalImplScalar> {
+    /// Eigenvalues and requested eigenvectors of the (unbalanced)
+    /// matrix.
+    pub solution: Solution<T, T::Complex>,
+
+    /// Balancing permutation.
+    ///
+    /// For rows and columns outside of `ilo..ihi`, the entry is the
+    /// index of the row and column interchanged with it. The
+    /// interchanges are applied for indices `n - 1` down to `ihi`,
+    /// then `0` up to `ilo - 1`. For rows and columns inside the
+    /// range, the entry is the index itself.
+    pub permutation: Vec<usize>,
+
+    /// Scale factor applied to each row and column by balancing.
+    ///
+    /// Rows and columns outside of `ilo..ihi` are not scaled.
+    pub scale: Array<T::RealPart, Ix1>,
+
+    /// Start of the range of rows and columns that were not isolated
+    /// by permuting.
+    pub ilo: usize,
+
+    /// End (exclusive) of the range of rows and columns that were not
+    /// isolated by permuting.
+    pub ihi: usize,
+
+    /// 1-norm of the balanced matrix.
+    pub norm: T::RealPart,
+
+    /// Reciprocal condition number of each eigenvalue.
+    pub rcond_values: Array<T::RealPart, Ix1>,
+
+    /// Reciprocal condition number of each right eigenvector.
+    pub rcond_vectors: Array<T::RealPart, Ix1>,
+}
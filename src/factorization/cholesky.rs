@@ -3,10 +3,27 @@
 //! An (n x n) symmetric, positive definite matrix `A` is factored
 //! into the product `L` * `L^H` = `A`, for a lower-triangular matrix
 //! `L`.
+//!
+//! A `CholeskyFactors` can be reused to solve systems of linear
+//! equations, invert the original matrix, and compute its
+//! determinant, without refactoring.
+//!
+//! The factors can also be modified in place by rank-one updates
+//! `A + x * x^H` and downdates `A - x * x^H`, in `O(n^2)` time instead
+//! of the `O(n^3)` time required to refactor.
+//!
+//! The pivoted Cholesky-factorization instead factors `P^T` * `A` *
+//! `P` = `L` * `L^H` for a permutation matrix `P`, and also applies
+//! to positive semi-definite matrices. The factorization stops once
+//! the remaining pivots are negligible, revealing the rank of `A`.
 
 use impl_prelude::*;
-use lapack::c::{spotrf, cpotrf, dpotrf, zpotrf};
-use util::external::make_triangular_into;
+use lapack::c::{spotrf, cpotrf, dpotrf, zpotrf, spocon, dpocon, cpocon, zpocon};
+use lapack::c::{spotrs, dpotrs, cpotrs, zpotrs, spotri, dpotri, cpotri, zpotri};
+use lapack::c::{spstrf, dpstrf, cpstrf, zpstrf};
+use util::external::{make_triangular_into, conj_t};
+use norms::{Norm, NormType, NormError};
+use num_traits::{Float, One};
 
 /// Error for Cholesky-based computations.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -20,10 +37,326 @@ pub enum CholeskyError {
     /// The matrix is not positive definite.
     NotPositiveDefinite,
 
+    /// The dimensions of the factor and the right-hand side don't
+    /// match.
+    InconsistentDimensions,
+
+    /// The layout of the right-hand side does not match the layout
+    /// of the factored matrix. (i.e. one is column-major and the
+    /// other is row-major.)
+    InconsistentLayout,
+
     /// Implementation error, please submit as bug.
     IllegalParameter(i32),
 }
 
+/// Representation of the triangular factor of a Cholesky
+/// factorization of a matrix `A`.
+///
+/// The factor is stored in the triangle specified by `uplo`, so that
+/// `U^H` * `U` = `A` for `Symmetric::Upper` and `L` * `L^H` = `A` for
+/// `Symmetric::Lower`.
+#[derive(Debug)]
+pub struct CholeskyFactors<T: Cholesky> {
+    mat: Array<T, Ix2>,
+    uplo: Symmetric,
+
+    /// 1-norm of the original matrix, for condition number
+    /// estimation. Unknown after the factor is updated.
+    anorm: Option<T::RealPart>,
+}
+
+impl<T: Cholesky> CholeskyFactors<T> {
+    /// Return the side of the matrix on which the factor is stored.
+    pub fn uplo(&self) -> Symmetric {
+        self.uplo
+    }
+
+    /// Return a reference to the triangular factor.
+    pub fn factor(&self) -> &Array<T, Ix2> {
+        &self.mat
+    }
+
+    /// Return the triangular factor, consuming the factorization.
+    pub fn into_factor(self) -> Array<T, Ix2> {
+        self.mat
+    }
+
+    /// Return the lower-triangular factor `L`, such that `L` * `L^H`
+    /// = `A`.
+    pub fn l(&self) -> Array<T, Ix2> {
+        match self.uplo {
+            Symmetric::Lower => self.mat.clone(),
+            Symmetric::Upper => conj_t(&self.mat),
+        }
+    }
+
+    /// Return the upper-triangular factor `U`, such that `U^H` * `U`
+    /// = `A`.
+    pub fn u(&self) -> Array<T, Ix2> {
+        match self.uplo {
+            Symmetric::Upper => self.mat.clone(),
+            Symmetric::Lower => conj_t(&self.mat),
+        }
+    }
+
+    /// Solve the system `A * X = B` for the original matrix `A`,
+    /// consuming the right-hand sides `b`.
+    ///
+    /// Each column of `b` is a right-hand side to be solved for. The
+    /// layout of `b` must match the layout of the factored matrix,
+    /// unless `b` is a single column.
+    pub fn solve_multi_into<D>(&self, b: ArrayBase<D, Ix2>) -> Result<ArrayBase<D, Ix2>, CholeskyError>
+        where D: DataOwned<Elem = T> + DataMut<Elem = T>
+    {
+        Cholesky::compute_solve_multi_into(&self.mat, self.uplo, b)
+    }
+
+    /// Solve the system `A * X = B` for the original matrix
+    /// `A`. (see `Self::solve_multi_into`).
+    ///
+    /// `b` is copied into a matrix of the appropriate layout
+    /// beforehand, so any layout is accepted.
+    pub fn solve_multi<D>(&self, b: &ArrayBase<D, Ix2>) -> Result<Array<T, Ix2>, CholeskyError>
+        where D: Data<Elem = T>
+    {
+        self.solve_multi_into(copy_with_matching_layout(b, &self.mat))
+    }
+
+    /// Solve the system `A * x = b` for the original matrix `A` and a
+    /// single column vector `b`, consuming `b`.
+    pub fn solve_into<D>(&self, b: ArrayBase<D, Ix1>) -> Result<ArrayBase<D, Ix1>, CholeskyError>
+        where D: DataOwned<Elem = T> + DataMut<Elem = T>
+    {
+        let n = b.dim();
+
+        // Create a new matrix, where the column vector is a degenerate 2-D matrix.
+        let b_mat = match b.into_shape((n, 1)) {
+            Ok(x) => x,
+            Err(_) => return Err(CholeskyError::BadLayout),
+        };
+
+        let res = try!(self.solve_multi_into(b_mat));
+
+        // Reshape the matrix into a vector and return.
+        Ok(res.into_shape(n).unwrap())
+    }
+
+    /// Solve the system `A * x = b` for the original matrix `A` and a
+    /// single column vector `b`.
+    pub fn solve<D>(&self, b: &ArrayBase<D, Ix1>) -> Result<Array<T, Ix1>, CholeskyError>
+        where D: Data<Elem = T>
+    {
+        self.solve_into(b.to_owned())
+    }
+
+    /// Return the inverse of the original matrix.
+    ///
+    /// Both triangles of the inverse are filled in.
+    pub fn inverse(&self) -> Result<Array<T, Ix2>, CholeskyError> {
+        Cholesky::compute_inverse_into(self.mat.clone(), self.uplo)
+    }
+
+    /// Return the inverse of the original matrix, consuming the
+    /// factorization.
+    pub fn inverse_into(self) -> Result<Array<T, Ix2>, CholeskyError> {
+        Cholesky::compute_inverse_into(self.mat, self.uplo)
+    }
+
+    /// Return the determinant of the original matrix.
+    ///
+    /// The determinant of a positive definite matrix is positive and
+    /// real, but may overflow or underflow for large matrices. Use
+    /// `Self::log_det` instead in that case.
+    pub fn det(&self) -> T::RealPart {
+        self.mat.diag().iter().fold(T::RealPart::one(), |acc, x| {
+            let m = x.mag();
+            acc * m * m
+        })
+    }
+
+    /// Return the natural logarithm of the determinant of the
+    /// original matrix.
+    pub fn log_det(&self) -> T::RealPart {
+        let half_logdet = self.mat.diag().iter()
+            .fold(T::RealPart::zero(), |acc, x| acc + x.mag().ln());
+        half_logdet + half_logdet
+    }
+
+    /// Return an estimate of the reciprocal condition number of the
+    /// original matrix, in the 1-norm.
+    ///
+    /// Since the original matrix is symmetric, the 1-norm and
+    /// infinity-norm condition numbers are identical.
+    ///
+    /// After `Self::update` or `Self::downdate`, the norm of the
+    /// modified matrix is recomputed from the factor, which requires
+    /// `O(n^3)` time.
+    pub fn rcond(&self) -> Result<T::RealPart, CholeskyError> {
+        let anorm = match self.anorm {
+            Some(x) => x,
+            None => {
+                // The 1-norm is the maximum absolute column sum.
+                let zero = T::RealPart::zero();
+                self.l().dot(&self.u()).axis_iter(Axis(1))
+                    .map(|col| col.iter().fold(zero, |acc, x| acc + x.mag()))
+                    .fold(zero, |acc, x| acc.max(x))
+            }
+        };
+        Cholesky::compute_rcond(&self.mat, self.uplo, anorm)
+    }
+
+    /// Update the factorization of `A` to the factorization of `A +
+    /// x * x^H`, in `O(n^2)` time.
+    pub fn update<D>(&mut self, x: &ArrayBase<D, Ix1>) -> Result<(), CholeskyError>
+        where D: Data<Elem = T>
+    {
+        self.rank_one_modify(x, T::RealPart::one())
+    }
+
+    /// Update the factorization of `A` to the factorization of `A -
+    /// x * x^H`, in `O(n^2)` time.
+    ///
+    /// # Errors
+    ///
+    /// Returns `CholeskyError::NotPositiveDefinite` if `A - x * x^H`
+    /// is not positive definite. The factorization is left unchanged
+    /// in that case.
+    pub fn downdate<D>(&mut self, x: &ArrayBase<D, Ix1>) -> Result<(), CholeskyError>
+        where D: Data<Elem = T>
+    {
+        self.rank_one_modify(x, -T::RealPart::one())
+    }
+
+    /// Apply the rank-one modification `A + sign * x * x^H` to the
+    /// factor, via a sequence of (hyperbolic, for downdates)
+    /// rotations.
+    fn rank_one_modify<D>(&mut self, x: &ArrayBase<D, Ix1>, sign: T::RealPart)
+                          -> Result<(), CholeskyError>
+        where D: Data<Elem = T>
+    {
+        let n = self.mat.rows();
+        if x.dim() != n {
+            return Err(CholeskyError::InconsistentDimensions);
+        }
+
+        // Work on a copy of the lower factor, so that a failed
+        // downdate leaves the factorization unchanged.
+        let mut l = self.l();
+        let mut x = x.to_owned();
+        let sg = T::from_real(sign);
+
+        for k in 0..n {
+            let lkk = l[(k, k)].mag();
+            let xk = x[k];
+            let xk_mag = xk.mag();
+
+            let r2 = lkk * lkk + sign * xk_mag * xk_mag;
+            if !(r2 > T::RealPart::zero()) {
+                return Err(CholeskyError::NotPositiveDefinite);
+            }
+
+            let r = r2.sqrt();
+            let c = T::from_real(r / lkk);
+            let s = xk / T::from_real(lkk);
+            l[(k, k)] = T::from_real(r);
+
+            for i in k + 1..n {
+                let li = (l[(i, k)] + sg * s.cj() * x[i]) / c;
+                x[i] = c * x[i] - s * li;
+                l[(i, k)] = li;
+            }
+        }
+
+        match self.uplo {
+            Symmetric::Lower => self.mat.assign(&l),
+            Symmetric::Upper => self.mat.assign(&conj_t(&l)),
+        }
+        self.anorm = None;
+        Ok(())
+    }
+}
+
+/// Representation of the triangular factor and permutation of a
+/// pivoted Cholesky factorization of a positive semi-definite matrix
+/// `A`.
+///
+/// The factor is stored in the triangle specified by `uplo`, so that
+/// `U^H` * `U` = `P^T` * `A` * `P` for `Symmetric::Upper` and `L` *
+/// `L^H` = `P^T` * `A` * `P` for `Symmetric::Lower`. Only the first
+/// `rank` rows (or columns) of the factor are non-zero.
+#[derive(Debug)]
+pub struct CholeskyPFactors<T: Cholesky> {
+    mat: Array<T, Ix2>,
+    uplo: Symmetric,
+
+    /// symmetric permutation in native fortran format
+    piv: Vec<i32>,
+
+    rank: usize,
+}
+
+impl<T: Cholesky> CholeskyPFactors<T> {
+    /// Return the side of the matrix on which the factor is stored.
+    pub fn uplo(&self) -> Symmetric {
+        self.uplo
+    }
+
+    /// Return the computed rank of the original matrix.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Return a reference to the `n` by `n` triangular factor.
+    pub fn factor(&self) -> &Array<T, Ix2> {
+        &self.mat
+    }
+
+    /// Return the symmetric permutation as a list of indices.
+    ///
+    /// The `j`th row and column of `P^T` * `A` * `P` are the
+    /// `permutation()[j]`th row and column of `A`.
+    pub fn permutation(&self) -> Vec<usize> {
+        self.piv.iter().map(|&j| j as usize - 1).collect()
+    }
+
+    /// Return the `n` by `n` permutation matrix `P`.
+    pub fn p(&self) -> Array<T, Ix2> {
+        let n = self.piv.len();
+        let mut p = Array::zeros((n, n));
+        for (j, i) in self.permutation().into_iter().enumerate() {
+            p[(i, j)] = T::one();
+        }
+        p
+    }
+
+    /// Return the `n` by `rank` matrix `G`, such that `G` * `G^H` =
+    /// `A`, up to the tolerance of the factorization.
+    ///
+    /// `G` is the lower-triangular factor with its rows permuted back
+    /// to the original order, and truncated to the computed rank. It
+    /// can be used to sample from a (possibly degenerate) multivariate
+    /// normal distribution with covariance `A`.
+    pub fn low_rank_factor(&self) -> Array<T, Ix2> {
+        let l = match self.uplo {
+            Symmetric::Lower => self.mat.slice(s![.., ..self.rank as isize]).to_owned(),
+            Symmetric::Upper => conj_t(&self.mat.slice(s![..self.rank as isize, ..])),
+        };
+
+        let mut g = Array::zeros(l.dim());
+        for (k, i) in self.permutation().into_iter().enumerate() {
+            g.row_mut(i).assign(&l.row(k));
+        }
+        g
+    }
+
+    /// Reconstruct the original matrix `A` from the factorization.
+    pub fn reconstruct(&self) -> Array<T, Ix2> {
+        let g = self.low_rank_factor();
+        g.dot(&conj_t(&g))
+    }
+}
+
 /// Trait defined on scalars to support Cholesky-factorization.
 pub trait Cholesky: LinxalImplScalar {
     /// Return a triangular matrix satisfying the Cholesky
@@ -50,10 +383,81 @@ pub trait Cholesky: LinxalImplScalar {
     {
         Self::compute_into(a.to_owned(), uplo)
     }
+
+    /// Return a `CholeskyFactors` structure, containing the Cholesky
+    /// factorization of the input matrix `A`, consuming the input.
+    ///
+    /// Only the portion of `A` specified by `uplo` is read.
+    fn compute_factors_into(a: Array<Self, Ix2>, uplo: Symmetric)
+                            -> Result<CholeskyFactors<Self>, CholeskyError>;
+
+    /// Return a `CholeskyFactors` structure, containing the Cholesky
+    /// factorization of the input matrix `A`.
+    fn compute_factors<D1>(a: &ArrayBase<D1, Ix2>, uplo: Symmetric)
+                           -> Result<CholeskyFactors<Self>, CholeskyError>
+        where D1: Data<Elem = Self>
+    {
+        Self::compute_factors_into(a.to_owned(), uplo)
+    }
+
+    /// Return a `CholeskyPFactors` structure, containing the pivoted
+    /// Cholesky factorization of the positive semi-definite input
+    /// matrix `A`, consuming the input.
+    ///
+    /// Only the portion of `A` specified by `uplo` is read. The
+    /// factorization stops when the largest remaining pivot is at
+    /// most `tolerance`. When `tolerance` is None, `n` times the
+    /// machine epsilon times the largest diagonal element of `A` is
+    /// used.
+    ///
+    /// Indefinite matrices are not detected; the factorization stops
+    /// at the first non-positive pivot instead.
+    fn compute_pivoted_into<Tol>(a: Array<Self, Ix2>, uplo: Symmetric, tolerance: Tol)
+                                 -> Result<CholeskyPFactors<Self>, CholeskyError>
+        where Tol: Into<Option<Self::RealPart>>;
+
+    /// Return a `CholeskyPFactors` structure, containing the pivoted
+    /// Cholesky factorization of the positive semi-definite input
+    /// matrix `A`. (see `Self::compute_pivoted_into`).
+    fn compute_pivoted<D1, Tol>(a: &ArrayBase<D1, Ix2>, uplo: Symmetric, tolerance: Tol)
+                                -> Result<CholeskyPFactors<Self>, CholeskyError>
+        where D1: Data<Elem = Self>,
+              Tol: Into<Option<Self::RealPart>>
+    {
+        Self::compute_pivoted_into(a.to_owned(), uplo, tolerance)
+    }
+
+    /// Return an estimate of the reciprocal condition number of `A`
+    /// from its Cholesky factor and its 1-norm `anorm`.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_rcond<D1>(mat: &ArrayBase<D1, Ix2>, uplo: Symmetric, anorm: Self::RealPart)
+                         -> Result<Self::RealPart, CholeskyError>
+        where D1: Data<Elem = Self>;
+
+    /// Solve `A * X = B` from the Cholesky factor of `A`, overwriting
+    /// `b` with the solution.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_solve_multi_into<D1, D2>(mat: &ArrayBase<D1, Ix2>,
+                                        uplo: Symmetric,
+                                        b: ArrayBase<D2, Ix2>)
+                                        -> Result<ArrayBase<D2, Ix2>, CholeskyError>
+        where D1: Data<Elem = Self>,
+              D2: DataOwned<Elem = Self> + DataMut<Elem = Self>;
+
+    /// Return the inverse of `A` from its Cholesky factor, consuming
+    /// the factor.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_inverse_into<D1>(mat: ArrayBase<D1, Ix2>, uplo: Symmetric)
+                                -> Result<ArrayBase<D1, Ix2>, CholeskyError>
+        where D1: DataOwned<Elem = Self> + DataMut<Elem = Self>;
 }
 
 macro_rules! impl_cholesky {
-    ($chol_type:ty, $chol_func:ident) => (
+    ($chol_type:ty, $chol_func:ident, $chol_cond:ident, $chol_solve:ident, $chol_inv:ident,
+     $chol_piv:ident) => (
 
         impl Cholesky for $chol_type {
             fn compute_into<D>(mut a: ArrayBase<D, Ix2>, uplo: Symmetric)
@@ -88,11 +492,174 @@ macro_rules! impl_cholesky {
                     Err(CholeskyError::NotPositiveDefinite)
                 }
             }
+
+            fn compute_factors_into(a: Array<Self, Ix2>, uplo: Symmetric)
+                                    -> Result<CholeskyFactors<Self>, CholeskyError> {
+                // The norm must be computed before `a` is overwritten.
+                let anorm = match Norm::compute_symmetric(&a, uplo, NormType::One) {
+                    Ok(x) => x,
+                    Err(NormError::BadLayout) => return Err(CholeskyError::BadLayout),
+                    Err(NormError::NotSquare) => return Err(CholeskyError::NotSquare),
+                    Err(NormError::IllegalParameter(i)) => return Err(CholeskyError::IllegalParameter(i)),
+                    Err(NormError::ContainsNaN) => Self::RealPart::nan(),
+                    // Only the spectral and nuclear norms are iterative.
+                    Err(NormError::Unconverged) => unreachable!(),
+                };
+
+                let mat = try!(Self::compute_into(a, uplo));
+
+                Ok(CholeskyFactors {
+                    mat: mat,
+                    uplo: uplo,
+                    anorm: Some(anorm),
+                })
+            }
+
+            fn compute_pivoted_into<Tol>(mut a: Array<Self, Ix2>, uplo: Symmetric, tolerance: Tol)
+                                         -> Result<CholeskyPFactors<Self>, CholeskyError>
+                where Tol: Into<Option<Self::RealPart>> {
+                let dim = a.dim();
+                if dim.0 != dim.1 {
+                    return Err(CholeskyError::NotSquare);
+                }
+
+                // A negative tolerance selects the LAPACK default.
+                let tol = tolerance.into().unwrap_or(-1.0);
+
+                let mut piv = vec![0; dim.0];
+                let mut rank = 0;
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                        None => return Err(CholeskyError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $chol_piv(layout, uplo as u8, dim.0 as i32, slice, lda as i32,
+                                  &mut piv, &mut rank, tol)
+                    }
+                };
+
+                // A positive `info` only indicates that the matrix is
+                // rank-deficient.
+                if info < 0 {
+                    return Err(CholeskyError::IllegalParameter(-info));
+                }
+
+                // The trailing block holds the unfactored remainder,
+                // which is discarded.
+                let rank = rank as usize;
+                let mut mat = make_triangular_into(a, uplo);
+                mat.slice_mut(s![rank as isize.., rank as isize..]).fill(<$chol_type as Zero>::zero());
+
+                Ok(CholeskyPFactors {
+                    mat: mat,
+                    uplo: uplo,
+                    piv: piv,
+                    rank: rank,
+                })
+            }
+
+            fn compute_rcond<D1>(mat: &ArrayBase<D1, Ix2>, uplo: Symmetric, anorm: Self::RealPart)
+                                 -> Result<Self::RealPart, CholeskyError>
+                where D1: Data<Elem = Self> {
+                let dim = mat.dim();
+                if dim.0 != dim.1 {
+                    return Err(CholeskyError::NotSquare);
+                }
+
+                let (slice, layout, lda) = match slice_and_layout(mat) {
+                    None => return Err(CholeskyError::BadLayout),
+                    Some(x) => x,
+                };
+
+                let mut rcond = 0.0;
+                let info = unsafe {
+                    $chol_cond(layout, uplo as u8, dim.0 as i32, slice, lda as i32, anorm, &mut rcond)
+                };
+
+                if info == 0 {
+                    Ok(rcond)
+                } else {
+                    Err(CholeskyError::IllegalParameter(-info))
+                }
+            }
+
+            fn compute_solve_multi_into<D1, D2>(mat: &ArrayBase<D1, Ix2>,
+                                                uplo: Symmetric,
+                                                mut b: ArrayBase<D2, Ix2>)
+                                                -> Result<ArrayBase<D2, Ix2>, CholeskyError>
+                where D1: Data<Elem = Self>,
+                      D2: DataOwned<Elem = Self> + DataMut<Elem = Self> {
+                let dim = mat.dim();
+                let b_dim = b.dim();
+                if dim.0 != dim.1 {
+                    return Err(CholeskyError::NotSquare);
+                }
+                if dim.0 != b_dim.0 {
+                    return Err(CholeskyError::InconsistentDimensions);
+                }
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout(mat) {
+                        None => return Err(CholeskyError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    let (b_slice, ldb) = match slice_and_layout_matching_mut(&mut b, layout) {
+                        None => return Err(CholeskyError::InconsistentLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $chol_solve(layout, uplo as u8, dim.0 as i32, b_dim.1 as i32,
+                                    slice, lda as i32, b_slice, ldb as i32)
+                    }
+                };
+
+                if info == 0 {
+                    Ok(b)
+                } else {
+                    Err(CholeskyError::IllegalParameter(-info))
+                }
+            }
+
+            fn compute_inverse_into<D1>(mut mat: ArrayBase<D1, Ix2>, uplo: Symmetric)
+                                        -> Result<ArrayBase<D1, Ix2>, CholeskyError>
+                where D1: DataOwned<Elem = Self> + DataMut<Elem = Self> {
+                let dim = mat.dim();
+                if dim.0 != dim.1 {
+                    return Err(CholeskyError::NotSquare);
+                }
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout_mut(&mut mat) {
+                        None => return Err(CholeskyError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $chol_inv(layout, uplo as u8, dim.0 as i32, slice, lda as i32)
+                    }
+                };
+
+                if info == 0 {
+                    // Only the `uplo` triangle of the inverse is
+                    // computed.
+                    fill_hermitian(&mut mat, uplo);
+                    Ok(mat)
+                } else if info < 0 {
+                    Err(CholeskyError::IllegalParameter(-info))
+                } else {
+                    Err(CholeskyError::NotPositiveDefinite)
+                }
+            }
         }
     )
 }
 
-impl_cholesky!(f32, spotrf);
-impl_cholesky!(f64, dpotrf);
-impl_cholesky!(c32, cpotrf);
-impl_cholesky!(c64, zpotrf);
+impl_cholesky!(f32, spotrf, spocon, spotrs, spotri, spstrf);
+impl_cholesky!(f64, dpotrf, dpocon, dpotrs, dpotri, dpstrf);
+impl_cholesky!(c32, cpotrf, cpocon, cpotrs, cpotri, cpstrf);
+impl_cholesky!(c64, zpotrf, zpocon, zpotrs, zpotri, zpstrf);
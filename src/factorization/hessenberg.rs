@@ -0,0 +1,226 @@
+//! Compute the Hessenberg reduction of a square matrix.
+//!
+//! An (n x n) matrix `A` is reduced to the product `Q` * `H` *
+//! `Q^H` = `A`, such that
+//!
+//! - `Q` is unitary (orthogonal, for real matrices).
+//!
+//! - `H` is upper Hessenberg, i.e. zero below the first subdiagonal.
+//!
+//! The reduction is the first step of the non-symmetric eigenvalue
+//! drivers. Since the shift `A - sigma * I = Q * (H - sigma * I) *
+//! Q^H` preserves the Hessenberg structure, a single reduction can
+//! be reused to solve shifted systems in `O(n^2)` operations per
+//! shift.
+
+use impl_prelude::*;
+use lapack::c::{sgehrd, dgehrd, cgehrd, zgehrd, sorghr, dorghr, cunghr, zunghr};
+use util::external::conj_t;
+
+/// Error for Hessenberg and tridiagonal reductions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReductionError {
+    /// The layout of the matrix is not compatible
+    BadLayout,
+
+    /// The matrix is not square.
+    NotSquare,
+
+    /// The dimensions of the reduced matrix and the right-hand side
+    /// don't match.
+    InconsistentDimensions,
+
+    /// The shifted matrix is exactly singular.
+    Singular,
+
+    /// Implementation error, please submit as bug.
+    IllegalParameter(i32),
+}
+
+/// Representation of the Hessenberg reduction `A = Q * H * Q^H` of a
+/// square matrix.
+#[derive(Debug, Clone)]
+pub struct HessenbergFactors<T: Hessenberg> {
+    q: Array<T, Ix2>,
+    h: Array<T, Ix2>,
+}
+
+impl<T: Hessenberg> HessenbergFactors<T> {
+    /// Return the unitary matrix `Q`.
+    pub fn q(&self) -> &Array<T, Ix2> {
+        &self.q
+    }
+
+    /// Return the upper Hessenberg matrix `H`.
+    pub fn h(&self) -> &Array<T, Ix2> {
+        &self.h
+    }
+
+    /// Return the factors `Q` and `H`, consuming the reduction.
+    pub fn into_parts(self) -> (Array<T, Ix2>, Array<T, Ix2>) {
+        (self.q, self.h)
+    }
+
+    /// Reconstruct the original matrix `A` from the reduction.
+    pub fn reconstruct(&self) -> Array<T, Ix2> {
+        self.q.dot(&self.h).dot(&conj_t(&self.q))
+    }
+
+    /// Return the solution `X` to the shifted system `(A - sigma * I)
+    /// * X = B`.
+    ///
+    /// Each column of `b` is a RHS vector to be solved for. The
+    /// Hessenberg system is solved by Gaussian elimination with
+    /// partial pivoting, in `O(n^2)` operations per column.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ReductionError::Singular` when `sigma` is an exact
+    /// eigenvalue of `A`.
+    pub fn solve_shifted_multi<D>(&self, sigma: T, b: &ArrayBase<D, Ix2>)
+                                  -> Result<Array<T, Ix2>, ReductionError>
+        where D: Data<Elem = T>
+    {
+        let n = self.h.rows();
+        if b.rows() != n {
+            return Err(ReductionError::InconsistentDimensions);
+        }
+
+        let mut h = self.h.to_owned();
+        for i in 0..n {
+            h[(i, i)] = h[(i, i)] - sigma;
+        }
+        let mut c = conj_t(&self.q).dot(b);
+
+        // Reduce `H - sigma * I` to upper-triangular form. Only the
+        // subdiagonal needs to be eliminated, so pivoting is limited
+        // to adjacent rows.
+        for k in 0..n.saturating_sub(1) {
+            if h[(k + 1, k)].mag() > h[(k, k)].mag() {
+                for j in k..n {
+                    let x = h[(k, j)];
+                    h[(k, j)] = h[(k + 1, j)];
+                    h[(k + 1, j)] = x;
+                }
+                for j in 0..c.cols() {
+                    let x = c[(k, j)];
+                    c[(k, j)] = c[(k + 1, j)];
+                    c[(k + 1, j)] = x;
+                }
+            }
+            if h[(k, k)].is_zero() {
+                return Err(ReductionError::Singular);
+            }
+
+            let l = h[(k + 1, k)] / h[(k, k)];
+            for j in k..n {
+                h[(k + 1, j)] = h[(k + 1, j)] - l * h[(k, j)];
+            }
+            for j in 0..c.cols() {
+                c[(k + 1, j)] = c[(k + 1, j)] - l * c[(k, j)];
+            }
+        }
+
+        // Back-substitute the triangular system.
+        for i in (0..n).rev() {
+            if h[(i, i)].is_zero() {
+                return Err(ReductionError::Singular);
+            }
+            for j in 0..c.cols() {
+                let mut x = c[(i, j)];
+                for l in i + 1..n {
+                    x = x - h[(i, l)] * c[(l, j)];
+                }
+                c[(i, j)] = x / h[(i, i)];
+            }
+        }
+
+        Ok(self.q.dot(&c))
+    }
+
+    /// Return the solution `x` to the shifted system `(A - sigma * I)
+    /// * x = b`. (see `Self::solve_shifted_multi`).
+    pub fn solve_shifted<D>(&self, sigma: T, b: &ArrayBase<D, Ix1>)
+                            -> Result<Array<T, Ix1>, ReductionError>
+        where D: Data<Elem = T>
+    {
+        let m = b.dim();
+
+        // Create a new matrix, where the column vector is a degenerate 2-D matrix.
+        let b_mat = b.to_owned().into_shape((m, 1)).unwrap();
+
+        let x = try!(self.solve_shifted_multi(sigma, &b_mat));
+        Ok(x.into_shape(m).unwrap())
+    }
+}
+
+/// Trait defined on scalars to support the Hessenberg reduction.
+pub trait Hessenberg: LinxalImplScalar {
+    /// Return the Hessenberg reduction of the square matrix `A`,
+    /// consuming the input.
+    fn compute_into(a: Array<Self, Ix2>) -> Result<HessenbergFactors<Self>, ReductionError>;
+
+    /// Return the Hessenberg reduction of the square matrix `A`.
+    fn compute<D>(a: &ArrayBase<D, Ix2>) -> Result<HessenbergFactors<Self>, ReductionError>
+        where D: Data<Elem = Self>
+    {
+        Self::compute_into(a.to_owned())
+    }
+}
+
+macro_rules! impl_hessenberg {
+    ($impl_type:ident, $reduce_func:ident, $q_func:ident) => (
+        impl Hessenberg for $impl_type {
+            fn compute_into(mut a: Array<Self, Ix2>) -> Result<HessenbergFactors<Self>, ReductionError> {
+                let dim = a.dim();
+                if dim.0 != dim.1 {
+                    return Err(ReductionError::NotSquare);
+                }
+                let n = dim.0;
+                if n == 0 {
+                    return Ok(HessenbergFactors { q: a.clone(), h: a });
+                }
+
+                let mut tau = vec![Self::zero(); n - 1];
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                        Some(x) => x,
+                        None => return Err(ReductionError::BadLayout),
+                    };
+                    unsafe {
+                        $reduce_func(layout, n as i32, 1, n as i32, slice, lda as i32, &mut tau)
+                    }
+                };
+                if info != 0 {
+                    return Err(ReductionError::IllegalParameter(-info));
+                }
+
+                // The reflectors are stored below the subdiagonal.
+                let mut q = a.clone();
+                let info = {
+                    let (slice, layout, ldq) = slice_and_layout_mut(&mut q).unwrap();
+                    unsafe {
+                        $q_func(layout, n as i32, 1, n as i32, slice, ldq as i32, &tau)
+                    }
+                };
+                if info != 0 {
+                    return Err(ReductionError::IllegalParameter(-info));
+                }
+
+                for ((i, j), x) in a.indexed_iter_mut() {
+                    if i > j + 1 {
+                        *x = Self::zero();
+                    }
+                }
+
+                Ok(HessenbergFactors { q: q, h: a })
+            }
+        }
+    )
+}
+
+impl_hessenberg!(f32, sgehrd, sorghr);
+impl_hessenberg!(f64, dgehrd, dorghr);
+impl_hessenberg!(c32, cgehrd, cunghr);
+impl_hessenberg!(c64, zgehrd, zunghr);
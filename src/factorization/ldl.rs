@@ -0,0 +1,410 @@
+//! Compute the symmetric-indefinite (LDL^H) factorization of a
+//! symmetric or Hermitian matrix.
+//!
+//! An (n x n) symmetric (or Hermitian) matrix `A` is factored into
+//! the product `P` * `L` * `D` * `L^H` * `P^T` = `A` (or `U` * `D` *
+//! `U^H` for the upper triangle), using the Bunch-Kaufman diagonal
+//! pivoting method, where
+//!
+//! - `L` is unit lower-triangular, and `P` is a permutation matrix.
+//!
+//! - `D` is symmetric (or Hermitian) and block-diagonal, with 1 x 1
+//! and 2 x 2 blocks.
+//!
+//! Unlike the Cholesky-factorization, `A` need not be positive
+//! definite. By Sylvester's law of inertia, `A` and `D` have the same
+//! number of positive, negative and zero eigenvalues, so the inertia
+//! of `A` can be read directly from `D`.
+
+use impl_prelude::*;
+use lapack::c::{ssytrf, dsytrf, chetrf, zhetrf, ssytrs, dsytrs, chetrs, zhetrs};
+use lapack::c::{ssytri, dsytri, chetri, zhetri};
+use num_traits::One;
+
+/// Error for LDL-based computations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LDLError {
+    /// The layout of the matrix is not compatible
+    BadLayout,
+
+    /// The matrix is not square.
+    NotSquare,
+
+    /// The matrix is singular, so the operation cannot be completed.
+    Singular,
+
+    /// The dimensions of the factor and the right-hand side don't
+    /// match.
+    InconsistentDimensions,
+
+    /// The layout of the right-hand side does not match the layout
+    /// of the factored matrix. (i.e. one is column-major and the
+    /// other is row-major.)
+    InconsistentLayout,
+
+    /// Implementation error, please submit as bug.
+    IllegalParameter(i32),
+}
+
+/// Counts of the positive, negative and zero eigenvalues of a
+/// symmetric or Hermitian matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Inertia {
+    /// Number of positive eigenvalues
+    pub positive: usize,
+
+    /// Number of negative eigenvalues
+    pub negative: usize,
+
+    /// Number of zero eigenvalues
+    pub zero: usize,
+}
+
+/// Representation of the factors `L` (or `U`), `D`, and the
+/// permutation of the symmetric-indefinite factorization of a matrix
+/// `A`.
+#[derive(Debug)]
+pub struct LDLFactors<T: LDL> {
+    mat: Array<T, Ix2>,
+    uplo: Symmetric,
+
+    /// pivots and block structure in native fortran format
+    ipiv: Vec<i32>,
+
+    /// whether a diagonal block of `D` is exactly singular
+    singular: bool,
+}
+
+impl<T: LDL> LDLFactors<T> {
+    /// Return the side of the matrix on which the factors are
+    /// stored.
+    pub fn uplo(&self) -> Symmetric {
+        self.uplo
+    }
+
+    /// Return the native LAPACKE representation of the pivots and
+    /// the block structure of `D`.
+    ///
+    /// This will only be useful for interfacing with other LAPACKE
+    /// functions.
+    pub fn ipiv(&self) -> &[i32] {
+        &self.ipiv
+    }
+
+    /// Return the diagonal blocks of `D`, as `(start, size)` pairs.
+    fn blocks(&self) -> Vec<(usize, usize)> {
+        let n = self.ipiv.len();
+        let mut blocks = Vec::new();
+        let mut k = 0;
+        while k < n {
+            // Both indices of a 2 x 2 block have negative pivots.
+            let size = if self.ipiv[k] < 0 && k + 1 < n { 2 } else { 1 };
+            blocks.push((k, size));
+            k += size;
+        }
+        blocks
+    }
+
+    /// Return the real diagonal entries and the off-diagonal entry of
+    /// the 2 x 2 block of `D` starting at `k`.
+    fn block_2x2(&self, k: usize) -> (T::RealPart, T, T::RealPart) {
+        let off = match self.uplo {
+            Symmetric::Upper => self.mat[(k, k + 1)],
+            Symmetric::Lower => self.mat[(k + 1, k)],
+        };
+        (self.mat[(k, k)].re(), off, self.mat[(k + 1, k + 1)].re())
+    }
+
+    /// Return the inertia of the original matrix.
+    ///
+    /// Eigenvalues are counted as zero only when the corresponding
+    /// block of `D` is exactly singular.
+    pub fn inertia(&self) -> Inertia {
+        let zero = T::RealPart::zero();
+        let mut inertia = Inertia {
+            positive: 0,
+            negative: 0,
+            zero: 0,
+        };
+
+        for (k, size) in self.blocks() {
+            if size == 1 {
+                let d = self.mat[(k, k)].re();
+                if d > zero {
+                    inertia.positive += 1;
+                } else if d < zero {
+                    inertia.negative += 1;
+                } else {
+                    inertia.zero += 1;
+                }
+            } else {
+                // The signs of the eigenvalues of a 2 x 2 Hermitian
+                // block follow from its determinant and trace.
+                let (a, b, c) = self.block_2x2(k);
+                let det = a * c - b.mag() * b.mag();
+                let trace = a + c;
+                if det < zero {
+                    inertia.positive += 1;
+                    inertia.negative += 1;
+                } else if det > zero {
+                    if trace > zero {
+                        inertia.positive += 2;
+                    } else {
+                        inertia.negative += 2;
+                    }
+                } else {
+                    inertia.zero += 1;
+                    if trace > zero {
+                        inertia.positive += 1;
+                    } else if trace < zero {
+                        inertia.negative += 1;
+                    } else {
+                        inertia.zero += 1;
+                    }
+                }
+            }
+        }
+
+        inertia
+    }
+
+    /// Return the determinant of the original matrix.
+    ///
+    /// The determinant of a symmetric real or Hermitian complex
+    /// matrix is real.
+    pub fn det(&self) -> T::RealPart {
+        self.blocks().into_iter().fold(T::RealPart::one(), |acc, (k, size)| {
+            if size == 1 {
+                acc * self.mat[(k, k)].re()
+            } else {
+                let (a, b, c) = self.block_2x2(k);
+                acc * (a * c - b.mag() * b.mag())
+            }
+        })
+    }
+
+    /// Solve the system `A * X = B` for the original matrix `A`,
+    /// consuming the right-hand sides `b`.
+    ///
+    /// Each column of `b` is a right-hand side to be solved for. The
+    /// layout of `b` must match the layout of the factored matrix,
+    /// unless `b` is a single column.
+    pub fn solve_multi_into<D>(&self, b: ArrayBase<D, Ix2>) -> Result<ArrayBase<D, Ix2>, LDLError>
+        where D: DataOwned<Elem = T> + DataMut<Elem = T>
+    {
+        if self.singular {
+            return Err(LDLError::Singular);
+        }
+        LDL::compute_solve_multi_into(&self.mat, self.uplo, &self.ipiv, b)
+    }
+
+    /// Solve the system `A * X = B` for the original matrix
+    /// `A`. (see `Self::solve_multi_into`).
+    ///
+    /// `b` is copied into a matrix of the appropriate layout
+    /// beforehand, so any layout is accepted.
+    pub fn solve_multi<D>(&self, b: &ArrayBase<D, Ix2>) -> Result<Array<T, Ix2>, LDLError>
+        where D: Data<Elem = T>
+    {
+        self.solve_multi_into(copy_with_matching_layout(b, &self.mat))
+    }
+
+    /// Solve the system `A * x = b` for the original matrix `A` and a
+    /// single column vector `b`, consuming `b`.
+    pub fn solve_into<D>(&self, b: ArrayBase<D, Ix1>) -> Result<ArrayBase<D, Ix1>, LDLError>
+        where D: DataOwned<Elem = T> + DataMut<Elem = T>
+    {
+        let n = b.dim();
+
+        // Create a new matrix, where the column vector is a degenerate 2-D matrix.
+        let b_mat = match b.into_shape((n, 1)) {
+            Ok(x) => x,
+            Err(_) => return Err(LDLError::BadLayout),
+        };
+
+        let res = try!(self.solve_multi_into(b_mat));
+
+        // Reshape the matrix into a vector and return.
+        Ok(res.into_shape(n).unwrap())
+    }
+
+    /// Solve the system `A * x = b` for the original matrix `A` and a
+    /// single column vector `b`.
+    pub fn solve<D>(&self, b: &ArrayBase<D, Ix1>) -> Result<Array<T, Ix1>, LDLError>
+        where D: Data<Elem = T>
+    {
+        self.solve_into(b.to_owned())
+    }
+
+    /// Return the inverse of the original matrix.
+    ///
+    /// Both triangles of the inverse are filled in.
+    pub fn inverse(&self) -> Result<Array<T, Ix2>, LDLError> {
+        if self.singular {
+            return Err(LDLError::Singular);
+        }
+        LDL::compute_inverse_into(self.mat.clone(), self.uplo, &self.ipiv)
+    }
+}
+
+/// Trait defined on scalars to support the symmetric-indefinite
+/// factorization.
+///
+/// For complex scalars, the input is assumed to be Hermitian.
+pub trait LDL: LinxalImplScalar {
+    /// Return a `LDLFactors` structure, containing the
+    /// symmetric-indefinite factorization of the input matrix `A`,
+    /// consuming the input.
+    ///
+    /// Only the portion of `A` specified by `uplo` is read. Singular
+    /// matrices are factored successfully, but cannot be used to
+    /// solve systems or compute inverses.
+    fn compute_into(a: Array<Self, Ix2>, uplo: Symmetric) -> Result<LDLFactors<Self>, LDLError>;
+
+    /// Return a `LDLFactors` structure, containing the
+    /// symmetric-indefinite factorization of the input matrix `A`.
+    fn compute<D1>(a: &ArrayBase<D1, Ix2>, uplo: Symmetric) -> Result<LDLFactors<Self>, LDLError>
+        where D1: Data<Elem = Self>
+    {
+        Self::compute_into(a.to_owned(), uplo)
+    }
+
+    /// Solve `A * X = B` from the raw factorization of `A`,
+    /// overwriting `b` with the solution.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_solve_multi_into<D1, D2>(mat: &ArrayBase<D1, Ix2>,
+                                        uplo: Symmetric,
+                                        ipiv: &[i32],
+                                        b: ArrayBase<D2, Ix2>)
+                                        -> Result<ArrayBase<D2, Ix2>, LDLError>
+        where D1: Data<Elem = Self>,
+              D2: DataOwned<Elem = Self> + DataMut<Elem = Self>;
+
+    /// Return the inverse of `A` from its raw factorization,
+    /// consuming the factors.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_inverse_into<D1>(mat: ArrayBase<D1, Ix2>, uplo: Symmetric, ipiv: &[i32])
+                                -> Result<ArrayBase<D1, Ix2>, LDLError>
+        where D1: DataOwned<Elem = Self> + DataMut<Elem = Self>;
+}
+
+macro_rules! impl_ldl {
+    ($ldl_type:ty, $ldl_func:ident, $ldl_solve:ident, $ldl_inv:ident) => (
+        impl LDL for $ldl_type {
+            fn compute_into(mut a: Array<Self, Ix2>, uplo: Symmetric)
+                            -> Result<LDLFactors<Self>, LDLError> {
+                let dim = a.dim();
+                if dim.0 != dim.1 {
+                    return Err(LDLError::NotSquare);
+                }
+
+                let mut ipiv = vec![0; dim.0];
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                        None => return Err(LDLError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $ldl_func(layout, uplo as u8, dim.0 as i32, slice, lda as i32, &mut ipiv)
+                    }
+                };
+
+                // A positive `info` indicates that `D` is exactly
+                // singular, but the factorization is complete.
+                if info < 0 {
+                    Err(LDLError::IllegalParameter(-info))
+                } else {
+                    Ok(LDLFactors {
+                        mat: a,
+                        uplo: uplo,
+                        ipiv: ipiv,
+                        singular: info > 0,
+                    })
+                }
+            }
+
+            fn compute_solve_multi_into<D1, D2>(mat: &ArrayBase<D1, Ix2>,
+                                                uplo: Symmetric,
+                                                ipiv: &[i32],
+                                                mut b: ArrayBase<D2, Ix2>)
+                                                -> Result<ArrayBase<D2, Ix2>, LDLError>
+                where D1: Data<Elem = Self>,
+                      D2: DataOwned<Elem = Self> + DataMut<Elem = Self> {
+                let dim = mat.dim();
+                let b_dim = b.dim();
+                if dim.0 != dim.1 {
+                    return Err(LDLError::NotSquare);
+                }
+                if dim.0 != b_dim.0 || dim.0 != ipiv.len() {
+                    return Err(LDLError::InconsistentDimensions);
+                }
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout(mat) {
+                        None => return Err(LDLError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    let (b_slice, ldb) = match slice_and_layout_matching_mut(&mut b, layout) {
+                        None => return Err(LDLError::InconsistentLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $ldl_solve(layout, uplo as u8, dim.0 as i32, b_dim.1 as i32,
+                                   slice, lda as i32, ipiv, b_slice, ldb as i32)
+                    }
+                };
+
+                if info == 0 {
+                    Ok(b)
+                } else {
+                    Err(LDLError::IllegalParameter(-info))
+                }
+            }
+
+            fn compute_inverse_into<D1>(mut mat: ArrayBase<D1, Ix2>, uplo: Symmetric, ipiv: &[i32])
+                                        -> Result<ArrayBase<D1, Ix2>, LDLError>
+                where D1: DataOwned<Elem = Self> + DataMut<Elem = Self> {
+                let dim = mat.dim();
+                if dim.0 != dim.1 {
+                    return Err(LDLError::NotSquare);
+                }
+                if dim.0 != ipiv.len() {
+                    return Err(LDLError::InconsistentDimensions);
+                }
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout_mut(&mut mat) {
+                        None => return Err(LDLError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $ldl_inv(layout, uplo as u8, dim.0 as i32, slice, lda as i32, ipiv)
+                    }
+                };
+
+                if info == 0 {
+                    // Only the `uplo` triangle of the inverse is
+                    // computed.
+                    fill_hermitian(&mut mat, uplo);
+                    Ok(mat)
+                } else if info < 0 {
+                    Err(LDLError::IllegalParameter(-info))
+                } else {
+                    Err(LDLError::Singular)
+                }
+            }
+        }
+    )
+}
+
+impl_ldl!(f32, ssytrf, ssytrs, ssytri);
+impl_ldl!(f64, dsytrf, dsytrs, dsytri);
+impl_ldl!(c32, chetrf, chetrs, chetri);
+impl_ldl!(c64, zhetrf, zhetrs, zhetri);
@@ -0,0 +1,398 @@
+//! Compute the LQ-factorization of a rectangular matrix.
+//!
+//! An (m x n) rectangular matrix `A` is factored into the product `L`
+//! * `Q`, such that
+//!
+//! - The rows of `Q` are orthonormal, and the span of the first `k`
+//! rows of `Q` contains the subspaces spanned by the first `k` rows
+//! of `A`, for all 1 <= `k` <= m.
+//!
+//! - `L` is a lower triangular or lower-trapezoidal matrix.
+//!
+//! The LQ-factorization is the conjugate transpose of the
+//! QR-factorization of `A^H`. A `LQFactors` of a wide matrix can be
+//! reused to find minimum-norm solutions of underdetermined systems
+//! for many right-hand sides.
+
+use impl_prelude::*;
+use lapack::c::{sgelqf, dgelqf, cgelqf, zgelqf, sormlq, dormlq, cunmlq, zunmlq};
+use lapack::c::{sorglq, dorglq, cunglq, zunglq};
+use solve_linear::TriangularSolveLinear;
+use super::qr::QRError;
+
+/// Representation of the components L, Q of the factorization of
+/// matrix A.
+#[derive(Debug)]
+pub struct LQFactors<T: LQ> {
+    mat: Array<T, Ix2>,
+    tau: Vec<T>,
+}
+
+impl<T: LQ> LQFactors<T> {
+    /// Create a `LQFactors` object from the output of the LAPACKE
+    /// functions.
+    fn from_raw<Matrix>(mat: Matrix, tau: Vec<T>) -> Result<LQFactors<T>, QRError>
+        where Matrix: Into<Array<T, Ix2>>
+    {
+        let mut ma = mat.into();
+
+        if slice_and_layout_mut(&mut ma).is_none() {
+            return Err(QRError::BadLayout);
+        }
+
+        Ok(LQFactors {
+            mat: ma,
+            tau: tau,
+        })
+    }
+
+    /// Return the number of rows in the original matrix
+    pub fn rows(&self) -> usize {
+        self.mat.rows()
+    }
+
+    /// Return the number of columns in the original matrix
+    pub fn cols(&self) -> usize {
+        self.mat.cols()
+    }
+
+    fn p(&self) -> usize {
+        cmp::min(self.rows(), self.cols())
+    }
+
+    /// Return the first `k` rows of the matrix Q of the LQ
+    /// factorization.
+    ///
+    /// `Q` is generated such that the rows of `Q` form an orthogonal
+    /// basis for the first `k` rows of `A`. `k` may be as large as
+    /// `n`, in which case the full square matrix `Q` is returned.
+    ///
+    /// When `k` is None, compute enough rows (`min(m, n)`) to
+    /// faithfully recreate the original matrix A.
+    pub fn qk<K: Into<Option<usize>>>(&self, k: K) -> Result<Array<T, Ix2>, QRError> {
+        let kr = k.into();
+        LQ::compute_q(&self.mat, &self.tau, kr.unwrap_or_else(|| self.p()))
+    }
+
+    /// Return the `min(m, n)` by `n` matrix `Q`.
+    ///
+    /// Equivalent to `self.qk(None)`.
+    #[inline]
+    pub fn q(&self) -> Array<T, Ix2> {
+        self.qk(None).expect("Invalid implementation of Self::qk. Please report.")
+    }
+
+    /// Return the first `k` columns of the matrix L of the LQ
+    /// factorization.
+    ///
+    /// When `k` is None, compute enough columns (`min(m, n)`) to
+    /// faithfully recreate the original matrix A.
+    pub fn lk<K: Into<Option<usize>>>(&self, k: K) -> Result<Array<T, Ix2>, QRError> {
+        let kr = k.into();
+        LQ::compute_l(&self.mat, kr.unwrap_or_else(|| self.p()))
+    }
+
+    /// Return the `m` by `min(m, n)` matrix L of the LQ
+    /// factorization.
+    ///
+    /// Equivalent to `self.lk(None)`.
+    #[inline]
+    pub fn l(&self) -> Array<T, Ix2> {
+        self.lk(None).expect("Invalid implementation of Self::lk. Please report.")
+    }
+
+    /// Reconstruct the original matrix `A` from the factorization.
+    pub fn reconstruct(&self) -> Array<T, Ix2> {
+        self.l().dot(&self.q())
+    }
+
+    /// Multiply the matrix `c` by the full `n` by `n` matrix `Q`,
+    /// consuming `c`, without explicitly forming `Q`.
+    ///
+    /// Computes `Q * C` when `side` is `Side::Left`, and `C * Q` when
+    /// `side` is `Side::Right`. The layout of `c` must match the
+    /// layout of the factored matrix, unless `c` is a single column.
+    pub fn apply_q_into<D>(&self, c: ArrayBase<D, Ix2>, side: Side)
+                           -> Result<ArrayBase<D, Ix2>, QRError>
+        where D: DataOwned<Elem = T> + DataMut<Elem = T>
+    {
+        LQ::compute_apply_q_into(&self.mat, &self.tau, c, side, Transpose::None)
+    }
+
+    /// Multiply the matrix `c` by the full `n` by `n` matrix `Q`,
+    /// without explicitly forming `Q`. (see `Self::apply_q_into`).
+    pub fn apply_q<D>(&self, c: &ArrayBase<D, Ix2>, side: Side) -> Result<Array<T, Ix2>, QRError>
+        where D: Data<Elem = T>
+    {
+        self.apply_q_into(copy_with_matching_layout(c, &self.mat), side)
+    }
+
+    /// Multiply the matrix `c` by the conjugate transpose `Q^H` of the
+    /// full `n` by `n` matrix `Q`, consuming `c`, without explicitly
+    /// forming `Q`.
+    ///
+    /// Computes `Q^H * C` when `side` is `Side::Left`, and `C * Q^H`
+    /// when `side` is `Side::Right`. For real-valued matrices, `Q^H`
+    /// is the transpose `Q^T`. The layout of `c` must match the
+    /// layout of the factored matrix, unless `c` is a single column.
+    pub fn apply_qt_into<D>(&self, c: ArrayBase<D, Ix2>, side: Side)
+                            -> Result<ArrayBase<D, Ix2>, QRError>
+        where D: DataOwned<Elem = T> + DataMut<Elem = T>
+    {
+        LQ::compute_apply_q_into(&self.mat, &self.tau, c, side, Transpose::ConjTranspose)
+    }
+
+    /// Multiply the matrix `c` by the conjugate transpose `Q^H` of the
+    /// full `n` by `n` matrix `Q`, without explicitly forming
+    /// `Q`. (see `Self::apply_qt_into`).
+    pub fn apply_qt<D>(&self, c: &ArrayBase<D, Ix2>, side: Side) -> Result<Array<T, Ix2>, QRError>
+        where D: Data<Elem = T>
+    {
+        self.apply_qt_into(copy_with_matching_layout(c, &self.mat), side)
+    }
+}
+
+impl<T: LQ + TriangularSolveLinear> LQFactors<T> {
+    /// Return the minimum-norm solution `X` to the underdetermined
+    /// system A*X = B for the factored matrix `A`.
+    ///
+    /// Each column of `b` is a RHS vector to be solved for. `A` must
+    /// be square or wide (m <= n) and of full rank.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QRError::Overdetermined` when `A` has more rows than
+    /// columns, and `QRError::RankDeficient` when a diagonal element
+    /// of `L` is exactly zero.
+    pub fn solve_min_norm_multi<D>(&self, b: &ArrayBase<D, Ix2>) -> Result<Array<T, Ix2>, QRError>
+        where D: Data<Elem = T>
+    {
+        let (m, n) = self.mat.dim();
+        if m > n {
+            return Err(QRError::Overdetermined);
+        }
+        if b.rows() != m {
+            return Err(QRError::InconsistentDimensions);
+        }
+
+        // Solve L * Y = B, then X = Q^H * [Y; 0].
+        let l = self.mat.slice(s![.., ..m as isize]);
+        let y = try!(TriangularSolveLinear::compute_multi(&l, Symmetric::Lower,
+                                                          Transpose::None, b));
+
+        let mut x = Array::zeros((n, b.cols()));
+        x.slice_mut(s![..m as isize, ..]).assign(&y);
+        self.apply_qt(&x, Side::Left)
+    }
+
+    /// Return the minimum-norm solution `x` to the underdetermined
+    /// system A*x = b for the factored matrix `A`. (see
+    /// `Self::solve_min_norm_multi`).
+    pub fn solve_min_norm<D>(&self, b: &ArrayBase<D, Ix1>) -> Result<Array<T, Ix1>, QRError>
+        where D: Data<Elem = T>
+    {
+        let m = b.dim();
+
+        // Create a new matrix, where the column vector is a degenerate 2-D matrix.
+        let b_mat = b.to_owned().into_shape((m, 1)).unwrap();
+
+        let x = try!(self.solve_min_norm_multi(&b_mat));
+        let n = x.rows();
+        Ok(x.into_shape(n).unwrap())
+    }
+}
+
+/// Trait defined on scalars to support LQ-factorization.
+pub trait LQ: LinxalImplScalar {
+    /// Return a `LQFactors` structure, containing the LQ
+    /// factorization of the input matrix `A`.
+    ///
+    /// Similar to `compute`, but consumes the input.
+    fn compute_into(a: Array<Self, Ix2>) -> Result<LQFactors<Self>, QRError>;
+
+    /// Return a `LQFactors` structure, containing the LQ
+    /// factorization of the input matrix `A`.
+    fn compute<D1>(a: &ArrayBase<D1, Ix2>) -> Result<LQFactors<Self>, QRError>
+        where D1: Data<Elem = Self>
+    {
+        Self::compute_into(a.to_owned())
+    }
+
+    /// Compute Q from raw parts.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_q<D1>(mat: &ArrayBase<D1, Ix2>,
+                     tau: &[Self],
+                     k: usize)
+                     -> Result<Array<Self, Ix2>, QRError>
+        where D1: Data<Elem = Self>;
+
+    /// Compute L from raw parts.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_l<D1>(mat: &ArrayBase<D1, Ix2>, k: usize) -> Result<Array<Self, Ix2>, QRError>
+        where D1: Data<Elem = Self>;
+
+    /// Multiply `c` by `Q` or `Q^H`, from the side specified by
+    /// `side`, using the raw parts of Q.
+    ///
+    /// `Transpose::Transpose` and `Transpose::ConjTranspose` both
+    /// apply `Q^H`.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_apply_q_into<D1, D2>(mat: &ArrayBase<D1, Ix2>,
+                                    tau: &[Self],
+                                    c: ArrayBase<D2, Ix2>,
+                                    side: Side,
+                                    trans: Transpose)
+                                    -> Result<ArrayBase<D2, Ix2>, QRError>
+        where D1: Data<Elem = Self>,
+              D2: DataOwned<Elem = Self> + DataMut<Elem = Self>;
+}
+
+macro_rules! impl_lq {
+    ($lq_type:ty, $lq_func:ident, $lq_to_q:ident, $lq_apply_q:ident, $qt_trans:expr) => (
+        impl LQ for $lq_type {
+            fn compute_into(mut a: Array<Self, Ix2>) -> Result<LQFactors<Self>, QRError> {
+                let dim = a.dim();
+
+                let mut tau = vec![<$lq_type as Zero>::zero(); cmp::min(dim.0, dim.1)];
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                        None => return Err(QRError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $lq_func(layout, dim.0 as i32, dim.1 as i32, slice, lda as i32, &mut tau)
+                    }
+                };
+
+                if info == 0 {
+                    LQFactors::from_raw(a, tau)
+                } else {
+                    Err(QRError::IllegalParameter(-info))
+                }
+            }
+
+            fn compute_q<D1>(mat: &ArrayBase<D1, Ix2>,
+                             tau: &[Self],
+                             k: usize)
+                             -> Result<Array<Self, Ix2>, QRError>
+                where D1: Data<Elem = Self>
+            {
+                let (m, n) = mat.dim();
+                if k > n {
+                    return Err(QRError::InconsistentDimensions);
+                }
+
+                // Initialize q with the reflectors. When more rows
+                // than reflectors are requested (k > m), the remaining
+                // rows start as zero.
+                let p = cmp::min(k, m);
+                let mut q = Array::zeros((k, n));
+                q.slice_mut(s![..p as isize, ..]).assign(&mat.slice(s![..p as isize, ..]));
+
+                let info = {
+                    let (slice, layout, ldq) = match slice_and_layout_mut(&mut q) {
+                        None => unreachable!(),
+                        Some(fwd) => fwd,
+                    };
+
+                    unsafe {
+                        $lq_to_q(layout, k as i32, n as i32, p as i32, slice, ldq as i32, tau)
+                    }
+                };
+                if info == 0 {
+                    Ok(q)
+                } else {
+                    Err(QRError::IllegalParameter(-info))
+                }
+            }
+
+            fn compute_l<D1>(mat: &ArrayBase<D1, Ix2>, k: usize) -> Result<Array<Self, Ix2>, QRError>
+                where D1: Data<Elem = Self>
+            {
+                let (m, n) = mat.dim();
+
+                if k > cmp::min(m, n) {
+                    return Err(QRError::InconsistentDimensions);
+                }
+
+                // Copy the lower triangular/trapezoidal part of the
+                // matrix to L.
+                let mut l = mat.slice(s![.., ..k as isize]).to_owned();
+
+                let zero = <$lq_type as Zero>::zero();
+
+                // Replace zeros above the diagonal.
+                for (i, mut row) in l.outer_iter_mut().enumerate().take(k) {
+                    row.slice_mut(s![i as isize + 1..]).fill(zero);
+                }
+
+                Ok(l)
+            }
+
+            fn compute_apply_q_into<D1, D2>(mat: &ArrayBase<D1, Ix2>,
+                                            tau: &[Self],
+                                            mut c: ArrayBase<D2, Ix2>,
+                                            side: Side,
+                                            trans: Transpose)
+                                            -> Result<ArrayBase<D2, Ix2>, QRError>
+                where D1: Data<Elem = Self>,
+                      D2: DataOwned<Elem = Self> + DataMut<Elem = Self>
+            {
+                let n = mat.cols();
+                let c_dim = c.dim();
+                let k = tau.len();
+
+                // Q is n by n, so it must match the appropriate
+                // dimension of C.
+                let q_dim = match side {
+                    Side::Left => c_dim.0,
+                    Side::Right => c_dim.1,
+                };
+                if q_dim != n || k > cmp::min(mat.rows(), n) {
+                    return Err(QRError::InconsistentDimensions);
+                }
+
+                // The real and complex routines use different
+                // descriptors for the (conjugate) transpose.
+                let trans_desc = match trans {
+                    Transpose::None => b'N',
+                    _ => $qt_trans,
+                };
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout(mat) {
+                        None => return Err(QRError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    let (c_slice, ldc) = match slice_and_layout_matching_mut(&mut c, layout) {
+                        None => return Err(QRError::InconsistentLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $lq_apply_q(layout, side as u8, trans_desc,
+                                    c_dim.0 as i32, c_dim.1 as i32, k as i32,
+                                    slice, lda as i32, tau, c_slice, ldc as i32)
+                    }
+                };
+
+                if info == 0 {
+                    Ok(c)
+                } else {
+                    Err(QRError::IllegalParameter(-info))
+                }
+            }
+        }
+    )
+}
+
+impl_lq!(f32, sgelqf, sorglq, sormlq, b'T');
+impl_lq!(f64, dgelqf, dorglq, dormlq, b'T');
+impl_lq!(c32, cgelqf, cunglq, cunmlq, b'C');
+impl_lq!(c64, zgelqf, zunglq, zunmlq, b'C');
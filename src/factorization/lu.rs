@@ -17,7 +17,8 @@
 //! matrix.
 //!
 //! The LU factorization can be used to solve Ax=b equations or
-//! compute the inverse of `A`.
+//! compute the inverse of `A`. Once computed, a single factorization
+//! can be reused to solve for any number of right-hand sides.
 //!
 //! # Remarks
 //!
@@ -28,8 +29,11 @@
 
 use impl_prelude::*;
 use permute::{MatrixPermutation, Permutes};
-use ndarray as nd;
+use num_traits::Float;
 use lapack::c::{sgetrf, dgetrf, cgetrf, zgetrf, sgetri, dgetri, cgetri, zgetri};
+use lapack::c::{sgetrs, dgetrs, cgetrs, zgetrs};
+use lapack::c::{sgecon, dgecon, cgecon, zgecon};
+use condition::ConditionNorm;
 
 /// Error for LU-based computations.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -47,6 +51,11 @@ pub enum LUError {
     /// The dimensions of the raw qr and tau don't match
     InconsistentDimensions,
 
+    /// The layout of the right-hand side does not match the layout
+    /// of the factored matrix. (i.e. one is column-major and the
+    /// other is row-major.)
+    InconsistentLayout,
+
     /// Implementation error, please submit as bug.
     IllegalParameter(i32),
 }
@@ -59,12 +68,48 @@ pub struct LUFactors<T: LU> {
 
     /// permutation in native fortran format
     perm: MatrixPermutation,
+
+    /// 1-norm of the original matrix
+    one_norm: T::RealPart,
+
+    /// infinity-norm of the original matrix
+    inf_norm: T::RealPart,
+}
+
+/// Return the largest of `values`, or NaN if any of them is NaN.
+fn max_or_nan<F: Float>(values: Vec<F>) -> F {
+    values.into_iter().fold(F::zero(), |acc, x| {
+        if acc.is_nan() || x.is_nan() {
+            F::nan()
+        } else if x > acc {
+            x
+        } else {
+            acc
+        }
+    })
+}
+
+/// Return the 1-norm and infinity-norm of `mat`.
+///
+/// The norms are computed before the factorization overwrites `mat`,
+/// for use in condition number estimates.
+fn one_and_inf_norms<T: LinxalImplScalar>(mat: &Array<T, Ix2>) -> (T::RealPart, T::RealPart) {
+    let (m, n) = mat.dim();
+    let mut row_sums = vec![T::RealPart::zero(); m];
+    let mut col_sums = vec![T::RealPart::zero(); n];
+    for ((i, j), x) in mat.indexed_iter() {
+        let v = x.mag();
+        row_sums[i] = row_sums[i] + v;
+        col_sums[j] = col_sums[j] + v;
+    }
+    (max_or_nan(col_sums), max_or_nan(row_sums))
 }
 
 impl<T: LU> LUFactors<T> {
     /// Create a `LUFactors` object from the output of the LAPACKE
     /// functions.
-    fn from_raw<Matrix>(mat: Matrix, perm: Vec<i32>) -> Result<LUFactors<T>, LUError>
+    fn from_raw<Matrix>(mat: Matrix, perm: Vec<i32>, norms: (T::RealPart, T::RealPart))
+                        -> Result<LUFactors<T>, LUError>
         where Matrix: Into<Array<T, Ix2>>
     {
         let mut ma = mat.into();
@@ -76,6 +121,8 @@ impl<T: LU> LUFactors<T> {
         Ok(LUFactors {
             mat: ma,
             perm: MatrixPermutation::from_ipiv(perm),
+            one_norm: norms.0,
+            inf_norm: norms.1,
         })
     }
 
@@ -91,6 +138,81 @@ impl<T: LU> LUFactors<T> {
         LU::compute_inverse_into(self.mat, self.perm.ipiv())
     }
 
+    /// Solve the system `op(A) * X = B` for the original square
+    /// matrix `A`, consuming the right-hand sides `b`.
+    ///
+    /// Each column of `b` is a right-hand side to be solved for, and
+    /// `op(A)` is `A`, `A^T`, or `A^H` depending on `trans`. The
+    /// layout of `b` must match the layout of the factored matrix,
+    /// unless `b` is a single column.
+    pub fn solve_multi_into<D>(&self, b: ArrayBase<D, Ix2>, trans: Transpose)
+                               -> Result<ArrayBase<D, Ix2>, LUError>
+        where D: DataOwned<Elem = T> + DataMut<Elem = T>
+    {
+        LU::compute_solve_multi_into(&self.mat, self.perm.ipiv(), b, trans)
+    }
+
+    /// Solve the system `op(A) * X = B` for the original square
+    /// matrix `A`. (see `Self::solve_multi_into`).
+    ///
+    /// `b` is copied into a matrix of the appropriate layout
+    /// beforehand, so any layout is accepted.
+    pub fn solve_multi<D>(&self, b: &ArrayBase<D, Ix2>, trans: Transpose)
+                          -> Result<Array<T, Ix2>, LUError>
+        where D: Data<Elem = T>
+    {
+        self.solve_multi_into(copy_with_matching_layout(b, &self.mat), trans)
+    }
+
+    /// Solve the system `op(A) * x = b` for the original square
+    /// matrix `A` and a single column vector `b`, consuming `b`.
+    pub fn solve_into<D>(&self, b: ArrayBase<D, Ix1>, trans: Transpose)
+                         -> Result<ArrayBase<D, Ix1>, LUError>
+        where D: DataOwned<Elem = T> + DataMut<Elem = T>
+    {
+        let n = b.dim();
+
+        // Create a new matrix, where the column vector is a degenerate 2-D matrix.
+        let b_mat = match b.into_shape((n, 1)) {
+            Ok(x) => x,
+            Err(_) => return Err(LUError::BadLayout),
+        };
+
+        let res = try!(self.solve_multi_into(b_mat, trans));
+
+        // Reshape the matrix into a vector and return.
+        Ok(res.into_shape(n).unwrap())
+    }
+
+    /// Solve the system `op(A) * x = b` for the original square
+    /// matrix `A` and a single column vector `b`.
+    pub fn solve<D>(&self, b: &ArrayBase<D, Ix1>, trans: Transpose)
+                    -> Result<Array<T, Ix1>, LUError>
+        where D: Data<Elem = T>
+    {
+        self.solve_into(b.to_owned(), trans)
+    }
+
+    /// Return an estimate of the reciprocal condition number of the
+    /// original matrix, assuming it was square, in the specified
+    /// norm.
+    ///
+    /// The estimate is computed from the factorization, and the norm
+    /// of the original matrix recorded when it was factored, without
+    /// forming the inverse. Values near zero indicate that solutions
+    /// computed from the factorization may be inaccurate. The estimate
+    /// is NaN when the original matrix contained NaN values.
+    pub fn rcond(&self, norm: ConditionNorm) -> Result<T::RealPart, LUError> {
+        let anorm = match norm {
+            ConditionNorm::One => self.one_norm,
+            ConditionNorm::Infinity => self.inf_norm,
+        };
+        if anorm.is_nan() {
+            return Ok(anorm);
+        }
+        LU::compute_rcond(&self.mat, anorm, norm)
+    }
+
     /// Return the number of rows in the original matrix
     pub fn rows(&self) -> usize {
         self.mat.rows()
@@ -166,12 +288,59 @@ impl<T: LU> LUFactors<T> {
         let lu = self.l().dot(&self.u());
         self.perm.permute_into(lu).expect("guarantee that lu is the right size")
     }
+
+    /// Return the determinant of the original matrix, assuming it was
+    /// square.
+    pub fn det(&self) -> Result<T, LUError> {
+        if self.rows() != self.cols() {
+            return Err(LUError::NotSquare);
+        }
+
+        let d = self.mat.diag().iter().fold(T::one(), |acc, &x| acc * x);
+        if self.perm.num_swaps() % 2 == 0 {
+            Ok(d)
+        } else {
+            Ok(T::zero() - d)
+        }
+    }
+
+    /// Return the sign and the natural log of the magnitude of the
+    /// determinant of the original matrix, assuming it was square.
+    ///
+    /// For real matrices, the sign is one of `-1`, `0`, or `1`. For
+    /// complex matrices, the sign is the phase of the determinant,
+    /// with magnitude `1` (or `0`). The determinant is equal to `sign
+    /// * exp(logdet)`, but the log-magnitude will not overflow or
+    /// underflow for large matrices.
+    pub fn slogdet(&self) -> Result<(T, T::RealPart), LUError> {
+        if self.rows() != self.cols() {
+            return Err(LUError::NotSquare);
+        }
+
+        let mut sign = if self.perm.num_swaps() % 2 == 0 {
+            T::one()
+        } else {
+            T::zero() - T::one()
+        };
+        let mut logdet = T::RealPart::zero();
+
+        for &x in self.mat.diag() {
+            let m = x.mag();
+            if m == T::RealPart::zero() {
+                return Ok((T::zero(), T::RealPart::neg_infinity()));
+            }
+            sign = sign * (x / T::from_real(m));
+            logdet = logdet + m.ln();
+        }
+
+        Ok((sign, logdet))
+    }
 }
 
 /// Trait defined on scalars to support LU-factorization.
 ///
 /// Any matrix composed of `LU` scalars can be split into `LUFactors`.
-pub trait LU: nd::LinalgScalar + Permutes {
+pub trait LU: LinxalImplScalar + Permutes {
     /// Return a `LUFactors` structure, containing the LU
     /// factorization of the input matrix `A`.
     ///
@@ -204,13 +373,34 @@ pub trait LU: nd::LinalgScalar + Permutes {
         let copy_mat = mat.to_owned();
         Self::compute_inverse_into(copy_mat, perm)
     }
+
+    /// Solve `op(A) * X = B` from the raw LU factorization of `A`,
+    /// overwriting `b` with the solution.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_solve_multi_into<D1, D2>(mat: &ArrayBase<D1, Ix2>,
+                                        perm: &[i32],
+                                        b: ArrayBase<D2, Ix2>,
+                                        trans: Transpose)
+                                        -> Result<ArrayBase<D2, Ix2>, LUError>
+        where D1: Data<Elem = Self>,
+              D2: DataOwned<Elem = Self> + DataMut<Elem = Self>;
+
+    /// Return an estimate of the reciprocal condition number of `A`
+    /// from its raw LU factorization and its norm `anorm`.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_rcond<D1>(mat: &ArrayBase<D1, Ix2>, anorm: Self::RealPart, norm: ConditionNorm)
+                         -> Result<Self::RealPart, LUError>
+        where D1: Data<Elem = Self>;
 }
 
 macro_rules! impl_lu {
-    ($lu_type:ty, $lu_func:ident, $lu_invert:ident) => (
+    ($lu_type:ty, $lu_func:ident, $lu_invert:ident, $lu_solve:ident, $lu_cond:ident) => (
         impl LU for $lu_type {
             fn compute_into(mut a: Array<Self, Ix2>) -> Result<LUFactors<Self>, LUError> {
                 let dim = a.dim();
+                let norms = one_and_inf_norms(&a);
 
                 let (info, perm_i) = {
                     let (mut slice, layout, lda) = match slice_and_layout_mut(&mut a) {
@@ -234,7 +424,7 @@ macro_rules! impl_lu {
                 };
 
                 if info == 0 {
-                    LUFactors::from_raw(a, perm_i)
+                    LUFactors::from_raw(a, perm_i, norms)
                 } else if info < 0 {
                     Err(LUError::IllegalParameter(-info))
                 } else {
@@ -268,11 +458,76 @@ macro_rules! impl_lu {
                     Err(LUError::Singular)
                 }
             }
+
+            fn compute_solve_multi_into<D1, D2>(mat: &ArrayBase<D1, Ix2>,
+                                                perm: &[i32],
+                                                mut b: ArrayBase<D2, Ix2>,
+                                                trans: Transpose)
+                                                -> Result<ArrayBase<D2, Ix2>, LUError>
+                where D1: Data<Elem = Self>,
+                      D2: DataOwned<Elem = Self> + DataMut<Elem = Self> {
+                let dim = mat.dim();
+                let b_dim = b.dim();
+                if dim.0 != dim.1 {
+                    return Err(LUError::NotSquare);
+                }
+                if dim.0 != b_dim.0 || dim.0 != perm.len() {
+                    return Err(LUError::InconsistentDimensions);
+                }
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout(mat) {
+                        None => return Err(LUError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    let (b_slice, ldb) = match slice_and_layout_matching_mut(&mut b, layout) {
+                        None => return Err(LUError::InconsistentLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $lu_solve(layout, trans as u8, dim.0 as i32, b_dim.1 as i32,
+                                  slice, lda as i32, perm, b_slice, ldb as i32)
+                    }
+                };
+
+                if info == 0 {
+                    Ok(b)
+                } else {
+                    Err(LUError::IllegalParameter(-info))
+                }
+            }
+
+            fn compute_rcond<D1>(mat: &ArrayBase<D1, Ix2>, anorm: Self::RealPart, norm: ConditionNorm)
+                                 -> Result<Self::RealPart, LUError>
+                where D1: Data<Elem = Self> {
+                let dim = mat.dim();
+                if dim.0 != dim.1 {
+                    return Err(LUError::NotSquare);
+                }
+
+                let (slice, layout, lda) = match slice_and_layout(mat) {
+                    None => return Err(LUError::BadLayout),
+                    Some(x) => x,
+                };
+
+                let mut rcond = 0.0;
+                let info = unsafe {
+                    $lu_cond(layout, norm as u8, dim.0 as i32, slice, lda as i32, anorm, &mut rcond)
+                };
+
+                if info == 0 {
+                    Ok(rcond)
+                } else {
+                    Err(LUError::IllegalParameter(-info))
+                }
+            }
         }
     )
 }
 
-impl_lu!(f32, sgetrf, sgetri);
-impl_lu!(f64, dgetrf, dgetri);
-impl_lu!(c32, cgetrf, cgetri);
-impl_lu!(c64, zgetrf, zgetri);
+impl_lu!(f32, sgetrf, sgetri, sgetrs, sgecon);
+impl_lu!(f64, dgetrf, dgetri, dgetrs, dgecon);
+impl_lu!(c32, cgetrf, cgetri, cgetrs, cgecon);
+impl_lu!(c64, zgetrf, zgetri, zgetrs, zgecon);
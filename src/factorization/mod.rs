@@ -2,9 +2,22 @@
 #![deny(missing_docs)]
 
 pub mod qr;
+mod qr_update;
+pub mod lq;
+pub mod rq;
+pub mod ql;
 pub mod lu;
 pub mod cholesky;
+pub mod ldl;
+pub mod hessenberg;
+pub mod tridiagonal;
 
-pub use self::qr::{QR, QRFactors, QRError};
+pub use self::qr::{QR, QRFactors, QRPFactors, QRError};
+pub use self::lq::{LQ, LQFactors};
+pub use self::rq::{RQ, RQFactors};
+pub use self::ql::{QL, QLFactors};
 pub use self::lu::{LU, LUFactors, LUError};
-pub use self::cholesky::{Cholesky, CholeskyError};
+pub use self::cholesky::{Cholesky, CholeskyFactors, CholeskyPFactors, CholeskyError};
+pub use self::ldl::{LDL, LDLFactors, LDLError, Inertia};
+pub use self::hessenberg::{Hessenberg, HessenbergFactors, ReductionError};
+pub use self::tridiagonal::{Tridiagonal, TridiagonalFactors};
@@ -0,0 +1,225 @@
+//! Compute the QL-factorization of a rectangular matrix.
+//!
+//! An (m x n) rectangular matrix `A` is factored into the product `Q`
+//! * `L`, such that
+//!
+//! - The columns of `Q` are orthonormal.
+//!
+//! - `L` is a lower triangular or lower-trapezoidal matrix.
+//!
+//! Unlike the QR-factorization, the triangular factor is aligned
+//! with the last rows (and the orthonormal columns with the last
+//! columns of the full `Q`).
+
+use impl_prelude::*;
+use lapack::c::{sgeqlf, dgeqlf, cgeqlf, zgeqlf, sorgql, dorgql, cungql, zungql};
+use super::qr::QRError;
+
+/// Representation of the components Q, L of the factorization of
+/// matrix A.
+#[derive(Debug)]
+pub struct QLFactors<T: QL> {
+    mat: Array<T, Ix2>,
+    tau: Vec<T>,
+}
+
+impl<T: QL> QLFactors<T> {
+    /// Create a `QLFactors` object from the output of the LAPACKE
+    /// functions.
+    fn from_raw<Matrix>(mat: Matrix, tau: Vec<T>) -> Result<QLFactors<T>, QRError>
+        where Matrix: Into<Array<T, Ix2>>
+    {
+        let mut ma = mat.into();
+
+        if slice_and_layout_mut(&mut ma).is_none() {
+            return Err(QRError::BadLayout);
+        }
+
+        Ok(QLFactors {
+            mat: ma,
+            tau: tau,
+        })
+    }
+
+    /// Return the number of rows in the original matrix
+    pub fn rows(&self) -> usize {
+        self.mat.rows()
+    }
+
+    /// Return the number of columns in the original matrix
+    pub fn cols(&self) -> usize {
+        self.mat.cols()
+    }
+
+    fn p(&self) -> usize {
+        cmp::min(self.rows(), self.cols())
+    }
+
+    /// Return the last `k` columns of the matrix Q of the QL
+    /// factorization.
+    ///
+    /// `Q` is generated such that the columns of `Q` form an
+    /// orthogonal basis for the last `k` columns of `A`. `k` may be
+    /// as large as `m`, in which case the full square matrix `Q` is
+    /// returned.
+    ///
+    /// When `k` is None, compute enough columns (`min(m, n)`) to
+    /// faithfully recreate the original matrix A.
+    pub fn qk<K: Into<Option<usize>>>(&self, k: K) -> Result<Array<T, Ix2>, QRError> {
+        let kr = k.into();
+        QL::compute_q(&self.mat, &self.tau, kr.unwrap_or_else(|| self.p()))
+    }
+
+    /// Return the `m` by `min(m, n)` matrix `Q`.
+    ///
+    /// Equivalent to `self.qk(None)`.
+    #[inline]
+    pub fn q(&self) -> Array<T, Ix2> {
+        self.qk(None).expect("Invalid implementation of Self::qk. Please report.")
+    }
+
+    /// Return the last `k` rows of the matrix L of the QL
+    /// factorization.
+    ///
+    /// When `k` is None, compute enough rows (`min(m, n)`) to
+    /// faithfully recreate the original matrix A.
+    pub fn lk<K: Into<Option<usize>>>(&self, k: K) -> Result<Array<T, Ix2>, QRError> {
+        let kr = k.into();
+        QL::compute_l(&self.mat, kr.unwrap_or_else(|| self.p()))
+    }
+
+    /// Return the `min(m, n)` by `n` matrix L of the QL
+    /// factorization.
+    ///
+    /// Equivalent to `self.lk(None)`.
+    #[inline]
+    pub fn l(&self) -> Array<T, Ix2> {
+        self.lk(None).expect("Invalid implementation of Self::lk. Please report.")
+    }
+
+    /// Reconstruct the original matrix `A` from the factorization.
+    pub fn reconstruct(&self) -> Array<T, Ix2> {
+        self.q().dot(&self.l())
+    }
+}
+
+/// Trait defined on scalars to support QL-factorization.
+pub trait QL: LinxalImplScalar {
+    /// Return a `QLFactors` structure, containing the QL
+    /// factorization of the input matrix `A`.
+    ///
+    /// Similar to `compute`, but consumes the input.
+    fn compute_into(a: Array<Self, Ix2>) -> Result<QLFactors<Self>, QRError>;
+
+    /// Return a `QLFactors` structure, containing the QL
+    /// factorization of the input matrix `A`.
+    fn compute<D1>(a: &ArrayBase<D1, Ix2>) -> Result<QLFactors<Self>, QRError>
+        where D1: Data<Elem = Self>
+    {
+        Self::compute_into(a.to_owned())
+    }
+
+    /// Compute Q from raw parts.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_q<D1>(mat: &ArrayBase<D1, Ix2>,
+                     tau: &[Self],
+                     k: usize)
+                     -> Result<Array<Self, Ix2>, QRError>
+        where D1: Data<Elem = Self>;
+
+    /// Compute the last `k` rows of L from raw parts.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_l<D1>(mat: &ArrayBase<D1, Ix2>, k: usize) -> Result<Array<Self, Ix2>, QRError>
+        where D1: Data<Elem = Self>
+    {
+        let (m, n) = mat.dim();
+        if k > cmp::min(m, n) {
+            return Err(QRError::InconsistentDimensions);
+        }
+
+        // L is stored in the last `k` rows, on and below the
+        // `(n - k)`-th superdiagonal.
+        let mut l = Array::zeros((k, n));
+        for ((i, j), x) in l.indexed_iter_mut() {
+            if j <= i + n - k {
+                *x = mat[(m - k + i, j)];
+            }
+        }
+
+        Ok(l)
+    }
+}
+
+macro_rules! impl_ql {
+    ($ql_type:ty, $ql_func:ident, $ql_to_q:ident) => (
+        impl QL for $ql_type {
+            fn compute_into(mut a: Array<Self, Ix2>) -> Result<QLFactors<Self>, QRError> {
+                let dim = a.dim();
+
+                let mut tau = vec![<$ql_type as Zero>::zero(); cmp::min(dim.0, dim.1)];
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                        None => return Err(QRError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $ql_func(layout, dim.0 as i32, dim.1 as i32, slice, lda as i32, &mut tau)
+                    }
+                };
+
+                if info == 0 {
+                    QLFactors::from_raw(a, tau)
+                } else {
+                    Err(QRError::IllegalParameter(-info))
+                }
+            }
+
+            fn compute_q<D1>(mat: &ArrayBase<D1, Ix2>,
+                             tau: &[Self],
+                             k: usize)
+                             -> Result<Array<Self, Ix2>, QRError>
+                where D1: Data<Elem = Self>
+            {
+                let (m, n) = mat.dim();
+                if k > m || tau.len() > cmp::min(m, n) {
+                    return Err(QRError::InconsistentDimensions);
+                }
+
+                // The reflectors are stored in the last columns, and
+                // the last `k` columns of Q only depend on the last
+                // `k` reflectors. When more columns than reflectors
+                // are requested, the remaining leading columns start
+                // as zero.
+                let p = cmp::min(k, tau.len());
+                let mut q = Array::zeros((m, k));
+                q.slice_mut(s![.., (k - p) as isize..]).assign(&mat.slice(s![.., (n - p) as isize..]));
+                let tau = &tau[tau.len() - p..];
+
+                let info = {
+                    let (slice, layout, ldq) = match slice_and_layout_mut(&mut q) {
+                        None => unreachable!(),
+                        Some(fwd) => fwd,
+                    };
+
+                    unsafe {
+                        $ql_to_q(layout, m as i32, k as i32, p as i32, slice, ldq as i32, tau)
+                    }
+                };
+                if info == 0 {
+                    Ok(q)
+                } else {
+                    Err(QRError::IllegalParameter(-info))
+                }
+            }
+        }
+    )
+}
+
+impl_ql!(f32, sgeqlf, sorgql);
+impl_ql!(f64, dgeqlf, dorgql);
+impl_ql!(c32, cgeqlf, cungql);
+impl_ql!(c64, zgeqlf, zungql);
@@ -7,10 +7,22 @@
 //! columns of `A`, for all 1 <= `k` <= n.
 //!
 //! - `R` is an upper triangular or upper-trapezoidal matrix.
+//!
+//! The column-pivoted QR-factorization instead factors `A` * `P` =
+//! `Q` * `R` for a permutation matrix `P`, chosen so that the
+//! magnitudes of the diagonal elements of `R` are non-increasing. The
+//! pivoted factorization reveals the numerical rank of `A`.
+//!
+//! A `QRFactors` of a tall matrix can be reused to solve least
+//! squares problems for many right-hand sides. (For minimum-norm
+//! solutions of wide systems, see the `lq` module.)
 
 use impl_prelude::*;
 use lapack::c::{sgeqrf, sorgqr, dgeqrf, dorgqr, cgeqrf, cungqr, zgeqrf, zungqr};
-use ndarray as nd;
+use lapack::c::{sgeqp3, dgeqp3, cgeqp3, zgeqp3, sormqr, dormqr, cunmqr, zunmqr};
+use solve_linear::{SolveError, TriangularSolveLinear};
+use util::external::conj_t;
+use num_traits::NumCast;
 
 /// Error for QR-based computations.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -21,16 +33,93 @@ pub enum QRError {
     /// The dimensions of the raw qr and tau don't match
     InconsistentDimensions,
 
+    /// The layout of the input does not match the layout of the
+    /// factored matrix. (i.e. one is column-major and the other is
+    /// row-major.)
+    InconsistentLayout,
+
+    /// The system has more columns than rows, so there is no unique
+    /// least squares solution.
+    Underdetermined,
+
+    /// The system has more rows than columns, so there is no
+    /// minimum-norm solution.
+    Overdetermined,
+
+    /// The triangular factor is exactly singular, so the matrix is
+    /// not of full rank.
+    RankDeficient,
+
     /// Implementation error, please submit as bug.
     IllegalParameter(i32),
 }
 
+impl From<SolveError> for QRError {
+    fn from(e: SolveError) -> QRError {
+        match e {
+            SolveError::BadLayout => QRError::BadLayout,
+            SolveError::InconsistentLayout => QRError::InconsistentLayout,
+            SolveError::IllegalValue(i) => QRError::IllegalParameter(i),
+            SolveError::Singular(_) => QRError::RankDeficient,
+            SolveError::NotSquare(_, _) |
+            SolveError::InconsistentDimensions(_, _) => QRError::InconsistentDimensions,
+        }
+    }
+}
+
+/// Storage for the factors of a `QRFactors`.
+#[derive(Debug)]
+enum QRRepr<T> {
+    /// The Householder reflectors and `R`, as returned by `?geqrf`.
+    Compact {
+        mat: Array<T, Ix2>,
+        tau: Vec<T>,
+    },
+
+    /// The thin factors `Q` (m x p) and `R` (p x n), with `p = min(m,
+    /// n)`, formed explicitly to support updates. (see `qr_update`)
+    Thin {
+        q: Array<T, Ix2>,
+        r: Array<T, Ix2>,
+    },
+}
+
 /// Representation of the components Q, R of the factorization of
 /// matrix A.
 #[derive(Debug)]
 pub struct QRFactors<T: QR> {
-    mat: Array<T, Ix2>,
-    tau: Vec<T>,
+    repr: QRRepr<T>,
+}
+
+/// Return the compact representation of the factorization `Q * R`,
+/// given the thin factors.
+///
+/// Since the columns of `Q` are orthonormal, its own factorization `Q
+/// = H * R_1` has a (numerically) diagonal, unitary `R_1`, so the
+/// reflectors of `H` also represent `Q * R = H * (R_1 * R)`.
+fn compact_thin<T: QR>(q: &Array<T, Ix2>, r: &Array<T, Ix2>)
+                       -> Result<(Array<T, Ix2>, Vec<T>), QRError> {
+    let (m, p) = q.dim();
+    let n = r.cols();
+    if p == 0 {
+        return Ok((Array::zeros((m, n)), Vec::new()));
+    }
+
+    let (h, tau) = match try!(QR::compute(q)).repr {
+        QRRepr::Compact { mat, tau } => (mat, tau),
+        QRRepr::Thin { .. } => unreachable!(),
+    };
+    let r1_r = try!(QR::compute_r(&h, p)).dot(r);
+
+    let mut mat = Array::zeros((m, n));
+    for ((i, j), x) in mat.indexed_iter_mut() {
+        if i > j {
+            *x = h[(i, j)];
+        } else {
+            *x = r1_r[(i, j)];
+        }
+    }
+    Ok((mat, tau))
 }
 
 impl<T: QR> QRFactors<T> {
@@ -46,19 +135,55 @@ impl<T: QR> QRFactors<T> {
         }
 
         Ok(QRFactors {
-            mat: ma,
-            tau: tau,
+            repr: QRRepr::Compact {
+                mat: ma,
+                tau: tau,
+            },
         })
     }
 
     /// Return the number of rows in the original matrix
     pub fn rows(&self) -> usize {
-        self.mat.rows()
+        match self.repr {
+            QRRepr::Compact { ref mat, .. } => mat.rows(),
+            QRRepr::Thin { ref q, .. } => q.rows(),
+        }
     }
 
     /// Return the number of columns in the original matrix
     pub fn cols(&self) -> usize {
-        self.mat.cols()
+        match self.repr {
+            QRRepr::Compact { ref mat, .. } => mat.cols(),
+            QRRepr::Thin { ref r, .. } => r.cols(),
+        }
+    }
+
+    /// Call `f` with the Householder reflectors and `R`, forming them
+    /// from the thin factors if the factorization has been updated.
+    fn with_compact<R, F>(&self, f: F) -> Result<R, QRError>
+        where F: FnOnce(&Array<T, Ix2>, &[T]) -> Result<R, QRError>
+    {
+        match self.repr {
+            QRRepr::Compact { ref mat, ref tau } => f(mat, tau),
+            QRRepr::Thin { ref q, ref r } => {
+                let (mat, tau) = try!(compact_thin(q, r));
+                f(&mat, &tau)
+            }
+        }
+    }
+
+    /// Return mutable references to the thin factors `Q` and `R`,
+    /// forming them explicitly if necessary.
+    pub(crate) fn thin_factors_mut(&mut self) -> (&mut Array<T, Ix2>, &mut Array<T, Ix2>) {
+        if let QRRepr::Compact { .. } = self.repr {
+            let (q, r) = (self.q(), self.r());
+            self.repr = QRRepr::Thin { q: q, r: r };
+        }
+
+        match self.repr {
+            QRRepr::Thin { ref mut q, ref mut r } => (q, r),
+            QRRepr::Compact { .. } => unreachable!(),
+        }
     }
 
     fn p(&self) -> usize {
@@ -69,13 +194,18 @@ impl<T: QR> QRFactors<T> {
     /// factorization.
     ///
     /// `Q` is generated such that the columns of `Q` form an
-    /// orthogonal basis for the first `k` columns of `A`.
+    /// orthogonal basis for the first `k` columns of `A`. `k` may be
+    /// as large as `m`, in which case the full square matrix `Q` is
+    /// returned.
     ///
     /// When `k` is None, compute enough columns (`min(m, n)`) to
     /// faithfully recreate the original matrix A.
     pub fn qk<K: Into<Option<usize>>>(&self, k: K) -> Result<Array<T, Ix2>, QRError> {
-        let kr = k.into();
-        QR::compute_q(&self.mat, &self.tau, kr.unwrap_or_else(|| self.p()))
+        let k = k.into().unwrap_or_else(|| self.p());
+        match self.repr {
+            QRRepr::Thin { ref q, .. } if k <= q.cols() => Ok(q.slice(s![.., ..k as isize]).to_owned()),
+            _ => self.with_compact(|mat, tau| QR::compute_q(mat, tau, k)),
+        }
     }
 
     /// Return the `m` by `min(m, n)` matrix `Q`.
@@ -98,7 +228,15 @@ impl<T: QR> QRFactors<T> {
     pub fn rk<K: Into<Option<usize>>>(&self, k: K) -> Result<Array<T, Ix2>, QRError> {
         let kr = k.into();
         let p = kr.unwrap_or_else(|| self.p());
-        QR::compute_r(&self.mat, p)
+        match self.repr {
+            QRRepr::Compact { ref mat, .. } => QR::compute_r(mat, p),
+            QRRepr::Thin { ref r, .. } => {
+                if p > r.rows() {
+                    return Err(QRError::InconsistentDimensions);
+                }
+                Ok(r.slice(s![..p as isize, ..]).to_owned())
+            }
+        }
     }
 
     /// Return the first `min(m, n)` by `n` matrix R of the QR
@@ -117,10 +255,242 @@ impl<T: QR> QRFactors<T> {
     pub fn reconstruct(&self) -> Array<T, Ix2> {
         self.q().dot(&self.r())
     }
+
+    /// Multiply the matrix `c` by the full `m` by `m` matrix `Q`,
+    /// consuming `c`, without explicitly forming `Q`.
+    ///
+    /// Computes `Q * C` when `side` is `Side::Left`, and `C * Q` when
+    /// `side` is `Side::Right`. The layout of `c` must match the
+    /// layout of the factored matrix, unless `c` is a single column.
+    ///
+    /// After an update, the reflectors are recomputed from the thin
+    /// factors, in `O(m * n * min(m, n))` operations, and the layout
+    /// of the factored matrix is row-major.
+    pub fn apply_q_into<D>(&self, c: ArrayBase<D, Ix2>, side: Side)
+                           -> Result<ArrayBase<D, Ix2>, QRError>
+        where D: DataOwned<Elem = T> + DataMut<Elem = T>
+    {
+        self.with_compact(|mat, tau| QR::compute_apply_q_into(mat, tau, c, side, Transpose::None))
+    }
+
+    /// Multiply the matrix `c` by the full `m` by `m` matrix `Q`,
+    /// without explicitly forming `Q`. (see `Self::apply_q_into`).
+    pub fn apply_q<D>(&self, c: &ArrayBase<D, Ix2>, side: Side) -> Result<Array<T, Ix2>, QRError>
+        where D: Data<Elem = T>
+    {
+        self.with_compact(|mat, tau| {
+            QR::compute_apply_q_into(mat, tau, copy_with_matching_layout(c, mat), side, Transpose::None)
+        })
+    }
+
+    /// Multiply the matrix `c` by the conjugate transpose `Q^H` of the
+    /// full `m` by `m` matrix `Q`, consuming `c`, without explicitly
+    /// forming `Q`.
+    ///
+    /// Computes `Q^H * C` when `side` is `Side::Left`, and `C * Q^H`
+    /// when `side` is `Side::Right`. For real-valued matrices, `Q^H`
+    /// is the transpose `Q^T`. The layout of `c` must match the
+    /// layout of the factored matrix, unless `c` is a single column.
+    /// (see `Self::apply_q_into` for updated factorizations.)
+    pub fn apply_qt_into<D>(&self, c: ArrayBase<D, Ix2>, side: Side)
+                            -> Result<ArrayBase<D, Ix2>, QRError>
+        where D: DataOwned<Elem = T> + DataMut<Elem = T>
+    {
+        self.with_compact(|mat, tau| {
+            QR::compute_apply_q_into(mat, tau, c, side, Transpose::ConjTranspose)
+        })
+    }
+
+    /// Multiply the matrix `c` by the conjugate transpose `Q^H` of the
+    /// full `m` by `m` matrix `Q`, without explicitly forming
+    /// `Q`. (see `Self::apply_qt_into`).
+    pub fn apply_qt<D>(&self, c: &ArrayBase<D, Ix2>, side: Side) -> Result<Array<T, Ix2>, QRError>
+        where D: Data<Elem = T>
+    {
+        self.with_compact(|mat, tau| {
+            QR::compute_apply_q_into(mat, tau, copy_with_matching_layout(c, mat), side,
+                                     Transpose::ConjTranspose)
+        })
+    }
+}
+
+impl<T: QR + TriangularSolveLinear> QRFactors<T> {
+    /// Return the solution `X` to the least squares problem
+    /// min(||A*X - B||) for the factored matrix `A`.
+    ///
+    /// Each column of `b` is a RHS vector to be solved for. `A` must
+    /// be square or tall (m >= n) and of full rank.
+    ///
+    /// # Errors
+    ///
+    /// Returns `QRError::Underdetermined` when `A` has more columns
+    /// than rows, and `QRError::RankDeficient` when a diagonal
+    /// element of `R` is exactly zero.
+    pub fn solve_least_squares_multi<D>(&self, b: &ArrayBase<D, Ix2>)
+                                        -> Result<Array<T, Ix2>, QRError>
+        where D: Data<Elem = T>
+    {
+        let (m, n) = (self.rows(), self.cols());
+        if m < n {
+            return Err(QRError::Underdetermined);
+        }
+        if b.rows() != m {
+            return Err(QRError::InconsistentDimensions);
+        }
+
+        // Q^H * B, of which only the first n rows contribute to the
+        // solution. The remaining rows are the residual.
+        let x = match self.repr {
+            QRRepr::Compact { ref mat, .. } => {
+                let c = try!(self.apply_qt(b, Side::Left));
+                let c_top = copy_with_matching_layout(&c.slice(s![..n as isize, ..]), mat);
+
+                let r = mat.slice(s![..n as isize, ..]);
+                try!(TriangularSolveLinear::compute_multi_into(&r, Symmetric::Upper,
+                                                               Transpose::None, c_top))
+            },
+            QRRepr::Thin { ref q, ref r } => {
+                let c_top = copy_with_matching_layout(&conj_t(q).dot(b), r);
+                try!(TriangularSolveLinear::compute_multi_into(r, Symmetric::Upper,
+                                                               Transpose::None, c_top))
+            }
+        };
+        Ok(x)
+    }
+
+    /// Return the solution `x` to the least squares problem
+    /// min(||A*x - b||) for the factored matrix `A`. (see
+    /// `Self::solve_least_squares_multi`).
+    pub fn solve_least_squares<D>(&self, b: &ArrayBase<D, Ix1>) -> Result<Array<T, Ix1>, QRError>
+        where D: Data<Elem = T>
+    {
+        let m = b.dim();
+
+        // Create a new matrix, where the column vector is a degenerate 2-D matrix.
+        let b_mat = b.to_owned().into_shape((m, 1)).unwrap();
+
+        let x = try!(self.solve_least_squares_multi(&b_mat));
+        let n = x.rows();
+        Ok(x.into_shape(n).unwrap())
+    }
+}
+
+/// Representation of the components Q, R, P of the column-pivoted
+/// factorization of matrix A.
+#[derive(Debug)]
+pub struct QRPFactors<T: QR> {
+    mat: Array<T, Ix2>,
+    tau: Vec<T>,
+
+    /// column permutation in native fortran format
+    jpvt: Vec<i32>,
+}
+
+impl<T: QR> QRPFactors<T> {
+    /// Return the number of rows in the original matrix
+    pub fn rows(&self) -> usize {
+        self.mat.rows()
+    }
+
+    /// Return the number of columns in the original matrix
+    pub fn cols(&self) -> usize {
+        self.mat.cols()
+    }
+
+    fn p_size(&self) -> usize {
+        cmp::min(self.rows(), self.cols())
+    }
+
+    /// Return the first `k` columns of the matrix Q of the
+    /// factorization.
+    ///
+    /// When `k` is None, compute enough columns (`min(m, n)`) to
+    /// faithfully recreate the original matrix A.
+    pub fn qk<K: Into<Option<usize>>>(&self, k: K) -> Result<Array<T, Ix2>, QRError> {
+        let kr = k.into();
+        QR::compute_q(&self.mat, &self.tau, kr.unwrap_or_else(|| self.p_size()))
+    }
+
+    /// Return the `m` by `min(m, n)` matrix `Q`.
+    ///
+    /// Equivalent to `self.qk(None)`.
+    #[inline]
+    pub fn q(&self) -> Array<T, Ix2> {
+        self.qk(None).expect("Invalid implementation of Self::qk. Please report.")
+    }
+
+    /// Return the first 'k' rows of the matrix R of the
+    /// factorization.
+    ///
+    /// When `k` is None, compute enough rows (`min(m, n)`) to
+    /// faithfully recreate the original matrix A.
+    pub fn rk<K: Into<Option<usize>>>(&self, k: K) -> Result<Array<T, Ix2>, QRError> {
+        let kr = k.into();
+        let p = kr.unwrap_or_else(|| self.p_size());
+        QR::compute_r(&self.mat, p)
+    }
+
+    /// Return the first `min(m, n)` by `n` matrix R of the
+    /// factorization.
+    ///
+    /// Equivalent to `self::rk(None)`.
+    #[inline]
+    pub fn r(&self) -> Array<T, Ix2> {
+        self.rk(None).expect("Invalid implementation of Self::rk. Please report.")
+    }
+
+    /// Return the column permutation as a list of indices.
+    ///
+    /// The `j`th column of `A` * `P` is the `permutation()[j]`th
+    /// column of `A`.
+    pub fn permutation(&self) -> Vec<usize> {
+        self.jpvt.iter().map(|&j| j as usize - 1).collect()
+    }
+
+    /// Return the `n` by `n` permutation matrix `P`.
+    pub fn p(&self) -> Array<T, Ix2> {
+        let n = self.cols();
+        let mut p = Array::zeros((n, n));
+        for (j, i) in self.permutation().into_iter().enumerate() {
+            p[(i, j)] = T::one();
+        }
+        p
+    }
+
+    /// Return the numerical rank of the original matrix.
+    ///
+    /// The rank is the number of diagonal elements of `R` with
+    /// magnitude greater than `tolerance` times the magnitude of the
+    /// first (and largest) diagonal element. When `tolerance` is
+    /// None, `max(m, n)` times the machine epsilon is used.
+    pub fn rank<Tol: Into<Option<T::RealPart>>>(&self, tolerance: Tol) -> usize {
+        let tol = tolerance.into().unwrap_or_else(|| {
+            let mx: T::RealPart = NumCast::from(cmp::max(self.rows(), self.cols())).unwrap();
+            mx * T::eps()
+        });
+
+        let diag = self.mat.diag();
+        if diag.len() == 0 {
+            return 0;
+        }
+
+        let threshold = diag[0].mag() * tol;
+        diag.iter().take_while(|x| x.mag() > threshold).count()
+    }
+
+    /// Reconstruct the original matrix `A` from the factorization.
+    pub fn reconstruct(&self) -> Array<T, Ix2> {
+        let qr = self.q().dot(&self.r());
+        let mut a = Array::zeros(qr.dim());
+        for (j, i) in self.permutation().into_iter().enumerate() {
+            a.column_mut(i).assign(&qr.column(j));
+        }
+        a
+    }
 }
 
 /// Trait defined on scalars to support QR-factorization.
-pub trait QR: nd::LinalgScalar {
+pub trait QR: LinxalImplScalar {
     /// Return a `QRFactors` structure, containing the QR
     /// factorization of the input matrix `A`.
     ///
@@ -135,6 +505,20 @@ pub trait QR: nd::LinalgScalar {
         Self::compute_into(a.to_owned())
     }
 
+    /// Return a `QRPFactors` structure, containing the column-pivoted
+    /// QR factorization of the input matrix `A`.
+    ///
+    /// Similar to `compute_pivoted`, but consumes the input.
+    fn compute_pivoted_into(a: Array<Self, Ix2>) -> Result<QRPFactors<Self>, QRError>;
+
+    /// Return a `QRPFactors` structure, containing the column-pivoted
+    /// QR factorization of the input matrix `A`.
+    fn compute_pivoted<D1>(a: &ArrayBase<D1, Ix2>) -> Result<QRPFactors<Self>, QRError>
+        where D1: Data<Elem = Self>
+    {
+        Self::compute_pivoted_into(a.to_owned())
+    }
+
     /// Compute Q from raw parts.
     ///
     /// Not intended to be used by end-users.
@@ -149,10 +533,27 @@ pub trait QR: nd::LinalgScalar {
     /// Not intended to be used by end-users.
     fn compute_r<D1>(mat: &ArrayBase<D1, Ix2>, k: usize) -> Result<Array<Self, Ix2>, QRError>
         where D1: Data<Elem = Self>;
+
+    /// Multiply `c` by `Q` or `Q^H`, from the side specified by
+    /// `side`, using the raw parts of Q.
+    ///
+    /// `Transpose::Transpose` and `Transpose::ConjTranspose` both
+    /// apply `Q^H`.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_apply_q_into<D1, D2>(mat: &ArrayBase<D1, Ix2>,
+                                    tau: &[Self],
+                                    c: ArrayBase<D2, Ix2>,
+                                    side: Side,
+                                    trans: Transpose)
+                                    -> Result<ArrayBase<D2, Ix2>, QRError>
+        where D1: Data<Elem = Self>,
+              D2: DataOwned<Elem = Self> + DataMut<Elem = Self>;
 }
 
 macro_rules! impl_qr {
-    ($qr_type:ty, $qr_func:ident, $qr_to_q:ident) => (
+    ($qr_type:ty, $qr_func:ident, $qr_to_q:ident, $qrp_func:ident,
+     $qr_apply_q:ident, $qt_trans:expr) => (
 
         impl QR for $qr_type {
             fn compute_into(mut a: Array<Self, Ix2>) -> Result<QRFactors<Self>, QRError> {
@@ -188,6 +589,37 @@ macro_rules! impl_qr {
                 }
             }
 
+            fn compute_pivoted_into(mut a: Array<Self, Ix2>) -> Result<QRPFactors<Self>, QRError> {
+                let dim = a.dim();
+
+                let mut tau = vec![<$qr_type as Zero>::zero(); cmp::min(dim.0, dim.1)];
+
+                // All columns are free to be pivoted.
+                let mut jpvt = vec![0; dim.1];
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                        None => return Err(QRError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $qrp_func(layout, dim.0 as i32, dim.1 as i32, slice, lda as i32,
+                                  &mut jpvt, &mut tau)
+                    }
+                };
+
+                if info == 0 {
+                    Ok(QRPFactors {
+                        mat: a,
+                        tau: tau,
+                        jpvt: jpvt,
+                    })
+                } else {
+                    Err(QRError::IllegalParameter(-info))
+                }
+            }
+
             fn compute_q<D1>(mat: &ArrayBase<D1, Ix2>,
                              tau: &[Self],
                              k: usize)
@@ -200,8 +632,12 @@ macro_rules! impl_qr {
                     return Err(QRError::InconsistentDimensions);
                 }
 
-                // Initialize q with the
-                let mut q = mat.slice(s![.., ..k as isize]).to_owned();
+                // Initialize q with the reflectors. When more columns
+                // than reflectors are requested (k > n), the remaining
+                // columns start as zero.
+                let p = cmp::min(k, n);
+                let mut q = Array::zeros((m, k));
+                q.slice_mut(s![.., ..p as isize]).assign(&mat.slice(s![.., ..p as isize]));
 
                 let info = {
                     let (slice, layout, ldq) = match slice_and_layout_mut(&mut q) {
@@ -213,7 +649,7 @@ macro_rules! impl_qr {
                         $qr_to_q(layout,
                                  m as i32,
                                  k as i32,
-                                 cmp::min(k, n) as i32,
+                                 p as i32,
                                  slice,
                                  ldq as i32,
                                  tau)
@@ -251,11 +687,66 @@ macro_rules! impl_qr {
 
                 Ok(r)
             }
+
+            fn compute_apply_q_into<D1, D2>(mat: &ArrayBase<D1, Ix2>,
+                                            tau: &[Self],
+                                            mut c: ArrayBase<D2, Ix2>,
+                                            side: Side,
+                                            trans: Transpose)
+                                            -> Result<ArrayBase<D2, Ix2>, QRError>
+                where D1: Data<Elem = Self>,
+                      D2: DataOwned<Elem = Self> + DataMut<Elem = Self>
+            {
+                let m = mat.rows();
+                let c_dim = c.dim();
+                let k = tau.len();
+
+                // Q is m by m, so it must match the appropriate
+                // dimension of C.
+                let q_dim = match side {
+                    Side::Left => c_dim.0,
+                    Side::Right => c_dim.1,
+                };
+                if q_dim != m || k > cmp::min(m, mat.cols()) {
+                    return Err(QRError::InconsistentDimensions);
+                }
+
+                // The real and complex routines use different
+                // descriptors for the (conjugate) transpose.
+                let trans_desc = match trans {
+                    Transpose::None => b'N',
+                    _ => $qt_trans,
+                };
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout(mat) {
+                        None => return Err(QRError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    let (c_slice, ldc) = match slice_and_layout_matching_mut(&mut c, layout) {
+                        None => return Err(QRError::InconsistentLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $qr_apply_q(layout, side as u8, trans_desc,
+                                    c_dim.0 as i32, c_dim.1 as i32, k as i32,
+                                    slice, lda as i32, tau, c_slice, ldc as i32)
+                    }
+                };
+
+                if info == 0 {
+                    Ok(c)
+                } else {
+                    Err(QRError::IllegalParameter(-info))
+                }
+            }
         }
     )
 }
 
-impl_qr!(f32, sgeqrf, sorgqr);
-impl_qr!(f64, dgeqrf, dorgqr);
-impl_qr!(c32, cgeqrf, cungqr);
-impl_qr!(c64, zgeqrf, zungqr);
+impl_qr!(f32, sgeqrf, sorgqr, sgeqp3, sormqr, b'T');
+impl_qr!(f64, dgeqrf, dorgqr, dgeqp3, dormqr, b'T');
+impl_qr!(c32, cgeqrf, cungqr, cgeqp3, cunmqr, b'C');
+impl_qr!(c64, zgeqrf, zungqr, zgeqp3, zunmqr, b'C');
@@ -0,0 +1,358 @@
+//! Update a QR-factorization after a low-rank modification of the
+//! factored matrix.
+//!
+//! The compact (Householder) representation returned by `?geqrf`
+//! cannot be cheaply modified. On the first update, `QRFactors`
+//! forms the thin factors `Q` (m x p) and `R` (p x n), with `p =
+//! min(m, n)`, explicitly. Appending or deleting a row or column, or
+//! adding a rank-one matrix `u * v^H`, is then applied to the thin
+//! factors with a sequence of Givens rotations in `O(m * n)`
+//! operations, instead of re-factoring the matrix from scratch.
+//!
+//! When the update adds a direction to the range of `A` that is not
+//! spanned by the thin `Q`, `Q` is first extended by one orthonormal
+//! column, computed with (re-orthogonalized) Gram-Schmidt.
+
+use impl_prelude::*;
+use util::external::conj_t;
+use num_traits::{Float, One};
+use super::qr::{QR, QRFactors, QRError};
+
+/// Return the Givens rotation `G = [c, s; -conj(s), c]` such that
+/// `G * [a; b] = [r; 0]`, as the tuple `(c, s, r)`.
+fn givens<T: LinxalImplScalar>(a: T, b: T) -> (T::RealPart, T, T) {
+    let a_mag = a.mag();
+    let b_mag = b.mag();
+    if b_mag == T::RealPart::zero() {
+        (T::RealPart::one(), T::zero(), a)
+    } else if a_mag == T::RealPart::zero() {
+        (T::RealPart::zero(), T::one(), b)
+    } else {
+        let rho = a_mag.hypot(b_mag);
+        let phase = a / T::from_real(a_mag);
+        (a_mag / rho, phase * b.cj() / T::from_real(rho), phase * T::from_real(rho))
+    }
+}
+
+/// Replace rows `i` and `j` of `mat` by `G * [mat_i; mat_j]`.
+fn rotate_rows<T: LinxalImplScalar>(mat: &mut Array<T, Ix2>, i: usize, j: usize, c: T::RealPart, s: T) {
+    let c = T::from_real(c);
+    for l in 0..mat.cols() {
+        let (x, y) = (mat[(i, l)], mat[(j, l)]);
+        mat[(i, l)] = c * x + s * y;
+        mat[(j, l)] = c * y - s.cj() * x;
+    }
+}
+
+/// Replace columns `i` and `j` of `mat` by `[mat_i, mat_j] * G^H`.
+fn rotate_cols<T: LinxalImplScalar>(mat: &mut Array<T, Ix2>, i: usize, j: usize, c: T::RealPart, s: T) {
+    let c = T::from_real(c);
+    for l in 0..mat.rows() {
+        let (x, y) = (mat[(l, i)], mat[(l, j)]);
+        mat[(l, i)] = c * x + s.cj() * y;
+        mat[(l, j)] = c * y - s * x;
+    }
+}
+
+/// Return the Euclidean norm of a vector.
+fn norm<T: LinxalImplScalar, D: Data<Elem = T>>(x: &ArrayBase<D, Ix1>) -> T::RealPart {
+    x.iter().fold(T::RealPart::zero(), |acc, y| acc.hypot(y.mag()))
+}
+
+/// Split `x` into its components in, and orthogonal to, the range of
+/// `q`, as `x = q * w + rho * z`, with `z` of unit norm and
+/// orthogonal to the columns of `q`. Returns `(w, rho, z)`.
+///
+/// When `x` is (numerically) in the range of `q`, `rho` is zero and
+/// `z` is an arbitrary unit vector orthogonal to the columns of `q`,
+/// which must then have fewer columns than rows.
+fn split<T, D>(q: &Array<T, Ix2>, x: &ArrayBase<D, Ix1>) -> (Array<T, Ix1>, T::RealPart, Array<T, Ix1>)
+    where T: LinxalImplScalar,
+          D: Data<Elem = T>
+{
+    let qh = conj_t(q);
+    let orthogonalize = |z: &mut Array<T, Ix1>, w: &mut Array<T, Ix1>| {
+        // Classical Gram-Schmidt, applied twice to keep `z`
+        // orthogonal to working precision.
+        for _ in 0..2 {
+            let c = qh.dot(z);
+            *z = &*z - &q.dot(&c);
+            *w = &*w + &c;
+        }
+    };
+
+    let mut w = Array::zeros(q.cols());
+    let mut z = x.to_owned();
+    orthogonalize(&mut z, &mut w);
+
+    let rho = norm(&z);
+    if rho > T::tol() * norm(x) {
+        z.mapv_inplace(|y| y / T::from_real(rho));
+        return (w, rho, z);
+    }
+
+    // The unit vector along the row of `q` with the smallest norm has
+    // a component of at least `1 / sqrt(m)` orthogonal to `q`.
+    let j = (0..q.rows())
+        .min_by(|&i, &j| {
+            let (a, b) = (norm(&q.row(i)), norm(&q.row(j)));
+            a.partial_cmp(&b).unwrap_or(cmp::Ordering::Equal)
+        })
+        .expect("The complement of an empty matrix is empty. Please report.");
+    let mut e = Array::zeros(q.rows());
+    e[j] = T::one();
+    orthogonalize(&mut e, &mut Array::zeros(q.cols()));
+    let e_norm = norm(&e);
+    e.mapv_inplace(|y| y / T::from_real(e_norm));
+    (w, T::RealPart::zero(), e)
+}
+
+/// Zero the element `(j, col)` of `r` against the element `(i, col)`,
+/// updating `q` to compensate.
+fn eliminate<T: LinxalImplScalar>(q: &mut Array<T, Ix2>, r: &mut Array<T, Ix2>,
+                                  i: usize, j: usize, col: usize) {
+    let (c, s, x) = givens(r[(i, col)], r[(j, col)]);
+    rotate_rows(r, i, j, c, s);
+    rotate_cols(q, i, j, c, s);
+    r[(i, col)] = x;
+    r[(j, col)] = T::zero();
+}
+
+/// Return `[q, z]` and `[r; 0]`, extending the thin factorization by
+/// the unit vector `z`.
+fn extend<T: LinxalImplScalar>(q: &Array<T, Ix2>, r: &Array<T, Ix2>, z: &Array<T, Ix1>)
+                               -> (Array<T, Ix2>, Array<T, Ix2>) {
+    let (m, p) = q.dim();
+    let mut q2 = Array::zeros((m, p + 1));
+    q2.slice_mut(s![.., ..p as isize]).assign(q);
+    q2.column_mut(p).assign(z);
+
+    let mut r2 = Array::zeros((p + 1, r.cols()));
+    r2.slice_mut(s![..p as isize, ..]).assign(r);
+    (q2, r2)
+}
+
+/// Drop all but the first `p` columns of `q` and rows of `r`, which
+/// must be zero beyond the first `p`.
+fn truncate<T: LinxalImplScalar>(q: &mut Array<T, Ix2>, r: &mut Array<T, Ix2>, p: usize) {
+    if p < q.cols() {
+        *q = q.slice(s![.., ..p as isize]).to_owned();
+        *r = r.slice(s![..p as isize, ..]).to_owned();
+    }
+}
+
+/// Updates of the factorization, as the factored matrix `A` is
+/// modified.
+///
+/// Each update costs `O(m * n)` operations, except for the first,
+/// which forms the thin factors `Q` and `R` explicitly.
+impl<T: QR> QRFactors<T> {
+    /// Update the factorization after inserting `row` into `A`, such
+    /// that `row` becomes the `k`-th row.
+    pub fn insert_row<D>(&mut self, k: usize, row: &ArrayBase<D, Ix1>) -> Result<(), QRError>
+        where D: Data<Elem = T>
+    {
+        let (m, n) = (self.rows(), self.cols());
+        if k > m || row.dim() != n {
+            return Err(QRError::InconsistentDimensions);
+        }
+
+        let (q, r) = self.thin_factors_mut();
+        let p = q.cols();
+
+        // [A_0; row; A_1] = P * diag(1, Q) * [row; R], where the
+        // permutation P moves the first row to position `k`.
+        let mut q2 = Array::zeros((m + 1, p + 1));
+        q2[(k, 0)] = T::one();
+        for i in 0..m {
+            let ip = if i < k { i } else { i + 1 };
+            q2.row_mut(ip).slice_mut(s![1..]).assign(&q.row(i));
+        }
+
+        let mut r2 = Array::zeros((p + 1, n));
+        r2.row_mut(0).assign(row);
+        r2.slice_mut(s![1.., ..]).assign(r);
+
+        // [row; R] is upper Hessenberg.
+        for j in 0..cmp::min(p, n) {
+            eliminate(&mut q2, &mut r2, j, j + 1, j);
+        }
+
+        *q = q2;
+        *r = r2;
+        truncate(q, r, cmp::min(m + 1, n));
+        Ok(())
+    }
+
+    /// Update the factorization after deleting the `k`-th row from
+    /// `A`.
+    pub fn delete_row(&mut self, k: usize) -> Result<(), QRError> {
+        let (m, n) = (self.rows(), self.cols());
+        if k >= m {
+            return Err(QRError::InconsistentDimensions);
+        }
+
+        let (q, r) = self.thin_factors_mut();
+        let p = q.cols();
+
+        // Extend `Q` so that the unit vector `e_k` is in its range,
+        // which gives the `k`-th row of `Q` unit norm.
+        let (mut q2, mut r2) = if p < m {
+            let mut e = Array::zeros(m);
+            e[k] = T::one();
+            let (_, _, z) = split(q, &e);
+            extend(q, r, &z)
+        } else {
+            (q.clone(), r.clone())
+        };
+        let pe = q2.cols();
+
+        // Rotate the `k`-th row of `Q` onto its first column, which
+        // makes the first row of `R` the only contribution to the
+        // `k`-th row of `A`.
+        for j in (1..pe).rev() {
+            let (c, s, _) = givens(q2[(k, j - 1)].cj(), q2[(k, j)].cj());
+            rotate_cols(&mut q2, j - 1, j, c, s);
+            rotate_rows(&mut r2, j - 1, j, c, s);
+            q2[(k, j)] = T::zero();
+        }
+
+        let mut q3 = Array::zeros((m - 1, pe - 1));
+        for i in 0..m - 1 {
+            let ip = if i < k { i } else { i + 1 };
+            q3.row_mut(i).assign(&q2.row(ip).slice(s![1..]));
+        }
+
+        // The remaining rows of `R` are upper-trapezoidal.
+        *q = q3;
+        *r = r2.slice(s![1.., ..]).to_owned();
+        truncate(q, r, cmp::min(m - 1, n));
+        Ok(())
+    }
+
+    /// Update the factorization after inserting `col` into `A`, such
+    /// that `col` becomes the `k`-th column.
+    pub fn insert_col<D>(&mut self, k: usize, col: &ArrayBase<D, Ix1>) -> Result<(), QRError>
+        where D: Data<Elem = T>
+    {
+        let (m, n) = (self.rows(), self.cols());
+        if k > n || col.dim() != m {
+            return Err(QRError::InconsistentDimensions);
+        }
+
+        let (q, r) = self.thin_factors_mut();
+        let p = q.cols();
+
+        // `Q^H * col`, extended by the component of `col` orthogonal
+        // to `Q` when `R` gains a row.
+        let w = if p < m {
+            let (w, rho, z) = split(q, col);
+            let (q2, r2) = extend(q, r, &z);
+            *q = q2;
+            *r = r2;
+
+            let mut we = Array::zeros(p + 1);
+            we.slice_mut(s![..p as isize]).assign(&w);
+            we[p] = T::from_real(rho);
+            we
+        } else {
+            conj_t(q).dot(col)
+        };
+        let pe = q.cols();
+
+        let mut r2 = Array::zeros((pe, n + 1));
+        r2.slice_mut(s![.., ..k as isize]).assign(&r.slice(s![.., ..k as isize]));
+        r2.column_mut(k).assign(&w);
+        r2.slice_mut(s![.., (k + 1) as isize..]).assign(&r.slice(s![.., k as isize..]));
+        *r = r2;
+
+        // Clear the new column from the bottom up, which only fills
+        // the diagonal of the following columns.
+        for i in (k + 1..pe).rev() {
+            eliminate(q, r, i - 1, i, k);
+        }
+        Ok(())
+    }
+
+    /// Update the factorization after deleting the `k`-th column
+    /// from `A`.
+    pub fn delete_col(&mut self, k: usize) -> Result<(), QRError> {
+        let (m, n) = (self.rows(), self.cols());
+        if k >= n {
+            return Err(QRError::InconsistentDimensions);
+        }
+
+        let (q, r) = self.thin_factors_mut();
+        let p = q.cols();
+
+        let mut r2 = Array::zeros((p, n - 1));
+        r2.slice_mut(s![.., ..k as isize]).assign(&r.slice(s![.., ..k as isize]));
+        r2.slice_mut(s![.., k as isize..]).assign(&r.slice(s![.., (k + 1) as isize..]));
+        *r = r2;
+
+        // The columns after `k` are upper Hessenberg.
+        for j in k..cmp::min(p.saturating_sub(1), n - 1) {
+            eliminate(q, r, j, j + 1, j);
+        }
+
+        truncate(q, r, cmp::min(m, n - 1));
+        Ok(())
+    }
+
+    /// Update the factorization of `A` to the factorization of `A +
+    /// u * v^H`.
+    pub fn rank_one_update<D1, D2>(&mut self, u: &ArrayBase<D1, Ix1>, v: &ArrayBase<D2, Ix1>)
+                                   -> Result<(), QRError>
+        where D1: Data<Elem = T>,
+              D2: Data<Elem = T>
+    {
+        let (m, n) = (self.rows(), self.cols());
+        if u.dim() != m || v.dim() != n {
+            return Err(QRError::InconsistentDimensions);
+        }
+
+        let (q, r) = self.thin_factors_mut();
+        let p = q.cols();
+
+        // `w = Q^H * u`, extended by the component of `u` orthogonal
+        // to `Q`.
+        let (mut q2, mut r2, mut w) = if p < m {
+            let (w, rho, z) = split(q, u);
+            let (q2, r2) = extend(q, r, &z);
+
+            let mut we = Array::zeros(p + 1);
+            we.slice_mut(s![..p as isize]).assign(&w);
+            we[p] = T::from_real(rho);
+            (q2, r2, we)
+        } else {
+            (q.clone(), r.clone(), conj_t(q).dot(u))
+        };
+        let pe = q2.cols();
+
+        // Rotate `w` onto the first unit vector, which makes `R`
+        // upper Hessenberg.
+        for i in (1..pe).rev() {
+            let (c, s, x) = givens(w[i - 1], w[i]);
+            rotate_rows(&mut r2, i - 1, i, c, s);
+            rotate_cols(&mut q2, i - 1, i, c, s);
+            w[i - 1] = x;
+            w[i] = T::zero();
+        }
+
+        if pe > 0 {
+            let w0 = w[0];
+            for (x, y) in r2.row_mut(0).iter_mut().zip(v.iter()) {
+                *x = *x + w0 * y.cj();
+            }
+        }
+
+        for j in 0..cmp::min(pe.saturating_sub(1), n) {
+            eliminate(&mut q2, &mut r2, j, j + 1, j);
+        }
+
+        *q = q2;
+        *r = r2;
+        truncate(q, r, p);
+        Ok(())
+    }
+}
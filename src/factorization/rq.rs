@@ -0,0 +1,225 @@
+//! Compute the RQ-factorization of a rectangular matrix.
+//!
+//! An (m x n) rectangular matrix `A` is factored into the product `R`
+//! * `Q`, such that
+//!
+//! - The rows of `Q` are orthonormal.
+//!
+//! - `R` is an upper triangular or upper-trapezoidal matrix.
+//!
+//! Unlike the QR-factorization, the triangular factor is aligned
+//! with the last columns (and the orthonormal rows with the last rows
+//! of the full `Q`). For a 3 x 4 camera matrix `P = [M | m]`, the RQ
+//! factorization of `M` separates the intrinsic (upper triangular)
+//! and rotation (orthogonal) components.
+
+use impl_prelude::*;
+use lapack::c::{sgerqf, dgerqf, cgerqf, zgerqf, sorgrq, dorgrq, cungrq, zungrq};
+use super::qr::QRError;
+
+/// Representation of the components R, Q of the factorization of
+/// matrix A.
+#[derive(Debug)]
+pub struct RQFactors<T: RQ> {
+    mat: Array<T, Ix2>,
+    tau: Vec<T>,
+}
+
+impl<T: RQ> RQFactors<T> {
+    /// Create a `RQFactors` object from the output of the LAPACKE
+    /// functions.
+    fn from_raw<Matrix>(mat: Matrix, tau: Vec<T>) -> Result<RQFactors<T>, QRError>
+        where Matrix: Into<Array<T, Ix2>>
+    {
+        let mut ma = mat.into();
+
+        if slice_and_layout_mut(&mut ma).is_none() {
+            return Err(QRError::BadLayout);
+        }
+
+        Ok(RQFactors {
+            mat: ma,
+            tau: tau,
+        })
+    }
+
+    /// Return the number of rows in the original matrix
+    pub fn rows(&self) -> usize {
+        self.mat.rows()
+    }
+
+    /// Return the number of columns in the original matrix
+    pub fn cols(&self) -> usize {
+        self.mat.cols()
+    }
+
+    fn p(&self) -> usize {
+        cmp::min(self.rows(), self.cols())
+    }
+
+    /// Return the last `k` rows of the matrix Q of the RQ
+    /// factorization.
+    ///
+    /// `Q` is generated such that the rows of `Q` form an orthogonal
+    /// basis for the last `k` rows of `A`. `k` may be as large as
+    /// `n`, in which case the full square matrix `Q` is returned.
+    ///
+    /// When `k` is None, compute enough rows (`min(m, n)`) to
+    /// faithfully recreate the original matrix A.
+    pub fn qk<K: Into<Option<usize>>>(&self, k: K) -> Result<Array<T, Ix2>, QRError> {
+        let kr = k.into();
+        RQ::compute_q(&self.mat, &self.tau, kr.unwrap_or_else(|| self.p()))
+    }
+
+    /// Return the `min(m, n)` by `n` matrix `Q`.
+    ///
+    /// Equivalent to `self.qk(None)`.
+    #[inline]
+    pub fn q(&self) -> Array<T, Ix2> {
+        self.qk(None).expect("Invalid implementation of Self::qk. Please report.")
+    }
+
+    /// Return the last `k` columns of the matrix R of the RQ
+    /// factorization.
+    ///
+    /// When `k` is None, compute enough columns (`min(m, n)`) to
+    /// faithfully recreate the original matrix A.
+    pub fn rk<K: Into<Option<usize>>>(&self, k: K) -> Result<Array<T, Ix2>, QRError> {
+        let kr = k.into();
+        RQ::compute_r(&self.mat, kr.unwrap_or_else(|| self.p()))
+    }
+
+    /// Return the `m` by `min(m, n)` matrix R of the RQ
+    /// factorization.
+    ///
+    /// Equivalent to `self.rk(None)`.
+    #[inline]
+    pub fn r(&self) -> Array<T, Ix2> {
+        self.rk(None).expect("Invalid implementation of Self::rk. Please report.")
+    }
+
+    /// Reconstruct the original matrix `A` from the factorization.
+    pub fn reconstruct(&self) -> Array<T, Ix2> {
+        self.r().dot(&self.q())
+    }
+}
+
+/// Trait defined on scalars to support RQ-factorization.
+pub trait RQ: LinxalImplScalar {
+    /// Return a `RQFactors` structure, containing the RQ
+    /// factorization of the input matrix `A`.
+    ///
+    /// Similar to `compute`, but consumes the input.
+    fn compute_into(a: Array<Self, Ix2>) -> Result<RQFactors<Self>, QRError>;
+
+    /// Return a `RQFactors` structure, containing the RQ
+    /// factorization of the input matrix `A`.
+    fn compute<D1>(a: &ArrayBase<D1, Ix2>) -> Result<RQFactors<Self>, QRError>
+        where D1: Data<Elem = Self>
+    {
+        Self::compute_into(a.to_owned())
+    }
+
+    /// Compute Q from raw parts.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_q<D1>(mat: &ArrayBase<D1, Ix2>,
+                     tau: &[Self],
+                     k: usize)
+                     -> Result<Array<Self, Ix2>, QRError>
+        where D1: Data<Elem = Self>;
+
+    /// Compute the last `k` columns of R from raw parts.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_r<D1>(mat: &ArrayBase<D1, Ix2>, k: usize) -> Result<Array<Self, Ix2>, QRError>
+        where D1: Data<Elem = Self>
+    {
+        let (m, n) = mat.dim();
+        if k > cmp::min(m, n) {
+            return Err(QRError::InconsistentDimensions);
+        }
+
+        // R is stored in the last `k` columns, on and above the
+        // `(m - k)`-th subdiagonal.
+        let mut r = Array::zeros((m, k));
+        for ((i, j), x) in r.indexed_iter_mut() {
+            if j + m - k >= i {
+                *x = mat[(i, n - k + j)];
+            }
+        }
+
+        Ok(r)
+    }
+}
+
+macro_rules! impl_rq {
+    ($rq_type:ty, $rq_func:ident, $rq_to_q:ident) => (
+        impl RQ for $rq_type {
+            fn compute_into(mut a: Array<Self, Ix2>) -> Result<RQFactors<Self>, QRError> {
+                let dim = a.dim();
+
+                let mut tau = vec![<$rq_type as Zero>::zero(); cmp::min(dim.0, dim.1)];
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                        None => return Err(QRError::BadLayout),
+                        Some(x) => x,
+                    };
+
+                    unsafe {
+                        $rq_func(layout, dim.0 as i32, dim.1 as i32, slice, lda as i32, &mut tau)
+                    }
+                };
+
+                if info == 0 {
+                    RQFactors::from_raw(a, tau)
+                } else {
+                    Err(QRError::IllegalParameter(-info))
+                }
+            }
+
+            fn compute_q<D1>(mat: &ArrayBase<D1, Ix2>,
+                             tau: &[Self],
+                             k: usize)
+                             -> Result<Array<Self, Ix2>, QRError>
+                where D1: Data<Elem = Self>
+            {
+                let (m, n) = mat.dim();
+                if k > n || tau.len() > cmp::min(m, n) {
+                    return Err(QRError::InconsistentDimensions);
+                }
+
+                // The reflectors are stored in the last rows, and the
+                // last `k` rows of Q only depend on the last `k`
+                // reflectors. When more rows than reflectors are
+                // requested, the remaining leading rows start as zero.
+                let p = cmp::min(k, tau.len());
+                let mut q = Array::zeros((k, n));
+                q.slice_mut(s![(k - p) as isize.., ..]).assign(&mat.slice(s![(m - p) as isize.., ..]));
+                let tau = &tau[tau.len() - p..];
+
+                let info = {
+                    let (slice, layout, ldq) = match slice_and_layout_mut(&mut q) {
+                        None => unreachable!(),
+                        Some(fwd) => fwd,
+                    };
+
+                    unsafe {
+                        $rq_to_q(layout, k as i32, n as i32, p as i32, slice, ldq as i32, tau)
+                    }
+                };
+                if info == 0 {
+                    Ok(q)
+                } else {
+                    Err(QRError::IllegalParameter(-info))
+                }
+            }
+        }
+    )
+}
+
+impl_rq!(f32, sgerqf, sorgrq);
+impl_rq!(f64, dgerqf, dorgrq);
+impl_rq!(c32, cgerqf, cungrq);
+impl_rq!(c64, zgerqf, zungrq);
@@ -0,0 +1,147 @@
+//! Compute the tridiagonal reduction of a symmetric or Hermitian
+//! matrix.
+//!
+//! An (n x n) symmetric (or Hermitian) matrix `A` is reduced to the
+//! product `Q` * `T` * `Q^H` = `A`, such that
+//!
+//! - `Q` is unitary (orthogonal, for real matrices).
+//!
+//! - `T` is real, symmetric and tridiagonal.
+//!
+//! The reduction is the first step of the symmetric eigenvalue
+//! drivers. `T` is represented by its diagonal and off-diagonal.
+
+use impl_prelude::*;
+use lapack::c::{ssytrd, dsytrd, chetrd, zhetrd, sorgtr, dorgtr, cungtr, zungtr};
+use util::external::conj_t;
+use super::hessenberg::ReductionError;
+
+/// Representation of the tridiagonal reduction `A = Q * T * Q^H` of
+/// a symmetric or Hermitian matrix.
+#[derive(Debug, Clone)]
+pub struct TridiagonalFactors<T: Tridiagonal> {
+    q: Array<T, Ix2>,
+    diag: Array<T::RealPart, Ix1>,
+    off_diag: Array<T::RealPart, Ix1>,
+}
+
+impl<T: Tridiagonal> TridiagonalFactors<T> {
+    /// Return the unitary matrix `Q`.
+    pub fn q(&self) -> &Array<T, Ix2> {
+        &self.q
+    }
+
+    /// Return the `n` diagonal elements of `T`.
+    pub fn diag(&self) -> &Array<T::RealPart, Ix1> {
+        &self.diag
+    }
+
+    /// Return the `n - 1` off-diagonal elements of `T`.
+    pub fn off_diag(&self) -> &Array<T::RealPart, Ix1> {
+        &self.off_diag
+    }
+
+    /// Return the tridiagonal matrix `T`.
+    pub fn t(&self) -> Array<T, Ix2> {
+        let n = self.diag.dim();
+        let mut t = Array::zeros((n, n));
+        for (i, &d) in self.diag.iter().enumerate() {
+            t[(i, i)] = T::from_real(d);
+        }
+        for (i, &e) in self.off_diag.iter().enumerate() {
+            t[(i, i + 1)] = T::from_real(e);
+            t[(i + 1, i)] = T::from_real(e);
+        }
+        t
+    }
+
+    /// Return the factor `Q` and the diagonal and off-diagonal of
+    /// `T`, consuming the reduction.
+    pub fn into_parts(self) -> (Array<T, Ix2>, Array<T::RealPart, Ix1>, Array<T::RealPart, Ix1>) {
+        (self.q, self.diag, self.off_diag)
+    }
+
+    /// Reconstruct the original matrix `A` from the reduction.
+    pub fn reconstruct(&self) -> Array<T, Ix2> {
+        self.q.dot(&self.t()).dot(&conj_t(&self.q))
+    }
+}
+
+/// Trait defined on scalars to support the tridiagonal reduction of
+/// symmetric matrices.
+pub trait Tridiagonal: LinxalImplScalar {
+    /// Return the tridiagonal reduction of the symmetric (or
+    /// Hermitian) matrix `A`, consuming the input.
+    ///
+    /// Only the portion of `a` specified by `uplo` is read.
+    fn compute_into(a: Array<Self, Ix2>, uplo: Symmetric)
+                    -> Result<TridiagonalFactors<Self>, ReductionError>;
+
+    /// Return the tridiagonal reduction of the symmetric (or
+    /// Hermitian) matrix `A`.
+    fn compute<D>(a: &ArrayBase<D, Ix2>, uplo: Symmetric)
+                  -> Result<TridiagonalFactors<Self>, ReductionError>
+        where D: Data<Elem = Self>
+    {
+        Self::compute_into(a.to_owned(), uplo)
+    }
+}
+
+macro_rules! impl_tridiagonal {
+    ($impl_type:ident, $reduce_func:ident, $q_func:ident) => (
+        impl Tridiagonal for $impl_type {
+            fn compute_into(mut a: Array<Self, Ix2>, uplo: Symmetric)
+                            -> Result<TridiagonalFactors<Self>, ReductionError> {
+                let dim = a.dim();
+                if dim.0 != dim.1 {
+                    return Err(ReductionError::NotSquare);
+                }
+                let n = dim.0;
+                if n == 0 {
+                    return Ok(TridiagonalFactors {
+                        q: a,
+                        diag: Array::default(0),
+                        off_diag: Array::default(0),
+                    });
+                }
+
+                let mut diag = vec![0.0; n];
+                let mut off_diag = vec![0.0; n - 1];
+                let mut tau = vec![Self::zero(); n - 1];
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                        Some(x) => x,
+                        None => return Err(ReductionError::BadLayout),
+                    };
+                    let info = unsafe {
+                        $reduce_func(layout, uplo as u8, n as i32, slice, lda as i32,
+                                     &mut diag, &mut off_diag, &mut tau)
+                    };
+                    if info != 0 {
+                        return Err(ReductionError::IllegalParameter(-info));
+                    }
+
+                    // Form `Q` in place from the reflectors.
+                    unsafe {
+                        $q_func(layout, uplo as u8, n as i32, slice, lda as i32, &tau)
+                    }
+                };
+                if info != 0 {
+                    return Err(ReductionError::IllegalParameter(-info));
+                }
+
+                Ok(TridiagonalFactors {
+                    q: a,
+                    diag: ArrayBase::from_vec(diag),
+                    off_diag: ArrayBase::from_vec(off_diag),
+                })
+            }
+        }
+    )
+}
+
+impl_tridiagonal!(f32, ssytrd, sorgtr);
+impl_tridiagonal!(f64, dsytrd, dorgtr);
+impl_tridiagonal!(c32, chetrd, cungtr);
+impl_tridiagonal!(c64, zhetrd, zungtr);
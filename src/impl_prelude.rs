@@ -3,5 +3,5 @@ pub use ndarray::{Data, DataMut, DataOwned, Ix, Ixs};
 pub use num_traits::{Zero};
 pub use util::internal::*;
 pub use lapack::{c32, c64};
-pub use types::{LinxalImplScalar, Symmetric};
+pub use types::{LinxalImplScalar, Symmetric, Transpose, Side};
 pub use std::cmp;
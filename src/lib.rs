@@ -181,6 +181,9 @@ pub mod types;
 pub mod factorization;
 pub mod generate;
 pub mod properties;
+pub mod condition;
+pub mod norms;
+pub mod matrix_equations;
 
 #[macro_use]
 pub mod prelude;
@@ -0,0 +1,497 @@
+//! Solve linear matrix equations.
+//!
+//! The Sylvester equation `A * X + X * B = C` has a unique solution
+//! `X` exactly when `A` and `-B` have no eigenvalues in common. It is
+//! solved with the Bartels-Stewart algorithm: `A` and `B` are reduced
+//! to Schur form, and the resulting (quasi-)triangular equation is
+//! solved by substitution with `?trsyl`.
+//!
+//! The Lyapunov equations are special cases:
+//!
+//! - The continuous Lyapunov equation `A * X + X * A^H + Q = 0`
+//! arises for the controllability Gramians of continuous-time systems.
+//!
+//! - The discrete Lyapunov (Stein) equation `A * X * A^H - X + Q = 0`
+//! arises for the Gramians and steady-state covariances of
+//! discrete-time systems. It is mapped to a continuous Lyapunov
+//! equation with a Cayley transform.
+//!
+//! The algebraic Riccati equations of optimal control and filtering
+//! are solved with Laub's method: the stabilizing solution is
+//! recovered from the stable invariant subspace of a Hamiltonian
+//! matrix (for the continuous equation), computed from an ordered
+//! Schur decomposition, or from the stable deflating subspace of a
+//! symplectic pencil (for the discrete equation), computed from an
+//! ordered generalized Schur decomposition. The pencil avoids
+//! inverting `A`, so the discrete equation may have a singular `A`.
+#![deny(missing_docs)]
+
+use impl_prelude::*;
+use lapack::c::{strsyl, dtrsyl, ctrsyl, ztrsyl};
+use eigenvalues::{EigenError, Schur, GeneralizedSchur};
+use solve_linear::{SolveError, SolveLinear};
+use util::external::conj_t;
+use num_traits::{Float, One};
+
+/// Error for matrix equation solvers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatrixEquationError {
+    /// The layout of a matrix is not compatible
+    BadLayout,
+
+    /// A coefficient matrix is not square.
+    NotSquare,
+
+    /// The dimensions of the coefficient matrices and the right-hand
+    /// side don't match.
+    InconsistentDimensions,
+
+    /// The layouts of the input matrices don't match. (i.e. one is
+    /// column-major and the other is row-major.)
+    InconsistentLayout,
+
+    /// The equation does not have a unique solution, because the
+    /// coefficient matrices have (nearly) conflicting eigenvalues.
+    Singular,
+
+    /// The Schur decomposition of a coefficient matrix failed to
+    /// converge.
+    Failed,
+
+    /// The Riccati equation has no stabilizing solution. (e.g. the
+    /// system is not stabilizable, or has uncontrollable modes on the
+    /// stability boundary.)
+    NoStabilizingSolution,
+
+    /// Implementation error, please submit as bug.
+    IllegalParameter(i32),
+}
+
+impl From<EigenError> for MatrixEquationError {
+    fn from(e: EigenError) -> MatrixEquationError {
+        match e {
+            EigenError::NotSquare => MatrixEquationError::NotSquare,
+            EigenError::BadLayout => MatrixEquationError::BadLayout,
+            EigenError::InconsistentLayout => MatrixEquationError::InconsistentLayout,
+            EigenError::IllegalParameter(i) => MatrixEquationError::IllegalParameter(i),
+            EigenError::InconsistentDimensions => MatrixEquationError::InconsistentDimensions,
+            // Matrix equations only compute (and reorder) Schur
+            // decompositions of general matrices, which never report
+            // the remaining errors.
+            EigenError::Failed |
+            EigenError::NotPositiveDefinite |
+            EigenError::InvalidRange => MatrixEquationError::Failed,
+        }
+    }
+}
+
+impl From<SolveError> for MatrixEquationError {
+    fn from(e: SolveError) -> MatrixEquationError {
+        match e {
+            SolveError::BadLayout => MatrixEquationError::BadLayout,
+            SolveError::InconsistentLayout => MatrixEquationError::InconsistentLayout,
+            SolveError::IllegalValue(i) => MatrixEquationError::IllegalParameter(i),
+            SolveError::Singular(_) => MatrixEquationError::Singular,
+            SolveError::NotSquare(_, _) => MatrixEquationError::NotSquare,
+            SolveError::InconsistentDimensions(_, _) => MatrixEquationError::InconsistentDimensions,
+        }
+    }
+}
+
+/// Stabilizing solution of an algebraic Riccati equation.
+#[derive(Debug)]
+pub struct RiccatiSolution<T: LinxalImplScalar> {
+    /// Hermitian solution `X` of the equation.
+    pub solution: Array<T, Ix2>,
+
+    /// Eigenvalues of the closed-loop system matrix `A - B * K`, for
+    /// the optimal feedback gain `K`.
+    pub closed_loop_eigenvalues: Array<T::Complex, Ix1>,
+
+    /// Frobenius norm of the residual of the equation at `solution`.
+    pub residual: T::RealPart,
+}
+
+/// Return the matrix `[a11, a12; a21, a22]`.
+fn block<T: LinxalImplScalar>(a11: &Array<T, Ix2>, a12: &Array<T, Ix2>,
+                              a21: &Array<T, Ix2>, a22: &Array<T, Ix2>) -> Array<T, Ix2> {
+    let n = a11.rows() as isize;
+    let mut h = Array::zeros((2 * a11.rows(), 2 * a11.rows()));
+    h.slice_mut(s![..n, ..n]).assign(a11);
+    h.slice_mut(s![..n, n..]).assign(a12);
+    h.slice_mut(s![n.., ..n]).assign(a21);
+    h.slice_mut(s![n.., n..]).assign(a22);
+    h
+}
+
+/// Return the Frobenius norm of `a`.
+fn frobenius<T: LinxalImplScalar>(a: &Array<T, Ix2>) -> T::RealPart {
+    a.iter().fold(T::RealPart::zero(), |acc, x| acc + x.mag() * x.mag()).sqrt()
+}
+
+/// Return the solution `X = U21 * U11^-1` of a Riccati equation from
+/// the (2n x 2n) matrix `z`, whose first `n` columns `[U11; U21]` are
+/// a basis of the stable subspace.
+fn stable_subspace_solution<T>(z: &Array<T, Ix2>, n: usize)
+                               -> Result<Array<T, Ix2>, MatrixEquationError>
+    where T: MatrixEquations
+{
+    // Solve X * U11 = U21 as U11^H * X^H = U21^H.
+    let n = n as isize;
+    let u11_h = conj_t(&z.slice(s![..n, ..n]));
+    let u21_h = conj_t(&z.slice(s![n.., ..n]));
+    let x_h = match SolveLinear::compute_multi(&u11_h, &u21_h) {
+        Ok(x) => x,
+        Err(SolveError::Singular(_)) => return Err(MatrixEquationError::NoStabilizingSolution),
+        Err(e) => return Err(e.into()),
+    };
+
+    // The solution is Hermitian, up to rounding errors.
+    let half = T::from_real(T::RealPart::one() / (T::RealPart::one() + T::RealPart::one()));
+    Ok((conj_t(&x_h) + &x_h).mapv(|v| v * half))
+}
+
+/// Check the dimensions of the Riccati equation for the system `(A,
+/// B)` with weights `Q` and `R`, and return the number of states and
+/// inputs.
+fn riccati_dim<T, D1, D2, D3, D4>(a: &ArrayBase<D1, Ix2>, b: &ArrayBase<D2, Ix2>,
+                                  q: &ArrayBase<D3, Ix2>, r: &ArrayBase<D4, Ix2>)
+                                  -> Result<(usize, usize), MatrixEquationError>
+    where D1: Data<Elem = T>,
+          D2: Data<Elem = T>,
+          D3: Data<Elem = T>,
+          D4: Data<Elem = T>
+{
+    if a.rows() != a.cols() || r.rows() != r.cols() {
+        return Err(MatrixEquationError::NotSquare);
+    }
+    let (n, m) = b.dim();
+    if a.rows() != n || q.dim() != (n, n) || r.rows() != m {
+        return Err(MatrixEquationError::InconsistentDimensions);
+    }
+    Ok((n, m))
+}
+
+/// Trait defined on scalars to support solving matrix equations.
+pub trait MatrixEquations: Schur + GeneralizedSchur + SolveLinear {
+    /// Solve the (quasi-)triangular Sylvester equation `A * X + X *
+    /// op(B) = scale * C` in place, where `op(B)` is `B^H` if
+    /// `conj_b` is true, and `B` otherwise. Return `scale`, which is
+    /// chosen to avoid overflow in `X`.
+    ///
+    /// `a` and `b` must be in Schur canonical form.
+    ///
+    /// Not intended to be used by end-users.
+    fn compute_triangular_sylvester_mut<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                                                b: &ArrayBase<D2, Ix2>,
+                                                c: &mut Array<Self, Ix2>,
+                                                conj_b: bool)
+                                                -> Result<Self::RealPart, MatrixEquationError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>;
+
+    /// Return the solution `X` to the Sylvester equation `A * X + X *
+    /// B = C`, for an (m x m) matrix `A`, an (n x n) matrix `B` and an
+    /// (m x n) matrix `C`.
+    fn sylvester<D1, D2, D3>(a: &ArrayBase<D1, Ix2>,
+                             b: &ArrayBase<D2, Ix2>,
+                             c: &ArrayBase<D3, Ix2>)
+                             -> Result<Array<Self, Ix2>, MatrixEquationError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>,
+              D3: Data<Elem = Self>
+    {
+        if a.rows() != a.cols() || b.rows() != b.cols() {
+            return Err(MatrixEquationError::NotSquare);
+        }
+        let (m, n) = c.dim();
+        if a.rows() != m || b.rows() != n {
+            return Err(MatrixEquationError::InconsistentDimensions);
+        }
+        if m == 0 || n == 0 {
+            return Ok(Array::zeros((m, n)));
+        }
+
+        let (ta, u) = try!(<Self as Schur>::compute(a)).into_parts();
+        let (tb, v) = try!(<Self as Schur>::compute(b)).into_parts();
+        let tb = copy_with_matching_layout(&tb, &ta);
+
+        // (U^H * C * V) is the right-hand side of the triangular
+        // equation.
+        let mut y = copy_with_matching_layout(&conj_t(&u).dot(c).dot(&v), &ta);
+        let scale = try!(Self::compute_triangular_sylvester_mut(&ta, &tb, &mut y, false));
+
+        let s = Self::from_real(scale);
+        Ok(u.dot(&y).dot(&conj_t(&v)).mapv(|x| x / s))
+    }
+
+    /// Return the solution `X` to the continuous Lyapunov equation `A
+    /// * X + X * A^H + Q = 0`.
+    ///
+    /// The solution is unique when no two eigenvalues of `A` sum to
+    /// zero (counting conjugates). In particular, when `A` is stable
+    /// and `Q` is Hermitian positive semi-definite, `X` is Hermitian
+    /// positive semi-definite.
+    fn lyapunov<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                        q: &ArrayBase<D2, Ix2>)
+                        -> Result<Array<Self, Ix2>, MatrixEquationError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>
+    {
+        if a.rows() != a.cols() {
+            return Err(MatrixEquationError::NotSquare);
+        }
+        let n = a.rows();
+        if q.dim() != (n, n) {
+            return Err(MatrixEquationError::InconsistentDimensions);
+        }
+        if n == 0 {
+            return Ok(Array::zeros((0, 0)));
+        }
+
+        // With A = U * T * U^H, solve T * Y + Y * T^H = -U^H * Q * U.
+        let (t, u) = try!(<Self as Schur>::compute(a)).into_parts();
+        let mut y = copy_with_matching_layout(&conj_t(&u).dot(q).dot(&u).mapv(|x| Self::zero() - x), &t);
+        let scale = try!(Self::compute_triangular_sylvester_mut(&t, &t, &mut y, true));
+
+        let s = Self::from_real(scale);
+        Ok(u.dot(&y).dot(&conj_t(&u)).mapv(|x| x / s))
+    }
+
+    /// Return the solution `X` to the discrete Lyapunov equation `A *
+    /// X * A^H - X + Q = 0`.
+    ///
+    /// The solution is unique when no two eigenvalues of `A` have a
+    /// product of one (counting conjugates). In particular, when all
+    /// eigenvalues of `A` lie inside the unit circle and `Q` is
+    /// Hermitian positive semi-definite, `X` is Hermitian positive
+    /// semi-definite.
+    ///
+    /// # Remarks
+    ///
+    /// The equation is transformed into the continuous Lyapunov
+    /// equation for `(A + I)^-1 * (A - I)`, so accuracy suffers when
+    /// `A` has eigenvalues close to -1.
+    fn discrete_lyapunov<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                                 q: &ArrayBase<D2, Ix2>)
+                                 -> Result<Array<Self, Ix2>, MatrixEquationError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>
+    {
+        if a.rows() != a.cols() {
+            return Err(MatrixEquationError::NotSquare);
+        }
+        let n = a.rows();
+        if q.dim() != (n, n) {
+            return Err(MatrixEquationError::InconsistentDimensions);
+        }
+        if n == 0 {
+            return Ok(Array::zeros((0, 0)));
+        }
+
+        let mut a_plus = a.to_owned();
+        let mut a_minus = a.to_owned();
+        for i in 0..n {
+            a_plus[(i, i)] = a_plus[(i, i)] + Self::one();
+            a_minus[(i, i)] = a_minus[(i, i)] - Self::one();
+        }
+
+        // With M = A + I, the equation becomes
+        // Ac * X + X * Ac^H + 2 * M^-1 * Q * M^-H = 0.
+        let ac = try!(SolveLinear::compute_multi(&a_plus, &a_minus));
+        let w = try!(SolveLinear::compute_multi(&a_plus, q));
+        let w = try!(SolveLinear::compute_multi(&a_plus, &conj_t(&w)));
+        let two = Self::one() + Self::one();
+        let qc = conj_t(&w).mapv(|x| x * two);
+
+        Self::lyapunov(&ac, &qc)
+    }
+
+    /// Return the stabilizing solution `X` to the continuous
+    /// algebraic Riccati equation `A^H * X + X * A - X * B * R^-1 *
+    /// B^H * X + Q = 0`, for an (n x n) matrix `A`, an (n x m) matrix
+    /// `B`, a Hermitian (n x n) matrix `Q` and a Hermitian (m x m)
+    /// matrix `R`.
+    ///
+    /// The optimal feedback gain is `K = R^-1 * B^H * X`, and all of
+    /// the closed-loop eigenvalues have negative real parts.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MatrixEquationError::NoStabilizingSolution` when the
+    /// Hamiltonian matrix does not have exactly `n` eigenvalues with
+    /// negative real part, or the solution cannot be recovered from
+    /// its stable invariant subspace. `MatrixEquationError::Singular`
+    /// is returned when `R` is singular.
+    fn care<D1, D2, D3, D4>(a: &ArrayBase<D1, Ix2>,
+                            b: &ArrayBase<D2, Ix2>,
+                            q: &ArrayBase<D3, Ix2>,
+                            r: &ArrayBase<D4, Ix2>)
+                            -> Result<RiccatiSolution<Self>, MatrixEquationError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>,
+              D3: Data<Elem = Self>,
+              D4: Data<Elem = Self>
+    {
+        let (n, _) = try!(riccati_dim(a, b, q, r));
+        if n == 0 {
+            return Ok(RiccatiSolution {
+                solution: Array::zeros((0, 0)),
+                closed_loop_eigenvalues: Array::default(0),
+                residual: Self::RealPart::zero(),
+            });
+        }
+
+        let neg = |x: Self| Self::zero() - x;
+        let a_h = conj_t(a);
+        let g = b.dot(&try!(SolveLinear::compute_multi(r, &conj_t(b))));
+
+        // The Hamiltonian matrix [A, -G; -Q, -A^H], with G = B * R^-1 * B^H.
+        let h = block(&a.to_owned(), &g.mapv(&neg), &q.mapv(&neg), &a_h.mapv(&neg));
+        let mut schur = try!(<Self as Schur>::compute(&h));
+        if try!(schur.reorder(|v: Self::Complex| v.re() < Zero::zero())) != n {
+            return Err(MatrixEquationError::NoStabilizingSolution);
+        }
+        let x = try!(stable_subspace_solution(schur.z(), n));
+        let values = schur.values().slice(s![..n as isize]).to_owned();
+
+        let residual = a_h.dot(&x) + &x.dot(a) - &x.dot(&g).dot(&x) + q;
+        Ok(RiccatiSolution {
+            solution: x,
+            closed_loop_eigenvalues: values,
+            residual: frobenius(&residual),
+        })
+    }
+
+    /// Return the stabilizing solution `X` to the discrete algebraic
+    /// Riccati equation `A^H * X * A - X - A^H * X * B * (R + B^H * X
+    /// * B)^-1 * B^H * X * A + Q = 0`, for an (n x n) matrix `A`, an
+    /// (n x m) matrix `B`, a Hermitian (n x n) matrix `Q` and a
+    /// Hermitian (m x m) matrix `R`.
+    ///
+    /// The optimal feedback gain is `K = (R + B^H * X * B)^-1 * B^H *
+    /// X * A`, and all of the closed-loop eigenvalues lie inside the
+    /// unit circle.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MatrixEquationError::NoStabilizingSolution` when the
+    /// symplectic pencil does not have exactly `n` eigenvalues inside
+    /// the unit circle, or the solution cannot be recovered from its
+    /// stable deflating subspace. `MatrixEquationError::Singular` is
+    /// returned when `R` is singular. `A` may be singular.
+    fn dare<D1, D2, D3, D4>(a: &ArrayBase<D1, Ix2>,
+                            b: &ArrayBase<D2, Ix2>,
+                            q: &ArrayBase<D3, Ix2>,
+                            r: &ArrayBase<D4, Ix2>)
+                            -> Result<RiccatiSolution<Self>, MatrixEquationError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>,
+              D3: Data<Elem = Self>,
+              D4: Data<Elem = Self>
+    {
+        let (n, _) = try!(riccati_dim(a, b, q, r));
+        if n == 0 {
+            return Ok(RiccatiSolution {
+                solution: Array::zeros((0, 0)),
+                closed_loop_eigenvalues: Array::default(0),
+                residual: Self::RealPart::zero(),
+            });
+        }
+
+        let a_h = conj_t(a);
+        let g = b.dot(&try!(SolveLinear::compute_multi(r, &conj_t(b))));
+        let zero = Array::zeros((n, n));
+        let eye = Array::eye(n);
+
+        // The symplectic pencil L - lambda * M, with L = [A, 0; -Q,
+        // I], M = [I, G; 0, A^H] and G = B * R^-1 * B^H. The
+        // eigenvalues at zero for a singular `A` pair with infinite
+        // eigenvalues, which have `beta = 0`.
+        let l = block(&a.to_owned(), &zero, &q.mapv(|x| Self::zero() - x), &eye);
+        let m = block(&eye, &g, &zero, &a_h);
+        let mut schur = try!(<Self as GeneralizedSchur>::compute(&l, &m));
+        if try!(schur.reorder(|alpha: Self::Complex, beta: Self::Complex| alpha.mag() < beta.mag())) != n {
+            return Err(MatrixEquationError::NoStabilizingSolution);
+        }
+        let x = try!(stable_subspace_solution(schur.z(), n));
+        let values = schur.alpha().iter().zip(schur.beta().iter())
+            .take(n)
+            .map(|(&alpha, &beta)| alpha / beta)
+            .collect();
+        let values = ArrayBase::from_vec(values);
+
+        let b_h_x = conj_t(b).dot(&x);
+        let s = b_h_x.dot(b) + r;
+        let k = try!(SolveLinear::compute_multi(&s, &b_h_x.dot(a)));
+        let residual = a_h.dot(&x).dot(a) - &x - &a_h.dot(&x).dot(b).dot(&k) + q;
+        Ok(RiccatiSolution {
+            solution: x,
+            closed_loop_eigenvalues: values,
+            residual: frobenius(&residual),
+        })
+    }
+}
+
+macro_rules! impl_matrix_equations {
+    ($impl_type:ident, $sylv_func:ident) => (
+        impl MatrixEquations for $impl_type {
+            fn compute_triangular_sylvester_mut<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                                                        b: &ArrayBase<D2, Ix2>,
+                                                        c: &mut Array<Self, Ix2>,
+                                                        conj_b: bool)
+                                                        -> Result<Self::RealPart, MatrixEquationError>
+                where D1: Data<Elem = Self>,
+                      D2: Data<Elem = Self>
+            {
+                if a.rows() != a.cols() || b.rows() != b.cols() {
+                    return Err(MatrixEquationError::NotSquare);
+                }
+                let (m, n) = c.dim();
+                if a.rows() != m || b.rows() != n {
+                    return Err(MatrixEquationError::InconsistentDimensions);
+                }
+
+                let (a_slice, layout, lda) = match slice_and_layout(a) {
+                    Some(x) => x,
+                    None => return Err(MatrixEquationError::BadLayout),
+                };
+                let (b_slice, b_layout, ldb) = match slice_and_layout(b) {
+                    Some(x) => x,
+                    None => return Err(MatrixEquationError::BadLayout),
+                };
+                if b_layout != layout {
+                    return Err(MatrixEquationError::InconsistentLayout);
+                }
+                let (c_slice, ldc) = match slice_and_layout_matching_mut(c, layout) {
+                    Some(x) => x,
+                    None => return Err(MatrixEquationError::InconsistentLayout),
+                };
+
+                let tranb = if conj_b { b'C' } else { b'N' };
+                let mut scale = [0.0];
+
+                let info = unsafe {
+                    $sylv_func(layout, b'N', tranb, 1, m as i32, n as i32,
+                               a_slice, lda as i32, b_slice, ldb as i32,
+                               c_slice, ldc as i32, &mut scale)
+                };
+
+                if info == 0 {
+                    Ok(scale[0])
+                } else if info < 0 {
+                    Err(MatrixEquationError::IllegalParameter(-info))
+                } else {
+                    // `A` and `-op(B)` have common or very close
+                    // eigenvalues.
+                    Err(MatrixEquationError::Singular)
+                }
+            }
+        }
+    )
+}
+
+impl_matrix_equations!(f32, strsyl);
+impl_matrix_equations!(f64, dtrsyl);
+impl_matrix_equations!(c32, ctrsyl);
+impl_matrix_equations!(c64, ztrsyl);
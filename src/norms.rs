@@ -0,0 +1,282 @@
+//! Compute norms of general, symmetric, and triangular matrices.
+//!
+//! The 1-norm, infinity-norm, Frobenius norm, and max-abs norm are
+//! computed directly by LAPACK. The spectral norm (the largest
+//! singular value) and the nuclear norm (the sum of the singular
+//! values) require a singular value or eigenvalue decomposition, and
+//! are considerably more expensive.
+#![deny(missing_docs)]
+
+use impl_prelude::*;
+use lapack::c::{slange, dlange, clange, zlange, slansy, dlansy, clanhe, zlanhe};
+use lapack::c::{slantr, dlantr, clantr, zlantr};
+use svd::{SVD, SVDComputeVectors, SVDError};
+use eigenvalues::{SymEigen, EigenError};
+use condition::ConditionNorm;
+use util::external::make_triangular;
+use num_traits::Float;
+
+/// Enum for the type of matrix norm to compute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NormType {
+    /// 1-norm (maximum absolute column sum)
+    One,
+
+    /// Infinity-norm (maximum absolute row sum)
+    Infinity,
+
+    /// Frobenius norm (square root of the sum of squares)
+    Frobenius,
+
+    /// Largest absolute value of any element
+    ///
+    /// This is not a consistent matrix norm.
+    MaxAbs,
+
+    /// Spectral norm, or 2-norm (largest singular value)
+    Spectral,
+
+    /// Nuclear norm, or trace norm (sum of the singular values)
+    Nuclear,
+}
+
+impl NormType {
+    /// Return the LAPACK norm descriptor for this type, if LAPACK
+    /// can compute the norm directly.
+    fn lapack_desc(&self) -> Option<u8> {
+        match *self {
+            NormType::One => Some(b'O'),
+            NormType::Infinity => Some(b'I'),
+            NormType::Frobenius => Some(b'F'),
+            NormType::MaxAbs => Some(b'M'),
+            NormType::Spectral | NormType::Nuclear => None,
+        }
+    }
+}
+
+impl From<ConditionNorm> for NormType {
+    fn from(n: ConditionNorm) -> NormType {
+        match n {
+            ConditionNorm::One => NormType::One,
+            ConditionNorm::Infinity => NormType::Infinity,
+        }
+    }
+}
+
+/// Error for norm computations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NormError {
+    /// The layout of the matrix is not compatible
+    BadLayout,
+
+    /// The matrix is not square, but the norm requires a square
+    /// matrix.
+    NotSquare,
+
+    /// The singular value or eigenvalue decomposition failed to
+    /// converge.
+    Unconverged,
+
+    /// The matrix contains NaN values.
+    ContainsNaN,
+
+    /// Implementation error, please submit as bug.
+    IllegalParameter(i32),
+}
+
+impl From<SVDError> for NormError {
+    fn from(e: SVDError) -> NormError {
+        match e {
+            SVDError::BadLayout => NormError::BadLayout,
+            SVDError::Unconverged => NormError::Unconverged,
+            SVDError::IllegalParameter(i) => NormError::IllegalParameter(i),
+        }
+    }
+}
+
+impl From<EigenError> for NormError {
+    fn from(e: EigenError) -> NormError {
+        match e {
+            EigenError::BadLayout |
+            EigenError::InconsistentLayout => NormError::BadLayout,
+            EigenError::NotSquare |
+            EigenError::InconsistentDimensions => NormError::NotSquare,
+            EigenError::Failed => NormError::Unconverged,
+            EigenError::IllegalParameter(i) => NormError::IllegalParameter(i),
+            // Norms only compute all of the eigenvalues of a standard
+            // symmetric problem.
+            EigenError::NotPositiveDefinite |
+            EigenError::InvalidRange => unreachable!(),
+        }
+    }
+}
+
+/// Trait defined on scalars to support computing matrix norms.
+pub trait Norm: SVD + SymEigen {
+    /// Return the norm of a general matrix.
+    fn compute<D>(mat: &ArrayBase<D, Ix2>, norm: NormType) -> Result<Self::RealPart, NormError>
+        where D: Data<Elem = Self>;
+
+    /// Return the norm of a symmetric (or Hermitian) matrix.
+    ///
+    /// Only the portion of the matrix specified by `uplo` is read.
+    fn compute_symmetric<D>(mat: &ArrayBase<D, Ix2>,
+                            uplo: Symmetric,
+                            norm: NormType)
+                            -> Result<Self::RealPart, NormError>
+        where D: Data<Elem = Self>;
+
+    /// Return the norm of a triangular or trapezoidal matrix.
+    ///
+    /// Only the portion of the matrix specified by `uplo` is read.
+    fn compute_triangular<D>(mat: &ArrayBase<D, Ix2>,
+                             uplo: Symmetric,
+                             norm: NormType)
+                             -> Result<Self::RealPart, NormError>
+        where D: Data<Elem = Self>;
+}
+
+/// Return the spectral or nuclear norm of a matrix from its singular
+/// values.
+fn singular_value_norm<T, D>(mat: &ArrayBase<D, Ix2>, norm: NormType)
+                             -> Result<T::RealPart, NormError>
+    where T: SVD,
+          D: Data<Elem = T>
+{
+    let values = try!(SVD::compute(mat, SVDComputeVectors::None)).values;
+    Ok(reduce_norm(values.iter().cloned(), norm))
+}
+
+/// Combine non-negative singular values into the spectral or nuclear
+/// norm.
+fn reduce_norm<F: Float, I: Iterator<Item = F>>(values: I, norm: NormType) -> F {
+    match norm {
+        NormType::Spectral => values.fold(F::zero(), |x, y| x.max(y)),
+        _ => values.fold(F::zero(), |x, y| x + y),
+    }
+}
+
+/// Return the largest absolute value of any element of `mat`, or NaN
+/// if `mat` contains NaN values.
+///
+/// Unlike `Norm::compute` with `NormType::MaxAbs`, this does not call
+/// LAPACK, so it accepts matrices of any layout.
+pub fn max_abs<T, D>(mat: &ArrayBase<D, Ix2>) -> T::RealPart
+    where T: LinxalImplScalar,
+          D: Data<Elem = T>
+{
+    mat.iter().fold(T::RealPart::zero(), |acc, x| {
+        let m = x.mag();
+        if acc.is_nan() || m.is_nan() {
+            T::RealPart::nan()
+        } else if m > acc {
+            m
+        } else {
+            acc
+        }
+    })
+}
+
+/// Check the value returned by a LAPACKE norm function.
+///
+/// The LAPACKE wrappers return a negative value, rather than a norm,
+/// when the input contains NaN values.
+fn check_lapack_norm<F: Float>(value: F) -> Result<F, NormError> {
+    if value < F::zero() {
+        Err(NormError::ContainsNaN)
+    } else {
+        Ok(value)
+    }
+}
+
+macro_rules! impl_norm {
+    ($norm_type:ty, $ge_func:ident, $sy_func:ident, $tr_func:ident) => (
+        impl Norm for $norm_type {
+            fn compute<D>(mat: &ArrayBase<D, Ix2>, norm: NormType) -> Result<Self::RealPart, NormError>
+                where D: Data<Elem = Self> {
+                let dim = mat.dim();
+                if dim.0 == 0 || dim.1 == 0 {
+                    return Ok(0.0);
+                }
+
+                let desc = match norm.lapack_desc() {
+                    Some(x) => x,
+                    None => return singular_value_norm(mat, norm),
+                };
+
+                let (slice, layout, lda) = match slice_and_layout(mat) {
+                    None => return Err(NormError::BadLayout),
+                    Some(x) => x,
+                };
+
+                check_lapack_norm(unsafe {
+                    $ge_func(layout, desc, dim.0 as i32, dim.1 as i32, slice, lda as i32)
+                })
+            }
+
+            fn compute_symmetric<D>(mat: &ArrayBase<D, Ix2>,
+                                    uplo: Symmetric,
+                                    norm: NormType)
+                                    -> Result<Self::RealPart, NormError>
+                where D: Data<Elem = Self> {
+                let dim = mat.dim();
+                if dim.0 != dim.1 {
+                    return Err(NormError::NotSquare);
+                }
+                if dim.0 == 0 {
+                    return Ok(0.0);
+                }
+
+                let desc = match norm.lapack_desc() {
+                    Some(x) => x,
+                    None => {
+                        // The singular values of a symmetric matrix
+                        // are the magnitudes of its eigenvalues.
+                        let values = try!(SymEigen::compute(mat, uplo, false)).values;
+                        return Ok(reduce_norm(values.iter().map(|x| x.abs()), norm));
+                    }
+                };
+
+                let (slice, layout, lda) = match slice_and_layout(mat) {
+                    None => return Err(NormError::BadLayout),
+                    Some(x) => x,
+                };
+
+                check_lapack_norm(unsafe {
+                    $sy_func(layout, desc, uplo as u8, dim.0 as i32, slice, lda as i32)
+                })
+            }
+
+            fn compute_triangular<D>(mat: &ArrayBase<D, Ix2>,
+                                     uplo: Symmetric,
+                                     norm: NormType)
+                                     -> Result<Self::RealPart, NormError>
+                where D: Data<Elem = Self> {
+                let dim = mat.dim();
+                if dim.0 == 0 || dim.1 == 0 {
+                    return Ok(0.0);
+                }
+
+                let desc = match norm.lapack_desc() {
+                    Some(x) => x,
+                    None => return singular_value_norm(&make_triangular(mat.view(), uplo), norm),
+                };
+
+                let (slice, layout, lda) = match slice_and_layout(mat) {
+                    None => return Err(NormError::BadLayout),
+                    Some(x) => x,
+                };
+
+                check_lapack_norm(unsafe {
+                    $tr_func(layout, desc, uplo as u8, b'N', dim.0 as i32, dim.1 as i32,
+                             slice, lda as i32)
+                })
+            }
+        }
+    )
+}
+
+impl_norm!(f32, slange, slansy, slantr);
+impl_norm!(f64, dlange, dlansy, dlantr);
+impl_norm!(c32, clange, clanhe, clantr);
+impl_norm!(c64, zlange, zlanhe, zlantr);
@@ -36,6 +36,13 @@ impl MatrixPermutation {
     pub fn ipiv(&self) -> &[i32] {
         &self.ipiv
     }
+
+    /// Return the number of row interchanges in the permutation.
+    ///
+    /// The permutation is odd exactly when this number is odd.
+    pub fn num_swaps(&self) -> usize {
+        self.ipiv.iter().enumerate().filter(|&(i, &p)| p != i as i32 + 1).count()
+    }
 }
 
 /// A `Permutes` is a scalar that one can apply a
@@ -3,12 +3,24 @@
 pub use svd::general::SVD;
 pub use svd::types::{SVDError, SVDSolution};
 pub use eigenvalues::general::Eigen;
-pub use eigenvalues::types::EigenError;
-pub use eigenvalues::symmetric::SymEigen;
-pub use types::{LinxalScalar, LinxalImplScalar, LinxalMatrix, LinxalMatrixInto, Symmetric, Error, c32, c64};
+pub use eigenvalues::generalized::GeneralizedEigen;
+pub use eigenvalues::schur::Schur;
+pub use eigenvalues::generalized_schur::GeneralizedSchur;
+pub use eigenvalues::tridiagonal::TridiagonalEigen;
+pub use eigenvalues::types::{EigenError, Balance};
+pub use eigenvalues::symmetric::{SymEigen, SymEigenMethod, EigenRange};
+pub use eigenvalues::symmetric_generalized::{SymGeneralizedEigen, GeneralizedProblem};
+pub use types::{LinxalScalar, LinxalImplScalar, LinxalMatrix, LinxalMatrixInto, Symmetric, Transpose, Side, Error, c32, c64};
 pub use solve_linear::general::SolveLinear;
 pub use solve_linear::symmetric::SymmetricSolveLinear;
+pub use solve_linear::expert::ExpertSolveLinear;
+pub use solve_linear::triangular::TriangularSolveLinear;
+pub use solve_linear::types::ExpertSolution;
 pub use least_squares::LeastSquares;
-pub use factorization::{Cholesky, QR, LU, QRError, LUError, CholeskyError};
+pub use factorization::{Cholesky, QR, LQ, RQ, QL, LU, LDL, Hessenberg, Tridiagonal,
+                        QRError, LUError, CholeskyError, LDLError, ReductionError};
+pub use condition::{Condition, ConditionNorm, ConditionError};
+pub use norms::{Norm, NormType, NormError};
+pub use matrix_equations::{MatrixEquations, MatrixEquationError, RiccatiSolution};
 
 pub use util::external::*;
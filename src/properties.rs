@@ -8,7 +8,7 @@
 
 use impl_prelude::*;
 use util::conj_t;
-use num_traits::{Float};
+use norms::max_abs;
 
 /// Return true iff the matrix is square.
 pub fn is_square_size(d: &Ix2) -> bool {
@@ -20,9 +20,7 @@ pub fn default_tol<T, D>(mat: &ArrayBase<D, Ix2>)
                               -> T::RealPart
     where T: LinxalImplScalar,
           D: Data<Elem=T> {
-    T::tol() * mat.iter().map(|x| x.mag())
-        .fold(T::RealPart::neg_infinity(), |x, y| if x > y { x } else { y })
-
+    T::tol() * max_abs(mat)
 }
 
 /// Returns true iff the matrix is diagonal.
@@ -0,0 +1,174 @@
+use impl_prelude::*;
+use lapack::c::{sgesvx, dgesvx, cgesvx, zgesvx};
+use super::types::{SolveError, ExpertSolution};
+
+/// Implements `compute_*` methods to solve systems of linear
+/// equations A*X = B, with equilibration, iterative refinement, and
+/// error bounds.
+///
+/// The system is first equilibrated by row and/or column scaling
+/// when `A` is poorly scaled. After solving the equilibrated system,
+/// the solution is iteratively refined, and error bounds are
+/// computed for each right-hand side. See `ExpertSolution` for the
+/// returned quantities.
+pub trait ExpertSolveLinear: LinxalImplScalar {
+    /// Solve the linear system A * x = B for square matrix `a` and
+    /// rectangular matrix `b`.
+    ///
+    /// Each column of `b` is a RHS vector to be solved for. Both
+    /// inputs are overwritten with their equilibrated values.
+    fn compute_multi_into<D1, D2>(a: ArrayBase<D1, Ix2>,
+                                  b: ArrayBase<D2, Ix2>)
+                                  -> Result<ExpertSolution<Self, Ix2>, SolveError>
+        where D1: DataMut<Elem = Self> + DataOwned<Elem = Self>,
+              D2: DataMut<Elem = Self> + DataOwned<Elem = Self>;
+
+    /// Solve the linear system A * x = b for square matrix `a` and
+    /// column vector `b`.
+    fn compute_into<D1, D2>(a: ArrayBase<D1, Ix2>,
+                            b: ArrayBase<D2, Ix1>)
+                            -> Result<ExpertSolution<Self, Ix1>, SolveError>
+        where D1: DataMut<Elem = Self> + DataOwned<Elem = Self>,
+              D2: DataMut<Elem = Self> + DataOwned<Elem = Self>
+    {
+        let n = b.dim();
+
+        // Create a new matrix, where the column vector is a degenerate 2-D matrix.
+        let b_mat = match b.into_shape((n, 1)) {
+            Ok(x) => x,
+            Err(_) => return Err(SolveError::BadLayout),
+        };
+
+        // Call the original
+        let res = try!(Self::compute_multi_into(a, b_mat));
+
+        // Reshape the solution into a vector and return.
+        Ok(ExpertSolution {
+            solution: res.solution.into_shape(n).unwrap(),
+            row_scale: res.row_scale,
+            col_scale: res.col_scale,
+            rcond: res.rcond,
+            forward_error: res.forward_error,
+            backward_error: res.backward_error,
+            pivot_growth: res.pivot_growth,
+            singular_to_working_precision: res.singular_to_working_precision,
+        })
+    }
+
+    /// Solve the linear system A * x = B for square matrix `a` and
+    /// rectangular matrix `b`.
+    fn compute_multi<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                             b: &ArrayBase<D2, Ix2>)
+                             -> Result<ExpertSolution<Self, Ix2>, SolveError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>
+    {
+        let a_copy = a.to_owned();
+        let b_copy = b.to_owned();
+        Self::compute_multi_into(a_copy, b_copy)
+    }
+
+    /// Solve the linear system A * x = b for square matrix `a` and
+    /// column vector `b`.
+    fn compute<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                       b: &ArrayBase<D2, Ix1>)
+                       -> Result<ExpertSolution<Self, Ix1>, SolveError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>
+    {
+        let a_copy = a.to_owned();
+        let b_copy = b.to_owned();
+        Self::compute_into(a_copy, b_copy)
+    }
+}
+
+macro_rules! impl_solve_linear_expert {
+    ($impl_type:ty, $sv_type:ty, $driver:ident) => (
+        impl ExpertSolveLinear for $impl_type {
+            fn compute_multi_into<D1, D2>(mut a: ArrayBase<D1, Ix2>, mut b: ArrayBase<D2, Ix2>)
+                                          -> Result<ExpertSolution<Self, Ix2>, SolveError>
+                where D1: DataMut<Elem=Self> + DataOwned<Elem = Self>,
+                      D2: DataMut<Elem=Self> + DataOwned<Elem = Self> {
+
+                // Make sure the input is square.
+                let dim = a.dim();
+                let b_dim = b.dim();
+
+                if dim.0 != dim.1 {
+                    return Err(SolveError::NotSquare(dim.0, dim.1));
+                }
+                if dim.0 != b_dim.0 {
+                    return Err(SolveError::InconsistentDimensions(dim.0, b_dim.0));
+                }
+
+                let n = dim.0;
+
+                let (slice, layout, lda) = match slice_and_layout_mut(&mut a) {
+                    Some(x) => x,
+                    None => return Err(SolveError::BadLayout)
+                };
+
+                // The factors of the equilibrated matrix and the
+                // solution are created to match the input layout.
+                let mut af = matrix_with_layout(dim, layout);
+                let mut x = matrix_with_layout(b_dim, layout);
+
+                let mut perm = vec![0; n];
+                let mut equed = b'N';
+                let mut r: Array<$sv_type, Ix1> = Array::zeros(n);
+                let mut c: Array<$sv_type, Ix1> = Array::zeros(n);
+                let mut rcond = 0.0;
+                let mut ferr: Array<$sv_type, Ix1> = Array::zeros(b_dim.1);
+                let mut berr: Array<$sv_type, Ix1> = Array::zeros(b_dim.1);
+                let mut rpivot = [0.0];
+
+                let info = {
+                    let (b_slice, ldb) = match slice_and_layout_matching_mut(&mut b, layout) {
+                        Some(x) => x,
+                        None => return Err(SolveError::InconsistentLayout)
+                    };
+                    let (af_slice, _, ldaf) = slice_and_layout_mut(&mut af)
+                        .expect("af is created with a valid layout");
+                    let (x_slice, ldx) = slice_and_layout_matching_mut(&mut x, layout)
+                        .expect("x is created with a matching layout");
+
+                    unsafe {
+                        $driver(layout, b'E', b'N', n as i32, b_dim.1 as i32,
+                                slice, lda as i32,
+                                af_slice, ldaf as i32,
+                                &mut perm, &mut equed,
+                                r.as_slice_mut().unwrap(), c.as_slice_mut().unwrap(),
+                                b_slice, ldb as i32,
+                                x_slice, ldx as i32,
+                                &mut rcond,
+                                ferr.as_slice_mut().unwrap(), berr.as_slice_mut().unwrap(),
+                                &mut rpivot)
+                    }
+                };
+
+                // `info == n + 1` indicates that the solution was
+                // computed, but `a` is singular to working precision.
+                if info == 0 || info == n as i32 + 1 {
+                    Ok(ExpertSolution {
+                        solution: x,
+                        row_scale: if equed == b'R' || equed == b'B' { Some(r) } else { None },
+                        col_scale: if equed == b'C' || equed == b'B' { Some(c) } else { None },
+                        rcond: rcond,
+                        forward_error: ferr,
+                        backward_error: berr,
+                        pivot_growth: rpivot[0],
+                        singular_to_working_precision: info != 0,
+                    })
+                } else if info < 0 {
+                    Err(SolveError::IllegalValue(-info))
+                } else {
+                    Err(SolveError::Singular(info))
+                }
+            }
+        })
+}
+
+impl_solve_linear_expert!(f32, f32, sgesvx);
+impl_solve_linear_expert!(f64, f64, dgesvx);
+impl_solve_linear_expert!(c32, f32, cgesvx);
+impl_solve_linear_expert!(c64, f64, zgesvx);
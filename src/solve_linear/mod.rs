@@ -3,11 +3,23 @@
 //! The primary content of this modules is the `SolveLinear` trait,
 //! which computes solutions `X` to the linear system A*X = B, for
 //! square matrices A.
+//!
+//! The `ExpertSolveLinear` trait additionally equilibrates the
+//! system, iteratively refines the solution, and returns error
+//! bounds for it.
+//!
+//! The `TriangularSolveLinear` trait solves triangular systems
+//! directly, such as those arising from the factors of a
+//! factorization.
 
 pub mod types;
 pub mod general;
 pub mod symmetric;
+pub mod expert;
+pub mod triangular;
 
-pub use self::types::SolveError;
+pub use self::types::{SolveError, ExpertSolution};
 pub use self::general::SolveLinear;
 pub use self::symmetric::SymmetricSolveLinear;
+pub use self::expert::ExpertSolveLinear;
+pub use self::triangular::TriangularSolveLinear;
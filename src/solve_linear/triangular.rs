@@ -0,0 +1,135 @@
+use lapack::c::{strtrs, dtrtrs, ctrtrs, ztrtrs};
+use super::types::SolveError;
+use impl_prelude::*;
+
+/// Implements `compute_*` methods to solve systems of linear
+/// equations A*X = B, op(A)*X = B where A is upper or lower
+/// triangular and op(A) is A, its transpose, or its conjugate
+/// transpose.
+///
+/// Only the triangular portion of `a` specified by `uplo` is read,
+/// so the factors stored in the output of factorizations can be used
+/// directly.
+pub trait TriangularSolveLinear: LinxalImplScalar {
+    /// Solve the linear system op(A) * x = B for triangular square
+    /// matrix `a` and rectangular matrix `b`.
+    ///
+    /// The layout of `b` must match the layout of `a`, unless `b` is
+    /// a single column.
+    fn compute_multi_into<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                                  uplo: Symmetric,
+                                  trans: Transpose,
+                                  b: ArrayBase<D2, Ix2>)
+                                  -> Result<ArrayBase<D2, Ix2>, SolveError>
+        where D1: Data<Elem = Self>,
+              D2: DataMut<Elem = Self> + DataOwned<Elem = Self>;
+
+    /// Solve the linear system op(A) * x = b for triangular square
+    /// matrix `a` and column vector `b`.
+    fn compute_into<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                            uplo: Symmetric,
+                            trans: Transpose,
+                            b: ArrayBase<D2, Ix1>)
+                            -> Result<ArrayBase<D2, Ix1>, SolveError>
+        where D1: Data<Elem = Self>,
+              D2: DataMut<Elem = Self> + DataOwned<Elem = Self>
+    {
+        let n = b.dim();
+
+        // Create a new matrix, where the column vector is a degenerate 2-D matrix.
+        let b_mat = match b.into_shape((n, 1)) {
+            Ok(x) => x,
+            Err(_) => return Err(SolveError::BadLayout),
+        };
+
+        // Call the original
+        let res = try!(Self::compute_multi_into(a, uplo, trans, b_mat));
+
+        // Reshape the matrix into a vector and return.
+        Ok(res.into_shape(n).unwrap())
+    }
+
+    /// Solve the linear system op(A) * x = B for triangular square
+    /// matrix `a` and rectangular matrix `b`.
+    fn compute_multi<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                             uplo: Symmetric,
+                             trans: Transpose,
+                             b: &ArrayBase<D2, Ix2>)
+                             -> Result<Array<Self, Ix2>, SolveError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>
+    {
+        let b_copy = copy_with_matching_layout(b, a);
+        Self::compute_multi_into(a, uplo, trans, b_copy)
+    }
+
+    /// Solve the linear system op(A) * x = b for triangular square
+    /// matrix `a` and column vector `b`.
+    fn compute<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                       uplo: Symmetric,
+                       trans: Transpose,
+                       b: &ArrayBase<D2, Ix1>)
+                       -> Result<Array<Self, Ix1>, SolveError>
+        where D1: Data<Elem = Self>,
+              D2: Data<Elem = Self>
+    {
+        let b_copy = b.to_owned();
+        Self::compute_into(a, uplo, trans, b_copy)
+    }
+}
+
+macro_rules! impl_solve_triangular {
+    ($impl_type: ty, $driver: ident) => (
+        impl TriangularSolveLinear for $impl_type {
+            fn compute_multi_into<D1, D2>(a: &ArrayBase<D1, Ix2>,
+                                          uplo: Symmetric,
+                                          trans: Transpose,
+                                          mut b: ArrayBase<D2, Ix2>)
+                                          -> Result<ArrayBase<D2, Ix2>, SolveError>
+                where D1: Data<Elem = Self>,
+                      D2: DataMut<Elem = Self> + DataOwned<Elem = Self> {
+
+                // Make sure the input is square.
+                let dim = a.dim();
+                let b_dim = b.dim();
+
+                if dim.0 != dim.1 {
+                    return Err(SolveError::NotSquare(dim.0, dim.1));
+                }
+                if dim.0 != b_dim.0 {
+                    return Err(SolveError::InconsistentDimensions(dim.0, b_dim.0));
+                }
+
+                let info = {
+                    let (slice, layout, lda) = match slice_and_layout(a) {
+                        Some(x) => x,
+                        None => return Err(SolveError::BadLayout)
+                    };
+
+                    let (b_slice, ldb) = match slice_and_layout_matching_mut(&mut b, layout) {
+                        Some(x) => x,
+                        None => return Err(SolveError::InconsistentLayout)
+                    };
+
+                    unsafe {
+                        $driver(layout, uplo as u8, trans as u8, b'N',
+                                dim.0 as i32, b_dim.1 as i32,
+                                slice, lda as i32, b_slice, ldb as i32)
+                    }
+                };
+
+                if info == 0 {
+                    Ok(b)
+                } else if info < 0 {
+                    Err(SolveError::IllegalValue(-info))
+                } else {
+                    Err(SolveError::Singular(info))
+                }
+            }
+        })
+}
+
+impl_solve_triangular!(f32, strtrs);
+impl_solve_triangular!(f64, dtrtrs);
+impl_solve_triangular!(c32, ctrtrs);
+impl_solve_triangular!(c64, ztrtrs);
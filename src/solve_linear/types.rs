@@ -1,3 +1,5 @@
+use impl_prelude::*;
+
 /// Error enum returns by various `SolveLinear`-esque compute methods.
 #[derive(Debug, Clone)]
 pub enum SolveError {
@@ -23,3 +25,48 @@ pub enum SolveError {
     /// The dimensions of `a` and `b` do not match.
     InconsistentDimensions(usize, usize),
 }
+
+/// Solution to a system of linear equations from an
+/// `ExpertSolveLinear` method.
+///
+/// In addition to the solution, contains the scaling applied to
+/// equilibrate the system and estimates of the error in the
+/// solution. The error bounds are per right-hand side, so they are
+/// vectors even when solving for a single column vector.
+#[derive(Debug)]
+pub struct ExpertSolution<T: LinxalImplScalar, D: Dimension> {
+    /// Solution `X` to the original (unscaled) system.
+    pub solution: Array<T, D>,
+
+    /// Row scale factors applied to `A`, if the rows were scaled.
+    pub row_scale: Option<Array<T::RealPart, Ix1>>,
+
+    /// Column scale factors applied to `A`, if the columns were
+    /// scaled.
+    pub col_scale: Option<Array<T::RealPart, Ix1>>,
+
+    /// Estimate of the reciprocal condition number of the
+    /// (equilibrated) matrix `A`, in the 1-norm.
+    pub rcond: T::RealPart,
+
+    /// Estimated bound on the relative forward error of each
+    /// solution vector.
+    pub forward_error: Array<T::RealPart, Ix1>,
+
+    /// Componentwise relative backward error of each solution
+    /// vector.
+    pub backward_error: Array<T::RealPart, Ix1>,
+
+    /// Reciprocal pivot growth factor of the factorization.
+    ///
+    /// Values much less than one indicate that the factorization,
+    /// and therefore the solution and error bounds, may be unstable.
+    pub pivot_growth: T::RealPart,
+
+    /// True iff `rcond` is less than the machine precision, so that
+    /// `A` is singular to working precision.
+    ///
+    /// The solution and error bounds are still computed, but should
+    /// be used with care.
+    pub singular_to_working_precision: bool,
+}
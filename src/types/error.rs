@@ -7,6 +7,11 @@ pub use generate::GenerateError;
 pub use factorization::qr::QRError;
 pub use factorization::lu::LUError;
 pub use factorization::cholesky::CholeskyError;
+pub use factorization::ldl::LDLError;
+pub use factorization::hessenberg::ReductionError;
+pub use condition::ConditionError;
+pub use norms::NormError;
+pub use matrix_equations::MatrixEquationError;
 
 /// Universal `linxal` error enum
 ///
@@ -35,8 +40,23 @@ pub enum Error {
     /// Error from computing a Cholesky decomposition
     Cholesky(CholeskyError),
 
+    /// Error from computing a symmetric-indefinite (LDL) decomposition
+    LDL(LDLError),
+
+    /// Error from computing a Hessenberg or tridiagonal reduction
+    Reduction(ReductionError),
+
     /// Error from attempting to generate a matrix
     Generate(GenerateError),
+
+    /// Error from estimating a condition number
+    Condition(ConditionError),
+
+    /// Error from computing a matrix norm
+    Norm(NormError),
+
+    /// Error from solving a matrix equation
+    MatrixEquation(MatrixEquationError),
 }
 
 impl From<SVDError> for Error {
@@ -81,8 +101,38 @@ impl From<CholeskyError> for Error {
     }
 }
 
+impl From<LDLError> for Error {
+    fn from(e: LDLError) -> Error {
+        Error::LDL(e)
+    }
+}
+
+impl From<ReductionError> for Error {
+    fn from(e: ReductionError) -> Error {
+        Error::Reduction(e)
+    }
+}
+
 impl From<GenerateError> for Error {
     fn from(e: GenerateError) -> Error {
         Error::Generate(e)
     }
 }
+
+impl From<ConditionError> for Error {
+    fn from(e: ConditionError) -> Error {
+        Error::Condition(e)
+    }
+}
+
+impl From<NormError> for Error {
+    fn from(e: NormError) -> Error {
+        Error::Norm(e)
+    }
+}
+
+impl From<MatrixEquationError> for Error {
+    fn from(e: MatrixEquationError) -> Error {
+        Error::MatrixEquation(e)
+    }
+}
@@ -34,6 +34,9 @@ pub trait LinxalImplScalar
     /// Returns the magnitude of the scalar.
     fn mag(self) -> Self::RealPart;
 
+    /// Return the real part of the scalar.
+    fn re(self) -> Self::RealPart;
+
     /// Return the machine epsilon for the value.
     fn eps() -> Self::RealPart;
 
@@ -57,6 +60,9 @@ impl LinxalImplScalar for f32 {
     fn mag(self) -> Self::RealPart {
         self.abs()
     }
+    fn re(self) -> Self::RealPart {
+        self
+    }
     fn tol() -> Self::RealPart {
         1e-5
     }
@@ -79,6 +85,9 @@ impl LinxalImplScalar for f64 {
     fn mag(self) -> Self::RealPart {
         self.abs()
     }
+    fn re(self) -> Self::RealPart {
+        self
+    }
     fn tol() -> Self::RealPart {
         2e-14
     }
@@ -101,6 +110,9 @@ impl LinxalImplScalar for c32 {
     fn mag(self) -> Self::RealPart {
         self.norm()
     }
+    fn re(self) -> Self::RealPart {
+        self.re
+    }
     fn tol() -> Self::RealPart {
         2e-5
     }
@@ -123,6 +135,9 @@ impl LinxalImplScalar for c64 {
     fn mag(self) -> Self::RealPart {
         self.norm()
     }
+    fn re(self) -> Self::RealPart {
+        self.re
+    }
     fn tol() -> Self::RealPart {
         4e-14
     }
@@ -1,14 +1,21 @@
 //! Define matrix traits for performing linear algebra operations.
 
-use eigenvalues::{self, Eigen, SymEigen};
-use solve_linear::{SolveLinear, SymmetricSolveLinear};
+use eigenvalues::{self, Eigen, Schur, SchurFactors, GeneralizedEigen, SymEigen, SymGeneralizedEigen, GeneralizedProblem, EigenRange, SymEigenMethod,
+                  Balance, ExpertEigenSolution};
+use solve_linear::{SolveLinear, SymmetricSolveLinear, ExpertSolveLinear, ExpertSolution};
 use least_squares::{LeastSquares, LeastSquaresType, LeastSquaresSolution};
 use super::error::*;
 use super::scalar::LinxalScalar;
 use impl_prelude::*;
-use factorization::{QR, QRFactors, LU, LUFactors, Cholesky};
+use factorization::{QR, QRFactors, QRPFactors, LQ, LQFactors, RQ, RQFactors, QL, QLFactors};
+use factorization::{LU, LUFactors, Cholesky, CholeskyFactors, CholeskyPFactors, LDL, LDLFactors};
+use factorization::{Hessenberg, HessenbergFactors, Tridiagonal, TridiagonalFactors};
 use svd::{SVD, SVDSolution, SVDComputeVectors};
 use properties::{self, default_tol};
+use condition::ConditionNorm;
+use norms::{Norm, NormType};
+use matrix_equations::{MatrixEquations, RiccatiSolution};
+use num_traits::{Float, One};
 
 /// All-encompassing matrix trait, supporting all of the linear
 /// algebra operations defined for any `LinxalScalar`.
@@ -22,6 +29,34 @@ pub trait LinxalMatrix<F: LinxalScalar> {
                            compute_right: bool)
                            -> Result<eigenvalues::Solution<F, F::Complex>, EigenError>;
 
+    /// Compute the eigenvalues, eigenvectors and their condition
+    /// numbers of a matrix, after balancing it.
+    ///
+    /// See [Eigen::compute_expert]().
+    fn eigenvalues_expert(&self,
+                          balance: Balance,
+                          compute_left: bool,
+                          compute_right: bool)
+                          -> Result<ExpertEigenSolution<F>, EigenError>;
+
+    /// Compute the eigenvalues of the generalized eigenvalue problem
+    /// `A x = lambda B x`, as `(alpha, beta)` pairs.
+    fn generalized_eigenvalues<D1: Data<Elem = F>>(&self, b: &ArrayBase<D1, Ix2>)
+                                                   -> Result<eigenvalues::GeneralizedSolution<F, F::Complex>, EigenError>;
+
+    /// Compute the eigenvalues and eigenvectors of the generalized
+    /// eigenvalue problem `A x = lambda B x`.
+    fn generalized_eigenvalues_vectors<D1: Data<Elem = F>>(&self,
+                                                           b: &ArrayBase<D1, Ix2>,
+                                                           compute_left: bool,
+                                                           compute_right: bool)
+                                                           -> Result<eigenvalues::GeneralizedSolution<F, F::Complex>, EigenError>;
+
+    /// Compute the Schur decomposition of a square matrix.
+    ///
+    /// See [Schur::compute]().
+    fn schur(&self) -> Result<SchurFactors<F>, EigenError>;
+
     /// Compute the eigenvalues of a symmetric matrix.
     fn symmetric_eigenvalues(&self, uplo: Symmetric)
                              -> Result<Array<F::RealPart, Ix1>, EigenError>;
@@ -31,6 +66,43 @@ pub trait LinxalMatrix<F: LinxalScalar> {
     fn symmetric_eigenvalues_vectors(&self, uplo: Symmetric)
                                      -> Result<eigenvalues::Solution<F, F::RealPart>, EigenError>;
 
+    /// Compute the eigenvalues and eigenvectors of a symmetric
+    /// matrix, using the driver specified by `method`.
+    ///
+    /// See [SymEigen::compute_with_method]().
+    fn symmetric_eigenvalues_vectors_with_method(&self, uplo: Symmetric, method: SymEigenMethod)
+                                                 -> Result<eigenvalues::Solution<F, F::RealPart>, EigenError>;
+
+    /// Compute the selected eigenvalues of a symmetric matrix.
+    ///
+    /// See [SymEigen::compute_range]().
+    fn symmetric_eigenvalues_range(&self, uplo: Symmetric, range: EigenRange<F::RealPart>)
+                                   -> Result<Array<F::RealPart, Ix1>, EigenError>;
+
+    /// Compute the selected eigenvalues and corresponding
+    /// eigenvectors of a symmetric matrix.
+    ///
+    /// See [SymEigen::compute_range]().
+    fn symmetric_eigenvalues_vectors_range(&self, uplo: Symmetric, range: EigenRange<F::RealPart>)
+                                           -> Result<eigenvalues::Solution<F, F::RealPart>, EigenError>;
+
+    /// Compute the eigenvalues of a symmetric-definite generalized
+    /// eigenvalue problem, where this matrix is `A` and `b` is
+    /// positive definite.
+    fn symmetric_generalized_eigenvalues<D1: Data<Elem = F>>(&self,
+                                                             b: &ArrayBase<D1, Ix2>,
+                                                             uplo: Symmetric,
+                                                             problem: GeneralizedProblem)
+                                                             -> Result<Array<F::RealPart, Ix1>, EigenError>;
+
+    /// Compute the eigenvalues and eigenvectors of a
+    /// symmetric-definite generalized eigenvalue problem.
+    fn symmetric_generalized_eigenvalues_vectors<D1: Data<Elem = F>>(&self,
+                                                                     b: &ArrayBase<D1, Ix2>,
+                                                                     uplo: Symmetric,
+                                                                     problem: GeneralizedProblem)
+                                                                     -> Result<eigenvalues::Solution<F, F::RealPart>, EigenError>;
+
     /// Solve a single system of linear equations.
     fn solve_linear<D1: Data<Elem = F>>(&self,
                                         b: &ArrayBase<D1, Ix1>)
@@ -57,6 +129,22 @@ pub trait LinxalMatrix<F: LinxalScalar> {
                                                         uplo: Symmetric)
                                                         -> Result<Array<F, Ix2>, SolveError>;
 
+    /// Solve a single system of linear equations, with
+    /// equilibration, iterative refinement, and error bounds.
+    ///
+    /// See [ExpertSolveLinear]().
+    fn solve_linear_expert<D1: Data<Elem = F>>(&self,
+                                               b: &ArrayBase<D1, Ix1>)
+                                               -> Result<ExpertSolution<F, Ix1>, SolveError>;
+
+    /// Solve a system of linear equations with multiple RHS vectors,
+    /// with equilibration, iterative refinement, and error bounds.
+    ///
+    /// See [ExpertSolveLinear]().
+    fn solve_multi_linear_expert<D1: Data<Elem = F>>(&self,
+                                                     b: &ArrayBase<D1, Ix2>)
+                                                     -> Result<ExpertSolution<F, Ix2>, SolveError>;
+
     /// Compute the least squares solution for a single RHS.
     fn least_squares<D1, PT>(&self,
                              b: &ArrayBase<D1, Ix1>,
@@ -78,6 +166,26 @@ pub trait LinxalMatrix<F: LinxalScalar> {
     /// See [QR::compute]().
     fn qr(&self) -> Result<QRFactors<F>, QRError>;
 
+    /// Return the column-pivoted QR factorization of the matrix.
+    ///
+    /// See [QR::compute_pivoted]().
+    fn qr_pivoted(&self) -> Result<QRPFactors<F>, QRError>;
+
+    /// Return the LQ factorization of the matrix.
+    ///
+    /// See [LQ::compute]().
+    fn lq(&self) -> Result<LQFactors<F>, QRError>;
+
+    /// Return the RQ factorization of the matrix.
+    ///
+    /// See [RQ::compute]().
+    fn rq(&self) -> Result<RQFactors<F>, QRError>;
+
+    /// Return the QL factorization of the matrix.
+    ///
+    /// See [QL::compute]().
+    fn ql(&self) -> Result<QLFactors<F>, QRError>;
+
     /// Return the LU factorization of the matrix.
     ///
     /// See [LU::compute]()
@@ -87,6 +195,131 @@ pub trait LinxalMatrix<F: LinxalScalar> {
     /// upper- or lower-triangular matrix defining it.
     fn cholesky(&self, uplo: Symmetric) -> Result<Array<F, Ix2>, CholeskyError>;
 
+    /// Return the cholesky factorization of the matrix as a
+    /// `CholeskyFactors`, which can be reused to solve systems and
+    /// invert the matrix.
+    ///
+    /// See [Cholesky::compute_factors]().
+    fn cholesky_factors(&self, uplo: Symmetric) -> Result<CholeskyFactors<F>, CholeskyError>;
+
+    /// Return the pivoted cholesky factorization of the positive
+    /// semi-definite matrix.
+    ///
+    /// See [Cholesky::compute_pivoted]().
+    fn cholesky_pivoted<Tol>(&self, uplo: Symmetric, tolerance: Tol)
+                             -> Result<CholeskyPFactors<F>, CholeskyError>
+        where Tol: Into<Option<F::RealPart>>;
+
+    /// Return the symmetric-indefinite factorization of the symmetric
+    /// (or Hermitian) matrix as a `LDLFactors`, which can be reused to
+    /// solve systems, invert the matrix, or compute its inertia.
+    ///
+    /// See [LDL::compute]().
+    fn ldl(&self, uplo: Symmetric) -> Result<LDLFactors<F>, LDLError>;
+
+    /// Return the reduction of the square matrix to upper Hessenberg
+    /// form, which can be reused to solve shifted systems.
+    ///
+    /// See [Hessenberg::compute]().
+    fn hessenberg(&self) -> Result<HessenbergFactors<F>, ReductionError>;
+
+    /// Return the reduction of the symmetric (or Hermitian) matrix to
+    /// real symmetric tridiagonal form.
+    ///
+    /// See [Tridiagonal::compute]().
+    fn tridiagonal(&self, uplo: Symmetric) -> Result<TridiagonalFactors<F>, ReductionError>;
+
+    /// Return the solution `X` to the Sylvester equation `A * X + X *
+    /// B = C`, where `A` is this matrix.
+    ///
+    /// See [MatrixEquations::sylvester]().
+    fn sylvester<D1, D2>(&self, b: &ArrayBase<D1, Ix2>, c: &ArrayBase<D2, Ix2>)
+                         -> Result<Array<F, Ix2>, MatrixEquationError>
+        where D1: Data<Elem = F>,
+              D2: Data<Elem = F>;
+
+    /// Return the solution `X` to the continuous Lyapunov equation `A
+    /// * X + X * A^H + Q = 0`, where `A` is this matrix.
+    ///
+    /// See [MatrixEquations::lyapunov]().
+    fn lyapunov<D1: Data<Elem = F>>(&self, q: &ArrayBase<D1, Ix2>)
+                                    -> Result<Array<F, Ix2>, MatrixEquationError>;
+
+    /// Return the solution `X` to the discrete Lyapunov equation `A *
+    /// X * A^H - X + Q = 0`, where `A` is this matrix.
+    ///
+    /// See [MatrixEquations::discrete_lyapunov]().
+    fn discrete_lyapunov<D1: Data<Elem = F>>(&self, q: &ArrayBase<D1, Ix2>)
+                                             -> Result<Array<F, Ix2>, MatrixEquationError>;
+
+    /// Return the stabilizing solution to the continuous algebraic
+    /// Riccati equation for the system `(A, B)` with weights `Q` and
+    /// `R`, where `A` is this matrix.
+    ///
+    /// See [MatrixEquations::care]().
+    fn care<D1, D2, D3>(&self, b: &ArrayBase<D1, Ix2>, q: &ArrayBase<D2, Ix2>, r: &ArrayBase<D3, Ix2>)
+                        -> Result<RiccatiSolution<F>, MatrixEquationError>
+        where D1: Data<Elem = F>,
+              D2: Data<Elem = F>,
+              D3: Data<Elem = F>;
+
+    /// Return the stabilizing solution to the discrete algebraic
+    /// Riccati equation for the system `(A, B)` with weights `Q` and
+    /// `R`, where `A` is this matrix.
+    ///
+    /// See [MatrixEquations::dare]().
+    fn dare<D1, D2, D3>(&self, b: &ArrayBase<D1, Ix2>, q: &ArrayBase<D2, Ix2>, r: &ArrayBase<D3, Ix2>)
+                        -> Result<RiccatiSolution<F>, MatrixEquationError>
+        where D1: Data<Elem = F>,
+              D2: Data<Elem = F>,
+              D3: Data<Elem = F>;
+
+    /// Return the norm of the matrix.
+    ///
+    /// See [NormType]() for the available norms.
+    fn norm(&self, norm: NormType) -> Result<F::RealPart, NormError>;
+
+    /// Return the norm of a symmetric (or Hermitian) matrix, reading
+    /// only the portion specified by `uplo`.
+    fn symmetric_norm(&self, uplo: Symmetric, norm: NormType) -> Result<F::RealPart, NormError>;
+
+    /// Return the norm of a triangular or trapezoidal matrix,
+    /// reading only the portion specified by `uplo`.
+    fn triangular_norm(&self, uplo: Symmetric, norm: NormType) -> Result<F::RealPart, NormError>;
+
+    /// Return an estimate of the condition number of a square
+    /// matrix, in the 1-norm or infinity-norm.
+    ///
+    /// The estimate is computed from the LU factorization of the
+    /// matrix. Singular matrices have an infinite condition number.
+    fn condition_number(&self, norm: ConditionNorm) -> Result<F::RealPart, LUError>;
+
+    /// Return the determinant of a square matrix.
+    ///
+    /// The determinant is computed from the LU factorization of the
+    /// matrix. Singular matrices have a determinant of zero.
+    fn det(&self) -> Result<F, LUError>;
+
+    /// Return the sign and the natural log of the magnitude of the
+    /// determinant of a square matrix.
+    ///
+    /// See [LUFactors::slogdet](). Singular matrices return a sign
+    /// of zero and a log-magnitude of negative infinity.
+    fn slogdet(&self) -> Result<(F, F::RealPart), LUError>;
+
+    /// Return the determinant of a symmetric/Hermitian positive
+    /// definite matrix.
+    ///
+    /// The determinant is computed from the Cholesky factorization of
+    /// the matrix, so only the portion specified by `uplo` is read.
+    fn positive_definite_det(&self, uplo: Symmetric) -> Result<F::RealPart, CholeskyError>;
+
+    /// Return the natural log of the determinant of a
+    /// symmetric/Hermitian positive definite matrix.
+    ///
+    /// The determinant is always positive, so no sign is returned.
+    fn positive_definite_logdet(&self, uplo: Symmetric) -> Result<F::RealPart, CholeskyError>;
+
     /// Return the full singular value decomposition of the matrix.
     ///
     /// The `SVDSolution` contains full size matrices `u` (m x m) and `vt` (n x n).
@@ -152,6 +385,30 @@ impl<F: LinxalScalar, D: Data<Elem = F>> LinxalMatrix<F> for ArrayBase<D, Ix2> {
         Eigen::compute(self, compute_left, compute_right)
     }
 
+    fn eigenvalues_expert(&self,
+                          balance: Balance,
+                          compute_left: bool,
+                          compute_right: bool)
+                          -> Result<ExpertEigenSolution<F>, EigenError> {
+        Eigen::compute_expert(self, balance, compute_left, compute_right)
+    }
+
+    fn generalized_eigenvalues<D1: Data<Elem = F>>(&self, b: &ArrayBase<D1, Ix2>)
+                                                   -> Result<eigenvalues::GeneralizedSolution<F, F::Complex>, EigenError> {
+        GeneralizedEigen::compute(self, b, false, false)
+    }
+
+    fn generalized_eigenvalues_vectors<D1: Data<Elem = F>>(&self,
+                                                           b: &ArrayBase<D1, Ix2>,
+                                                           compute_left: bool,
+                                                           compute_right: bool)
+                                                           -> Result<eigenvalues::GeneralizedSolution<F, F::Complex>, EigenError> {
+        GeneralizedEigen::compute(self, b, compute_left, compute_right)
+    }
+
+    fn schur(&self) -> Result<SchurFactors<F>, EigenError> {
+        Schur::compute(self)
+    }
 
     fn symmetric_eigenvalues(&self, uplo: Symmetric)
                              -> Result<Array<F::RealPart, Ix1>, EigenError> {
@@ -164,6 +421,37 @@ impl<F: LinxalScalar, D: Data<Elem = F>> LinxalMatrix<F> for ArrayBase<D, Ix2> {
         SymEigen::compute(self, uplo, true)
     }
 
+    fn symmetric_eigenvalues_vectors_with_method(&self, uplo: Symmetric, method: SymEigenMethod)
+                                                 -> Result<eigenvalues::Solution<F, F::RealPart>, EigenError> {
+        SymEigen::compute_with_method(self, uplo, true, method)
+    }
+
+    fn symmetric_eigenvalues_range(&self, uplo: Symmetric, range: EigenRange<F::RealPart>)
+                                   -> Result<Array<F::RealPart, Ix1>, EigenError> {
+        SymEigen::compute_range(self, uplo, range, false).map(|s| s.values)
+    }
+
+    fn symmetric_eigenvalues_vectors_range(&self, uplo: Symmetric, range: EigenRange<F::RealPart>)
+                                           -> Result<eigenvalues::Solution<F, F::RealPart>, EigenError> {
+        SymEigen::compute_range(self, uplo, range, true)
+    }
+
+    fn symmetric_generalized_eigenvalues<D1: Data<Elem = F>>(&self,
+                                                             b: &ArrayBase<D1, Ix2>,
+                                                             uplo: Symmetric,
+                                                             problem: GeneralizedProblem)
+                                                             -> Result<Array<F::RealPart, Ix1>, EigenError> {
+        SymGeneralizedEigen::compute(self, b, uplo, problem, false).map(|s| s.values)
+    }
+
+    fn symmetric_generalized_eigenvalues_vectors<D1: Data<Elem = F>>(&self,
+                                                                     b: &ArrayBase<D1, Ix2>,
+                                                                     uplo: Symmetric,
+                                                                     problem: GeneralizedProblem)
+                                                                     -> Result<eigenvalues::Solution<F, F::RealPart>, EigenError> {
+        SymGeneralizedEigen::compute(self, b, uplo, problem, true)
+    }
+
     fn solve_linear<D1: Data<Elem = F>>(&self,
                                         b: &ArrayBase<D1, Ix1>)
                                         -> Result<Array<F, Ix1>, SolveError> {
@@ -191,6 +479,18 @@ impl<F: LinxalScalar, D: Data<Elem = F>> LinxalMatrix<F> for ArrayBase<D, Ix2> {
         SymmetricSolveLinear::compute_multi(self, uplo, b)
     }
 
+    fn solve_linear_expert<D1: Data<Elem = F>>(&self,
+                                               b: &ArrayBase<D1, Ix1>)
+                                               -> Result<ExpertSolution<F, Ix1>, SolveError> {
+        ExpertSolveLinear::compute(self, b)
+    }
+
+    fn solve_multi_linear_expert<D1: Data<Elem = F>>(&self,
+                                                     b: &ArrayBase<D1, Ix2>)
+                                                     -> Result<ExpertSolution<F, Ix2>, SolveError> {
+        ExpertSolveLinear::compute_multi(self, b)
+    }
+
     fn least_squares<D1, PT>(&self,
                              b: &ArrayBase<D1, Ix1>,
                              _problem_type: PT)
@@ -220,6 +520,22 @@ impl<F: LinxalScalar, D: Data<Elem = F>> LinxalMatrix<F> for ArrayBase<D, Ix2> {
         QR::compute(self)
     }
 
+    fn qr_pivoted(&self) -> Result<QRPFactors<F>, QRError> {
+        QR::compute_pivoted(self)
+    }
+
+    fn lq(&self) -> Result<LQFactors<F>, QRError> {
+        LQ::compute(self)
+    }
+
+    fn rq(&self) -> Result<RQFactors<F>, QRError> {
+        RQ::compute(self)
+    }
+
+    fn ql(&self) -> Result<QLFactors<F>, QRError> {
+        QL::compute(self)
+    }
+
     fn lu(&self) -> Result<LUFactors<F>, LUError> {
         LU::compute(self)
     }
@@ -228,6 +544,117 @@ impl<F: LinxalScalar, D: Data<Elem = F>> LinxalMatrix<F> for ArrayBase<D, Ix2> {
         Cholesky::compute(self, uplo)
     }
 
+    fn cholesky_factors(&self, uplo: Symmetric) -> Result<CholeskyFactors<F>, CholeskyError> {
+        Cholesky::compute_factors(self, uplo)
+    }
+
+    fn cholesky_pivoted<Tol>(&self, uplo: Symmetric, tolerance: Tol)
+                             -> Result<CholeskyPFactors<F>, CholeskyError>
+        where Tol: Into<Option<F::RealPart>> {
+        Cholesky::compute_pivoted(self, uplo, tolerance)
+    }
+
+    fn ldl(&self, uplo: Symmetric) -> Result<LDLFactors<F>, LDLError> {
+        LDL::compute(self, uplo)
+    }
+
+    fn hessenberg(&self) -> Result<HessenbergFactors<F>, ReductionError> {
+        Hessenberg::compute(self)
+    }
+
+    fn tridiagonal(&self, uplo: Symmetric) -> Result<TridiagonalFactors<F>, ReductionError> {
+        Tridiagonal::compute(self, uplo)
+    }
+
+    fn sylvester<D1, D2>(&self, b: &ArrayBase<D1, Ix2>, c: &ArrayBase<D2, Ix2>)
+                         -> Result<Array<F, Ix2>, MatrixEquationError>
+        where D1: Data<Elem = F>,
+              D2: Data<Elem = F> {
+        MatrixEquations::sylvester(self, b, c)
+    }
+
+    fn lyapunov<D1: Data<Elem = F>>(&self, q: &ArrayBase<D1, Ix2>)
+                                    -> Result<Array<F, Ix2>, MatrixEquationError> {
+        MatrixEquations::lyapunov(self, q)
+    }
+
+    fn discrete_lyapunov<D1: Data<Elem = F>>(&self, q: &ArrayBase<D1, Ix2>)
+                                             -> Result<Array<F, Ix2>, MatrixEquationError> {
+        MatrixEquations::discrete_lyapunov(self, q)
+    }
+
+    fn care<D1, D2, D3>(&self, b: &ArrayBase<D1, Ix2>, q: &ArrayBase<D2, Ix2>, r: &ArrayBase<D3, Ix2>)
+                        -> Result<RiccatiSolution<F>, MatrixEquationError>
+        where D1: Data<Elem = F>,
+              D2: Data<Elem = F>,
+              D3: Data<Elem = F> {
+        MatrixEquations::care(self, b, q, r)
+    }
+
+    fn dare<D1, D2, D3>(&self, b: &ArrayBase<D1, Ix2>, q: &ArrayBase<D2, Ix2>, r: &ArrayBase<D3, Ix2>)
+                        -> Result<RiccatiSolution<F>, MatrixEquationError>
+        where D1: Data<Elem = F>,
+              D2: Data<Elem = F>,
+              D3: Data<Elem = F> {
+        MatrixEquations::dare(self, b, q, r)
+    }
+
+    fn norm(&self, norm: NormType) -> Result<F::RealPart, NormError> {
+        Norm::compute(self, norm)
+    }
+
+    fn symmetric_norm(&self, uplo: Symmetric, norm: NormType) -> Result<F::RealPart, NormError> {
+        Norm::compute_symmetric(self, uplo, norm)
+    }
+
+    fn triangular_norm(&self, uplo: Symmetric, norm: NormType) -> Result<F::RealPart, NormError> {
+        Norm::compute_triangular(self, uplo, norm)
+    }
+
+    fn condition_number(&self, norm: ConditionNorm) -> Result<F::RealPart, LUError> {
+        if !self.is_square() {
+            return Err(LUError::NotSquare);
+        }
+
+        match LU::compute(self) {
+            Ok(factors) => factors.rcond(norm).map(|rcond| F::RealPart::one() / rcond),
+            Err(LUError::Singular) => Ok(F::RealPart::infinity()),
+            Err(lu_error) => Err(lu_error),
+        }
+    }
+
+    fn det(&self) -> Result<F, LUError> {
+        if !self.is_square() {
+            return Err(LUError::NotSquare);
+        }
+
+        match LU::compute(self) {
+            Ok(factors) => factors.det(),
+            Err(LUError::Singular) => Ok(F::zero()),
+            Err(lu_error) => Err(lu_error),
+        }
+    }
+
+    fn slogdet(&self) -> Result<(F, F::RealPart), LUError> {
+        if !self.is_square() {
+            return Err(LUError::NotSquare);
+        }
+
+        match LU::compute(self) {
+            Ok(factors) => factors.slogdet(),
+            Err(LUError::Singular) => Ok((F::zero(), F::RealPart::neg_infinity())),
+            Err(lu_error) => Err(lu_error),
+        }
+    }
+
+    fn positive_definite_det(&self, uplo: Symmetric) -> Result<F::RealPart, CholeskyError> {
+        Cholesky::compute_factors(self, uplo).map(|chol| chol.det())
+    }
+
+    fn positive_definite_logdet(&self, uplo: Symmetric) -> Result<F::RealPart, CholeskyError> {
+        Cholesky::compute_factors(self, uplo).map(|chol| chol.log_det())
+    }
+
     fn svd_full(&self) -> Result<SVDSolution<F>, SVDError> {
         SVD::compute(self, SVDComputeVectors::Full)
     }
@@ -6,7 +6,7 @@ pub use std::{f32, f64};
 
 /// Enum for symmetric matrix inputs.
 #[repr(u8)]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Symmetric {
     /// Read elements from the upper-triangular portion of the matrix
     Upper = b'U',
@@ -15,6 +15,34 @@ pub enum Symmetric {
     Lower = b'L',
 }
 
+/// Enum for selecting the operation applied to a matrix before it is
+/// used in a computation.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transpose {
+    /// Use the matrix as-is
+    None = b'N',
+
+    /// Use the transpose of the matrix
+    Transpose = b'T',
+
+    /// Use the conjugate transpose of the matrix. For real-valued
+    /// matrices, this is equivalent to `Transpose::Transpose`.
+    ConjTranspose = b'C',
+}
+
+/// Enum for the side from which a matrix is applied to another
+/// matrix.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    /// Multiply from the left (`Q * C`)
+    Left = b'L',
+
+    /// Multiply from the right (`C * Q`)
+    Right = b'R',
+}
+
 pub mod impl_scalar;
 pub mod scalar;
 pub mod error;
@@ -1,20 +1,25 @@
 //! Define scalar types for matrix usage.
 
-use eigenvalues::{Eigen, SymEigen};
-use solve_linear::{SolveLinear, SymmetricSolveLinear};
+use eigenvalues::{Eigen, GeneralizedEigen, SymEigen, SymGeneralizedEigen, Schur, TridiagonalEigen};
+use solve_linear::{SolveLinear, SymmetricSolveLinear, ExpertSolveLinear, TriangularSolveLinear};
 use least_squares::LeastSquares;
 use num_traits::Float;
 use impl_prelude::*;
-use factorization::{QR, LU, Cholesky};
+use factorization::{QR, LQ, RQ, QL, LU, Cholesky, LDL, Hessenberg, Tridiagonal};
 use svd::SVD;
 use generate::matgen::MG;
+use condition::Condition;
+use norms::Norm;
+use matrix_equations::MatrixEquations;
 
 /// Catch-all aggregate trait for computational routines needed by
 /// `LinxalMatrix`.
-pub trait LinxalScalar: LinxalImplScalar + Eigen + SymEigen + SolveLinear + SymmetricSolveLinear +
-    LeastSquares + QR + LU + Cholesky + SVD + MG {}
-impl<T: LinxalImplScalar + Eigen + SymEigen + SolveLinear + SymmetricSolveLinear +
-     LeastSquares + QR + LU + Cholesky + SVD + MG> LinxalScalar for T {}
+pub trait LinxalScalar: LinxalImplScalar + Eigen + GeneralizedEigen + SymEigen + SymGeneralizedEigen + Schur + TridiagonalEigen + SolveLinear + SymmetricSolveLinear +
+    LeastSquares + QR + LQ + RQ + QL + LU + Cholesky + LDL + Hessenberg + Tridiagonal + SVD + MG + Condition + Norm + ExpertSolveLinear +
+    TriangularSolveLinear + MatrixEquations {}
+impl<T: LinxalImplScalar + Eigen + GeneralizedEigen + SymEigen + SymGeneralizedEigen + Schur + TridiagonalEigen + SolveLinear + SymmetricSolveLinear +
+     LeastSquares + QR + LQ + RQ + QL + LU + Cholesky + LDL + Hessenberg + Tridiagonal + SVD + MG + Condition + Norm + ExpertSolveLinear +
+     TriangularSolveLinear + MatrixEquations> LinxalScalar for T {}
 
 /// Narrowing trait for `LinxalScalar`s that are also real.
 pub trait LinxalReal: LinxalScalar + Float {}
@@ -18,6 +18,27 @@ pub fn matrix_with_layout<T: LinxalImplScalar, Sh>(d: Sh, layout: Layout)
     Array::default(shape)
 }
 
+/// Return a copy of the matrix `mat`, with the same layout as the
+/// matrix `other`.
+///
+/// This function is used internally to ensure that copied inputs are
+/// compatible with existing matrices, such as stored factorizations.
+pub fn copy_with_matching_layout<T, D1, D2>(mat: &ArrayBase<D1, Ix2>,
+                                            other: &ArrayBase<D2, Ix2>)
+                                            -> Array<T, Ix2>
+    where T: LinxalImplScalar,
+          D1: Data<Elem = T>,
+          D2: Data<Elem = T> {
+    let layout = match slice_and_layout(other) {
+        Some((_, layout, _)) => layout,
+        None => Layout::RowMajor,
+    };
+
+    let mut c = matrix_with_layout(mat.dim(), layout);
+    c.assign(mat);
+    c
+}
+
 /// Return the slice, layout, and leading dimension of the matrix.
 ///
 /// For LAPACKE methods, the memory layout does not need to be
@@ -113,11 +134,19 @@ pub fn slice_and_layout_matching_mut<D, S: DataMut<Elem = D>>(mat: &mut ArrayBas
     if dim.1 == 1 {
         let m = mat.strides()[0];
 
+        // In column-major order, the leading dimension must span the
+        // entire column, so the column must be contiguous.
+        let ld = match layout {
+            Layout::RowMajor => m,
+            Layout::ColumnMajor if m == 1 => cmp::max(dim.0, 1) as Ixs,
+            Layout::ColumnMajor => return None,
+        };
+
         let s = unsafe {
             let nelem: usize = (dim.0 - 1) * m as usize + dim.1;
             slice::from_raw_parts_mut(mat.as_mut_ptr(), nelem)
         };
-        return Some((s, m));
+        return Some((s, ld));
     }
 
     // Otherwise, we just use the normal method and check for a match.
@@ -129,3 +158,56 @@ pub fn slice_and_layout_matching_mut<D, S: DataMut<Elem = D>>(mat: &mut ArrayBas
 
     None
 }
+
+/// Fill the triangle of the square matrix `mat` opposite to `uplo`
+/// with the conjugate of the triangle specified by `uplo`.
+///
+/// This function is used internally to expand the output of LAPACK
+/// routines that only compute one triangle of a symmetric (or
+/// Hermitian) matrix.
+pub fn fill_hermitian<T, D>(mat: &mut ArrayBase<D, Ix2>, uplo: Symmetric)
+    where T: LinxalImplScalar,
+          D: DataMut<Elem = T> {
+    let n = cmp::min(mat.rows(), mat.cols());
+    for i in 0..n {
+        for j in 0..i {
+            match uplo {
+                Symmetric::Upper => mat[(i, j)] = mat[(j, i)].cj(),
+                Symmetric::Lower => mat[(j, i)] = mat[(i, j)].cj(),
+            }
+        }
+    }
+}
+
+/// Trait for stepping a floating-point value to its predecessor.
+///
+/// This trait is used internally to convert the half-open interval
+/// `[vl, vu)` into the interval `(vl', vu']` expected by LAPACK
+/// routines that select eigenvalues by value.
+pub trait NextDown {
+    /// Return the largest representable value less than `self`.
+    ///
+    /// NaN and negative infinity are returned unchanged.
+    fn next_down(self) -> Self;
+}
+
+macro_rules! impl_next_down {
+    ($float_type:ty, $neg_min_subnormal:expr) => (
+        impl NextDown for $float_type {
+            fn next_down(self) -> Self {
+                if self.is_nan() || (self.is_infinite() && self < 0.0) {
+                    self
+                } else if self == 0.0 {
+                    <$float_type>::from_bits($neg_min_subnormal)
+                } else if self > 0.0 {
+                    <$float_type>::from_bits(self.to_bits() - 1)
+                } else {
+                    <$float_type>::from_bits(self.to_bits() + 1)
+                }
+            }
+        }
+    )
+}
+
+impl_next_down!(f32, 0x8000_0001);
+impl_next_down!(f64, 0x8000_0000_0000_0001);
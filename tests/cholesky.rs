@@ -5,7 +5,7 @@ extern crate ndarray;
 extern crate num_traits;
 extern crate rand;
 
-use ndarray::{Array, ArrayBase, Data, Ix2};
+use ndarray::{Array, ArrayBase, Data, Ix2, ShapeBuilder, arr1, arr2};
 use rand::thread_rng;
 use linxal::types::{LinxalScalar, LinxalMatrix, Symmetric, c32, c64};
 use linxal::types::error::{ CholeskyError};
@@ -109,3 +109,188 @@ fn cholesky_fail_not_square() {
         }
     }
 }
+
+fn cholesky_factors_generic<T: LinxalScalar>(uplo: Symmetric) {
+    let mut rng = thread_rng();
+    for n in 1..8 {
+        let m: Array<T, Ix2> = RandomSemiPositive::new(n, &mut rng).generate().unwrap();
+        let chol = m.cholesky_factors(uplo).unwrap();
+        assert_eq!(chol.uplo(), uplo);
+
+        let l = chol.l();
+        let u = chol.u();
+        assert!(l.is_triangular(Symmetric::Lower, None));
+        assert!(u.is_triangular(Symmetric::Upper, None));
+        assert_eq_within_tol!(l.dot(&u), m, 1e-3.into());
+
+        // The solution to the system matches the original RHS.
+        let b: Array<T, Ix2> = Array::linspace(1.0f32, 2.0, n * 2)
+            .into_shape((n, 2)).unwrap()
+            .mapv(|x| T::from_real(x.into()));
+        let x = chol.solve_multi(&b).unwrap();
+        assert_eq_within_tol!(m.dot(&x), b, 1e-3.into());
+
+        let x0 = chol.solve(&b.column(0)).unwrap();
+        assert_eq_within_tol!(x0, x.column(0), 1e-3.into());
+
+        // The inverse is symmetric in both triangles.
+        let inv = chol.inverse().unwrap();
+        let eye: Array<T, Ix2> = Array::eye(n);
+        assert_eq_within_tol!(m.dot(&inv), eye, 1e-3.into());
+
+        let logdet = m.positive_definite_logdet(uplo).unwrap();
+        assert_eq_within_tol!(arr1(&[chol.log_det()]), arr1(&[logdet]), 1e-3.into());
+    }
+}
+
+#[test]
+fn cholesky_factors() {
+    cholesky_factors_generic::<f32>(Symmetric::Upper);
+    cholesky_factors_generic::<f32>(Symmetric::Lower);
+    cholesky_factors_generic::<f64>(Symmetric::Upper);
+    cholesky_factors_generic::<f64>(Symmetric::Lower);
+    cholesky_factors_generic::<c32>(Symmetric::Upper);
+    cholesky_factors_generic::<c32>(Symmetric::Lower);
+    cholesky_factors_generic::<c64>(Symmetric::Upper);
+    cholesky_factors_generic::<c64>(Symmetric::Lower);
+}
+
+#[test]
+fn cholesky_factors_known() {
+    let m = arr2(&[[4.0f64, 2.0, -2.0],
+                   [2.0, 10.0, 2.0],
+                   [-2.0, 2.0, 5.0]]);
+    let chol = m.cholesky_factors(Symmetric::Lower).unwrap();
+
+    let l = arr2(&[[2.0, 0.0, 0.0],
+                   [1.0, 3.0, 0.0],
+                   [-1.0, 1.0, 1.7320508075688772]]);
+    assert_eq_within_tol!(chol.l(), l, 1e-10);
+    assert_eq_within_tol!(chol.u(), l.t(), 1e-10);
+    assert!((chol.det() - 108.0).abs() < 1e-8);
+    assert!((chol.log_det() - 108.0f64.ln()).abs() < 1e-10);
+
+    // A well-conditioned matrix.
+    let rcond = chol.rcond().unwrap();
+    assert!(rcond > 0.05 && rcond <= 1.0);
+
+    // Column-major right-hand sides are accepted by the copying
+    // variants.
+    let mut b = Array::zeros((3, 2).f());
+    b.assign(&arr2(&[[2.0, 0.0], [14.0, 1.0], [5.0, 2.0]]));
+    let x = chol.solve_multi(&b).unwrap();
+    assert_eq_within_tol!(m.dot(&x), b, 1e-10);
+
+    assert_eq!(chol.solve(&arr1(&[1.0, 2.0])).unwrap_err(),
+               CholeskyError::InconsistentDimensions);
+}
+
+fn cholesky_pivoted_generic<T: LinxalScalar>(uplo: Symmetric) {
+    // A rank-2 positive semi-definite matrix.
+    let g: Array<T, Ix2> = arr2(&[[1.0f32, 0.0],
+                                  [2.0, 1.0],
+                                  [0.0, 3.0],
+                                  [1.0, 1.0]])
+        .mapv(|x| T::from_real(x.into()));
+    let m = g.dot(&g.conj_t());
+
+    let chol = m.cholesky_pivoted(uplo, None).unwrap();
+    assert_eq!(chol.uplo(), uplo);
+    assert_eq!(chol.rank(), 2);
+    assert!(chol.factor().is_triangular(uplo, None));
+    assert_eq_within_tol!(chol.reconstruct(), m, 1e-3.into());
+
+    let low_rank = chol.low_rank_factor();
+    assert_eq!(low_rank.dim(), (4, 2));
+    assert_eq_within_tol!(low_rank.dot(&low_rank.conj_t()), m, 1e-3.into());
+
+    // The first pivot is the largest diagonal element.
+    assert_eq!(chol.permutation()[0], 2);
+}
+
+#[test]
+fn cholesky_pivoted() {
+    cholesky_pivoted_generic::<f32>(Symmetric::Upper);
+    cholesky_pivoted_generic::<f32>(Symmetric::Lower);
+    cholesky_pivoted_generic::<f64>(Symmetric::Upper);
+    cholesky_pivoted_generic::<f64>(Symmetric::Lower);
+    cholesky_pivoted_generic::<c32>(Symmetric::Upper);
+    cholesky_pivoted_generic::<c32>(Symmetric::Lower);
+    cholesky_pivoted_generic::<c64>(Symmetric::Upper);
+    cholesky_pivoted_generic::<c64>(Symmetric::Lower);
+}
+
+#[test]
+fn cholesky_pivoted_full_rank() {
+    let mut rng = thread_rng();
+    let m: Array<f64, Ix2> = RandomSemiPositive::new(6, &mut rng).generate().unwrap();
+
+    let chol = m.cholesky_pivoted(Symmetric::Lower, None).unwrap();
+    assert_eq!(chol.rank(), 6);
+
+    // P^T * A * P = L * L^H
+    let p = chol.p();
+    let l = chol.factor();
+    assert_eq_within_tol!(p.t().dot(&m).dot(&p), l.dot(&l.t()), 1e-8);
+
+    // A loose tolerance truncates the factorization.
+    let chol = m.cholesky_pivoted(Symmetric::Lower, 1e3).unwrap();
+    assert_eq!(chol.rank(), 0);
+}
+
+fn cholesky_update_generic<T: LinxalScalar>(uplo: Symmetric) {
+    let mut rng = thread_rng();
+    let n = 6;
+    let m: Array<T, Ix2> = RandomSemiPositive::new(n, &mut rng).generate().unwrap();
+    let x: Array<T, Ix2> = Array::linspace(-1.0f32, 1.0, n)
+        .into_shape((n, 1)).unwrap()
+        .mapv(|v| T::from_real(v.into()));
+    let xxh = x.dot(&x.conj_t());
+    let x = x.column(0);
+
+    let mut chol = m.cholesky_factors(uplo).unwrap();
+
+    // A + x * x^H
+    chol.update(&x).unwrap();
+    assert!(chol.factor().is_triangular(uplo, None));
+    let updated = &m + &xxh;
+    assert_eq_within_tol!(chol.l().dot(&chol.u()), updated, 1e-3.into());
+    assert_eq_within_tol!(chol.factor(), updated.cholesky(uplo).unwrap(), 1e-3.into());
+
+    // Removing it again recovers the original factorization.
+    chol.downdate(&x).unwrap();
+    assert_eq_within_tol!(chol.l().dot(&chol.u()), m, 1e-3.into());
+
+    // The condition number is still available after the update.
+    let rcond = chol.rcond().unwrap();
+    let truth = m.cholesky_factors(uplo).unwrap().rcond().unwrap();
+    assert_eq_within_tol!(arr1(&[rcond]), arr1(&[truth]), 1e-3.into());
+}
+
+#[test]
+fn cholesky_update() {
+    cholesky_update_generic::<f32>(Symmetric::Upper);
+    cholesky_update_generic::<f32>(Symmetric::Lower);
+    cholesky_update_generic::<f64>(Symmetric::Upper);
+    cholesky_update_generic::<f64>(Symmetric::Lower);
+    cholesky_update_generic::<c32>(Symmetric::Upper);
+    cholesky_update_generic::<c32>(Symmetric::Lower);
+    cholesky_update_generic::<c64>(Symmetric::Upper);
+    cholesky_update_generic::<c64>(Symmetric::Lower);
+}
+
+#[test]
+fn cholesky_downdate_not_positive_definite() {
+    let m = arr2(&[[2.0f64, 1.0],
+                   [1.0, 2.0]]);
+    let mut chol = m.cholesky_factors(Symmetric::Lower).unwrap();
+    let before = chol.factor().clone();
+
+    // A - x * x^H has the eigenvalue 3 - 4 < 0.
+    let x = arr1(&[2.0f64.sqrt(), 2.0f64.sqrt()]);
+    assert_eq!(chol.downdate(&x).unwrap_err(), CholeskyError::NotPositiveDefinite);
+    assert_eq!(chol.factor(), &before);
+
+    assert_eq!(chol.update(&arr1(&[1.0])).unwrap_err(),
+               CholeskyError::InconsistentDimensions);
+}
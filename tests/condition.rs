@@ -0,0 +1,78 @@
+#[macro_use]
+extern crate linxal;
+
+extern crate ndarray;
+
+use ndarray::{Array, Ix2, arr1, arr2};
+use linxal::types::{LinxalMatrix, LinxalScalar, Symmetric, c32, c64};
+use linxal::condition::{Condition, ConditionNorm, ConditionError};
+use linxal::factorization::{Cholesky, LUError};
+
+fn condition_diagonal_generic<T: LinxalScalar>() {
+    let mut m: Array<T, Ix2> = Array::eye(4);
+    m[(2, 2)] = T::from_real(1e-3.into());
+    m[(3, 3)] = T::from_real(5.0.into());
+
+    let truth = arr1(&[5000.0.into()]);
+
+    for &norm in &[ConditionNorm::One, ConditionNorm::Infinity] {
+        let kappa = m.condition_number(norm).unwrap();
+        assert_eq_within_tol!(arr1(&[kappa]), truth, 1e-1.into());
+
+        let rcond = m.lu().unwrap().rcond(norm).unwrap();
+        assert_eq_within_tol!(arr1(&[T::RealPart::from(1.0) / rcond]), truth, 1e-1.into());
+
+        let rcond = Condition::compute_triangular(&m, Symmetric::Upper, norm).unwrap();
+        assert_eq_within_tol!(arr1(&[T::RealPart::from(1.0) / rcond]), truth, 1e-1.into());
+    }
+
+    let chol = Cholesky::compute_factors(&m, Symmetric::Lower).unwrap();
+    let rcond = chol.rcond().unwrap();
+    assert_eq_within_tol!(arr1(&[T::RealPart::from(1.0) / rcond]), truth, 1e-1.into());
+}
+
+#[test]
+fn condition_diagonal() {
+    condition_diagonal_generic::<f32>();
+    condition_diagonal_generic::<f64>();
+    condition_diagonal_generic::<c32>();
+    condition_diagonal_generic::<c64>();
+}
+
+#[test]
+fn condition_identity() {
+    let m: Array<f64, Ix2> = Array::eye(10);
+    let kappa = m.condition_number(ConditionNorm::One).unwrap();
+    assert!((kappa - 1.0).abs() < 1e-10);
+}
+
+#[test]
+fn condition_nearly_singular() {
+    let m = arr2(&[[1.0f64, 1.0], [1.0, 1.0 + 1e-12]]);
+    let rcond = m.lu().unwrap().rcond(ConditionNorm::One).unwrap();
+    assert!(rcond < 1e-10);
+}
+
+#[test]
+fn condition_lu_factors() {
+    // The factors remember both norms of the original matrix.
+    let m = arr2(&[[1.0f64, 2.0], [0.0, 4.0]]);
+    let lu = m.lu().unwrap();
+    assert!((lu.rcond(ConditionNorm::One).unwrap() - 1.0 / 4.5).abs() < 1e-10);
+    assert!((lu.rcond(ConditionNorm::Infinity).unwrap() - 1.0 / 6.0).abs() < 1e-10);
+}
+
+#[test]
+fn condition_singular() {
+    let m = arr2(&[[1.0f32, 2.0], [2.0, 4.0]]);
+    assert_eq!(m.condition_number(ConditionNorm::One).unwrap(), f32::INFINITY);
+}
+
+#[test]
+fn condition_not_square() {
+    let m: Array<f32, Ix2> = Array::zeros((3, 2));
+    assert_eq!(m.condition_number(ConditionNorm::One).err().unwrap(), LUError::NotSquare);
+
+    let r = Condition::compute_triangular(&m, Symmetric::Upper, ConditionNorm::One);
+    assert_eq!(r.err().unwrap(), ConditionError::NotSquare);
+}
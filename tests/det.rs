@@ -0,0 +1,92 @@
+#[macro_use]
+extern crate linxal;
+
+extern crate ndarray;
+extern crate num_traits;
+extern crate rand;
+
+use ndarray::{Array, Ix2, arr2};
+use rand::thread_rng;
+use num_traits::Float;
+use linxal::types::{LinxalMatrix, LinxalScalar, Symmetric, c32, c64};
+use linxal::factorization::LUError;
+use linxal::generate::{RandomSemiPositive};
+
+#[test]
+fn det_basic() {
+    let m = arr2(&[[1.0f64, 2.0], [3.0, 4.0]]);
+    assert!((m.det().unwrap() - -2.0).abs() < 1e-10);
+
+    let (sign, logdet) = m.slogdet().unwrap();
+    assert_eq!(sign, -1.0);
+    assert!((logdet - 2.0.ln()).abs() < 1e-10);
+
+    // Requires a row interchange.
+    let p = arr2(&[[0.0f64, 1.0, 0.0], [0.0, 0.0, 2.0], [3.0, 0.0, 0.0]]);
+    assert!((p.det().unwrap() - 6.0).abs() < 1e-10);
+    assert!((p.lu().unwrap().det().unwrap() - 6.0).abs() < 1e-10);
+}
+
+#[test]
+fn det_complex() {
+    let m = arr2(&[[c64::new(0.0, 1.0), c64::new(0.0, 0.0)],
+                   [c64::new(5.0, 0.0), c64::new(2.0, 0.0)]]);
+    let d = m.det().unwrap();
+    assert!((d - c64::new(0.0, 2.0)).norm() < 1e-10);
+
+    let (sign, logdet) = m.slogdet().unwrap();
+    assert!((sign - c64::new(0.0, 1.0)).norm() < 1e-10);
+    assert!((logdet - 2.0.ln()).abs() < 1e-10);
+}
+
+#[test]
+fn det_singular() {
+    let m = arr2(&[[1.0f32, 2.0], [2.0, 4.0]]);
+    assert_eq!(m.det().unwrap(), 0.0);
+
+    let (sign, logdet) = m.slogdet().unwrap();
+    assert_eq!(sign, 0.0);
+    assert_eq!(logdet, f32::neg_infinity());
+}
+
+#[test]
+fn det_not_square() {
+    let m: Array<f32, Ix2> = Array::zeros((2, 3));
+    assert_eq!(m.det().err().unwrap(), LUError::NotSquare);
+    assert_eq!(m.slogdet().err().unwrap(), LUError::NotSquare);
+}
+
+#[test]
+fn slogdet_large() {
+    // The determinant itself overflows, but the log-determinant is
+    // well-defined.
+    let n = 200;
+    let mut m: Array<f32, Ix2> = Array::eye(n);
+    m.diag_mut().fill(-10.0);
+
+    let (sign, logdet) = m.slogdet().unwrap();
+    assert_eq!(sign, 1.0);
+    assert!((logdet - (n as f32) * 10.0.ln()).abs() < 1e-2);
+}
+
+fn det_positive_definite_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for n in 1..11 {
+        let m: Array<T, Ix2> = RandomSemiPositive::new(n, &mut rng).generate().unwrap();
+
+        let (_, logdet) = m.slogdet().unwrap();
+        let pd_logdet = m.positive_definite_logdet(Symmetric::Upper).unwrap();
+        let pd_det = m.positive_definite_det(Symmetric::Lower).unwrap();
+
+        assert_eq_within_tol!(Array::from_elem(1, logdet), Array::from_elem(1, pd_logdet), 1e-3.into());
+        assert_eq_within_tol!(Array::from_elem(1, logdet), Array::from_elem(1, pd_det.ln()), 1e-3.into());
+    }
+}
+
+#[test]
+fn det_positive_definite() {
+    det_positive_definite_generic::<f32>();
+    det_positive_definite_generic::<f64>();
+    det_positive_definite_generic::<c32>();
+    det_positive_definite_generic::<c64>();
+}
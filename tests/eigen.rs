@@ -1,10 +1,14 @@
 #[macro_use]
 extern crate linxal;
 extern crate ndarray;
+extern crate rand;
 
-use linxal::types::{LinxalMatrixInto, c32};
+use linxal::types::{LinxalMatrix, LinxalMatrixInto, c32, c64};
 use linxal::types::error::{EigenError};
-use ndarray::{arr1, arr2, Array};
+use linxal::eigenvalues::Balance;
+use linxal::generate::RandomGeneral;
+use ndarray::{arr1, arr2, Array, Ix2};
+use rand::thread_rng;
 
 #[test]
 fn try_eig() {
@@ -35,3 +39,131 @@ fn eig_nonsquare() {
     assert!(r.is_err());
     assert_eq!(r.err().unwrap(), EigenError::NotSquare);
 }
+
+#[test]
+fn eig_complex_vectors() {
+    let mut rng = thread_rng();
+    for n in 1..8 {
+        let m: Array<f64, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let mc = m.mapv(|x| c64::new(x, 0.0));
+
+        let sol = m.eigenvalues_vectors(true, true).unwrap();
+        let vl = sol.left_vectors_complex().unwrap();
+        let vr = sol.right_vectors_complex().unwrap();
+        assert_eq!(vr.dim(), (n, n));
+
+        for (j, &lambda) in sol.values.iter().enumerate() {
+            // A * x = lambda * x
+            let x = vr.column(j);
+            assert_eq_within_tol!(mc.dot(&x), x.mapv(|v| v * lambda), 1e-8);
+
+            // y^H * A = lambda * y^H
+            let y = vl.column(j).mapv(|v| v.conj());
+            assert_eq_within_tol!(y.dot(&mc), y.mapv(|v| v * lambda), 1e-8);
+        }
+    }
+}
+
+#[test]
+fn eig_complex_vectors_rotation() {
+    let m = arr2(&[[1.0f32, 2.0],
+                   [-2.0, 1.0]]);
+    let sol = m.eigenvalues_vectors(false, true).unwrap();
+    let vr = sol.right_vectors_complex().unwrap();
+
+    // The eigenvectors of the conjugate pair are conjugates.
+    assert_eq_within_tol!(vr.column(1), vr.column(0).mapv(|v| v.conj()), 1e-6);
+    assert!(sol.left_vectors_complex().is_none());
+}
+
+#[test]
+fn generalized_complex_vectors() {
+    let mut rng = thread_rng();
+    for n in 1..8 {
+        let a: Array<f64, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let b: Array<f64, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let ac = a.mapv(|x| c64::new(x, 0.0));
+        let bc = b.mapv(|x| c64::new(x, 0.0));
+
+        let sol = a.generalized_eigenvalues_vectors(&b, false, true).unwrap();
+        let vr = sol.right_vectors_complex().unwrap();
+
+        for j in 0..n {
+            // beta * A * x = alpha * B * x
+            let x = vr.column(j);
+            assert_eq_within_tol!(ac.dot(&x).mapv(|v| v * sol.beta[j]),
+                                  bc.dot(&x).mapv(|v| v * sol.alpha[j]),
+                                  1e-8);
+        }
+    }
+}
+
+#[test]
+fn eig_expert() {
+    let mut rng = thread_rng();
+    for &balance in &[Balance::None, Balance::Permute, Balance::Scale, Balance::Both] {
+        for n in 0..8 {
+            let m: Array<f64, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+            let mc = m.mapv(|x| c64::new(x, 0.0));
+
+            let exp = m.eigenvalues_expert(balance, false, true).unwrap();
+            assert!(exp.solution.left_vectors.is_none());
+            assert_eq!(exp.rcond_values.dim(), n);
+            assert_eq!(exp.rcond_vectors.dim(), n);
+            assert!(exp.ilo <= exp.ihi && exp.ihi <= n);
+            assert_eq!(exp.permutation.len(), n);
+            for j in (0..exp.ilo).chain(exp.ihi..n) {
+                assert!(exp.permutation[j] < n);
+                assert_eq!(exp.scale[j], 1.0);
+            }
+            assert!(exp.rcond_values.iter().all(|&r| r > 0.0 && r <= 1.0 + 1e-8));
+
+            // The eigenvectors are those of the original matrix.
+            let vr = exp.solution.right_vectors_complex().unwrap();
+            for (j, &lambda) in exp.solution.values.iter().enumerate() {
+                let x = vr.column(j);
+                assert_eq_within_tol!(mc.dot(&x), x.mapv(|v| v * lambda), 1e-8);
+            }
+        }
+    }
+}
+
+#[test]
+fn eig_expert_condition() {
+    // The eigenvalues of a normal matrix are perfectly conditioned.
+    let m = arr2(&[[2.0f64, 1.0], [1.0, 2.0]]);
+    let exp = m.eigenvalues_expert(Balance::None, false, false).unwrap();
+    assert_eq_within_tol!(exp.rcond_values, arr1(&[1.0, 1.0]), 1e-8);
+
+    // The eigenvalues of a nearly defective matrix are not.
+    let m = arr2(&[[1.0f64, 1e4], [0.0, 1.0 + 1e-4]]);
+    let exp = m.eigenvalues_expert(Balance::None, false, false).unwrap();
+    assert!(exp.rcond_values.iter().all(|&r| r < 1e-6));
+}
+
+#[test]
+fn eig_expert_balance() {
+    let m = arr2(&[[1.0f64, 1e6], [1e-6, 1.0]]);
+
+    let exp = m.eigenvalues_expert(Balance::Scale, false, false).unwrap();
+    assert!(exp.scale.iter().any(|&s| s != 1.0));
+    assert_eq!((exp.ilo, exp.ihi), (0, 2));
+    assert_eq!(exp.permutation, vec![0, 1]);
+
+    let exp = m.eigenvalues_expert(Balance::None, false, false).unwrap();
+    assert_eq!(exp.scale, arr1(&[1.0, 1.0]));
+    assert_eq!(exp.norm, 1e6 + 1.0);
+}
+
+#[test]
+fn eig_expert_permute() {
+    // The first row isolates the eigenvalue 3, which is moved to the
+    // bottom by interchanging the first and last rows and columns.
+    let m = arr2(&[[3.0f64, 0.0, 0.0],
+                   [6.0, 1.0, 4.0],
+                   [7.0, 2.0, 5.0]]);
+    let exp = m.eigenvalues_expert(Balance::Permute, false, false).unwrap();
+    assert_eq!((exp.ilo, exp.ihi), (0, 2));
+    assert_eq!(exp.permutation, vec![0, 1, 0]);
+    assert_eq!(exp.scale, arr1(&[1.0, 1.0, 1.0]));
+}
@@ -0,0 +1,140 @@
+#[macro_use]
+extern crate linxal;
+#[macro_use]
+extern crate ndarray;
+extern crate rand;
+
+use linxal::types::{LinxalMatrix, LinxalScalar, LinxalImplScalar, c32, c64};
+use linxal::types::error::EigenError;
+use linxal::eigenvalues::GeneralizedSchur;
+use linxal::generate::{RandomGeneral, RandomSemiPositive, RandomSymmetric};
+use ndarray::{Array, Ix2, arr1, arr2};
+use rand::thread_rng;
+
+fn generalized_residual_generic<T>()
+    where T: LinxalScalar + LinxalImplScalar<Complex = T>
+{
+    let mut rng = thread_rng();
+    for n in 1..8 {
+        let a: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let b: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+
+        let sol = a.generalized_eigenvalues_vectors(&b, true, true).unwrap();
+        let vl = sol.left_vectors.unwrap();
+        let vr = sol.right_vectors.unwrap();
+
+        for j in 0..n {
+            let (alpha, beta) = (sol.alpha[j], sol.beta[j]);
+            let x = vr.slice(s![.., j as isize..(j + 1) as isize]);
+            let y = vl.slice(s![.., j as isize..(j + 1) as isize]);
+
+            // beta * A * x = alpha * B * x
+            assert_eq_within_tol!(a.dot(&x).mapv(|v| v * beta),
+                                  b.dot(&x).mapv(|v| v * alpha),
+                                  1e-3.into());
+
+            // beta * y^H * A = alpha * y^H * B
+            assert_eq_within_tol!(y.conj_t().dot(&a).mapv(|v| v * beta),
+                                  y.conj_t().dot(&b).mapv(|v| v * alpha),
+                                  1e-3.into());
+        }
+    }
+}
+
+#[test]
+fn generalized_residual() {
+    generalized_residual_generic::<c32>();
+    generalized_residual_generic::<c64>();
+}
+
+#[test]
+fn generalized_symmetric_definite() {
+    // With symmetric `A` and positive definite `B`, the eigenvalues
+    // are real and the eigenvectors are not packed.
+    let mut rng = thread_rng();
+    for n in 1..8 {
+        let a: Array<f64, Ix2> = RandomSymmetric::new(n, &mut rng).generate().unwrap();
+        let b: Array<f64, Ix2> = RandomSemiPositive::new(n, &mut rng).generate().unwrap();
+
+        let sol = a.generalized_eigenvalues_vectors(&b, false, true).unwrap();
+        assert!(sol.left_vectors.is_none());
+        let values = sol.values();
+        let vr = sol.right_vectors.unwrap();
+
+        for (j, value) in values.into_iter().enumerate() {
+            let lambda = value.unwrap();
+            assert!(lambda.im.abs() < 1e-8);
+
+            let x = vr.column(j);
+            assert_eq_within_tol!(a.dot(&x), b.dot(&x) * lambda.re, 1e-8);
+        }
+    }
+}
+
+#[test]
+fn generalized_infinite() {
+    // The singular `B` gives one infinite eigenvalue.
+    let a = arr2(&[[1.0f64, 0.0, 0.0],
+                   [0.0, 2.0, 0.0],
+                   [0.0, 0.0, 3.0]]);
+    let b = arr2(&[[1.0f64, 0.0, 0.0],
+                   [0.0, 1.0, 0.0],
+                   [0.0, 0.0, 0.0]]);
+
+    let sol = a.generalized_eigenvalues(&b).unwrap();
+    let values = sol.values();
+    assert_eq!(values.iter().filter(|v| v.is_none()).count(), 1);
+
+    let mut finite: Vec<_> = values.iter().filter_map(|v| v.map(|x| x.re)).collect();
+    finite.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    assert_eq_within_tol!(arr1(&finite), arr1(&[1.0, 2.0]), 1e-12);
+}
+
+fn generalized_schur_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for n in 1..8 {
+        let a: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let b: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let mut schur = GeneralizedSchur::compute(&a, &b).unwrap();
+
+        let (a_r, b_r) = schur.reconstruct();
+        assert_eq_within_tol!(a_r, a, 1e-3.into());
+        assert_eq_within_tol!(b_r, b, 1e-3.into());
+
+        // Move the eigenvalues inside the unit circle to the top.
+        let inside = |alpha: T::Complex, beta: T::Complex| alpha.mag() < beta.mag();
+        let count = schur.alpha().iter().zip(schur.beta().iter())
+            .filter(|&(&alpha, &beta)| inside(alpha, beta)).count();
+        let k = schur.reorder(inside).unwrap();
+        assert_eq!(k, count);
+
+        let (a_r, b_r) = schur.reconstruct();
+        assert_eq_within_tol!(a_r, a, 1e-3.into());
+        assert_eq_within_tol!(b_r, b, 1e-3.into());
+
+        // The leading columns of `Z` span a deflating subspace.
+        let k = k as isize;
+        let z1 = schur.z().slice(s![.., ..k]);
+        let q1 = schur.q().slice(s![.., ..k]);
+        assert_eq_within_tol!(a.dot(&z1), q1.dot(&schur.s().slice(s![..k, ..k])), 1e-3.into());
+        assert_eq_within_tol!(b.dot(&z1), q1.dot(&schur.t().slice(s![..k, ..k])), 1e-3.into());
+    }
+}
+
+#[test]
+fn generalized_schur() {
+    generalized_schur_generic::<f32>();
+    generalized_schur_generic::<f64>();
+    generalized_schur_generic::<c32>();
+    generalized_schur_generic::<c64>();
+}
+
+#[test]
+fn generalized_inconsistent() {
+    let a: Array<f64, Ix2> = Array::eye(3);
+    let b: Array<f64, Ix2> = Array::eye(2);
+    assert_eq!(a.generalized_eigenvalues(&b).unwrap_err(), EigenError::InconsistentDimensions);
+
+    let c: Array<f64, Ix2> = Array::zeros((2, 3));
+    assert_eq!(c.generalized_eigenvalues(&c).unwrap_err(), EigenError::NotSquare);
+}
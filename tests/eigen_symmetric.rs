@@ -1,9 +1,15 @@
 #[macro_use]
 extern crate linxal;
+#[macro_use]
 extern crate ndarray;
+extern crate rand;
 
-use ndarray::{arr1, arr2, Array, Ix2};
-use linxal::types::{LinxalMatrix, Symmetric};
+use ndarray::{arr1, arr2, Array, Axis, Ix2};
+use rand::thread_rng;
+use linxal::types::{LinxalMatrix, LinxalScalar, Symmetric, c32, c64};
+use linxal::types::error::EigenError;
+use linxal::eigenvalues::{EigenRange, SymEigen, SymEigenMethod};
+use linxal::generate::RandomSymmetric;
 
 #[test]
 fn try_eig() {
@@ -27,3 +33,110 @@ fn test_eig_access() {
 
     assert_eq_within_tol!(upper_only_ev, full_ev, 1e-5);
 }
+
+fn eig_range_generic<T: LinxalScalar>(uplo: Symmetric) {
+    let mut rng = thread_rng();
+    for n in 4..10 {
+        let m: Array<T, Ix2> = RandomSymmetric::new(n, &mut rng).generate().unwrap();
+        let full = m.symmetric_eigenvalues(uplo).unwrap();
+
+        // Select by index.
+        let sol = m.symmetric_eigenvalues_vectors_range(uplo, EigenRange::Indices(1, n - 2)).unwrap();
+        assert_eq_within_tol!(sol.values, full.slice(s![1..(n - 1) as isize]), 1e-3.into());
+
+        let z = sol.right_vectors.unwrap();
+        assert_eq!(z.dim(), (n, n - 2));
+        for (j, &lambda) in sol.values.iter().enumerate() {
+            let x = z.column(j);
+            assert_eq_within_tol!(m.dot(&x), x.mapv(|v| v * T::from_real(lambda)), 1e-3.into());
+        }
+
+        // Select by value, between the eigenvalues.
+        let two: T::RealPart = 2.0.into();
+        let vl = (full[0] + full[1]) / two;
+        let vu = (full[2] + full[3]) / two;
+        let values = m.symmetric_eigenvalues_range(uplo, EigenRange::Values(vl, vu)).unwrap();
+        assert_eq_within_tol!(values, full.slice(s![1..3]), 1e-3.into());
+
+        // Selecting everything matches the full spectrum.
+        let values = m.symmetric_eigenvalues_range(uplo, EigenRange::All).unwrap();
+        assert_eq_within_tol!(values, full, 1e-3.into());
+    }
+}
+
+#[test]
+fn eig_range() {
+    eig_range_generic::<f32>(Symmetric::Upper);
+    eig_range_generic::<f32>(Symmetric::Lower);
+    eig_range_generic::<f64>(Symmetric::Upper);
+    eig_range_generic::<f64>(Symmetric::Lower);
+    eig_range_generic::<c32>(Symmetric::Upper);
+    eig_range_generic::<c32>(Symmetric::Lower);
+    eig_range_generic::<c64>(Symmetric::Upper);
+    eig_range_generic::<c64>(Symmetric::Lower);
+}
+
+#[test]
+fn eig_range_invalid() {
+    let m: Array<f64, Ix2> = Array::eye(3);
+    assert_eq!(m.symmetric_eigenvalues_range(Symmetric::Upper, EigenRange::Indices(2, 3)).unwrap_err(),
+               EigenError::InvalidRange);
+    assert_eq!(m.symmetric_eigenvalues_range(Symmetric::Upper, EigenRange::Indices(2, 1)).unwrap_err(),
+               EigenError::InvalidRange);
+    assert_eq!(m.symmetric_eigenvalues_range(Symmetric::Upper, EigenRange::Values(1.0, 1.0)).unwrap_err(),
+               EigenError::InvalidRange);
+
+    // An interval without eigenvalues is valid, but empty.
+    let values = m.symmetric_eigenvalues_range(Symmetric::Upper, EigenRange::Values(2.0, 3.0)).unwrap();
+    assert_eq!(values.len(), 0);
+}
+
+#[test]
+fn eig_range_boundary() {
+    // The interval includes `vl`, but excludes `vu`.
+    let m = arr2(&[[0.0f64, 0.0, 0.0],
+                   [0.0, 1.0, 0.0],
+                   [0.0, 0.0, 2.0]]);
+    let values = m.symmetric_eigenvalues_range(Symmetric::Upper, EigenRange::Values(0.0, 2.0)).unwrap();
+    assert_eq!(values, arr1(&[0.0, 1.0]));
+
+    let values = m.symmetric_eigenvalues_range(Symmetric::Upper, EigenRange::Values(1.0, 2.0)).unwrap();
+    assert_eq!(values, arr1(&[1.0]));
+}
+
+fn eig_method_generic<T: LinxalScalar>(method: SymEigenMethod) {
+    let mut rng = thread_rng();
+    for &n in &[1, 2, 5, 9, 120] {
+        let m: Array<T, Ix2> = RandomSymmetric::new(n, &mut rng).generate().unwrap();
+        let reference = SymEigen::compute_with_method(&m, Symmetric::Upper, false,
+                                                      SymEigenMethod::QRIteration).unwrap();
+
+        let sol = m.symmetric_eigenvalues_vectors_with_method(Symmetric::Upper, method).unwrap();
+        assert_eq_within_tol!(sol.values, reference.values, 1e-3.into());
+
+        // The eigenvectors are orthonormal and diagonalize the matrix.
+        let z = sol.right_vectors.unwrap();
+        let eye: Array<T, Ix2> = Array::eye(n);
+        assert_eq_within_tol!(z.conj_t().dot(&z), eye, 1e-3.into());
+
+        let mut z_lambda = z.clone();
+        for (j, mut col) in z_lambda.axis_iter_mut(Axis(1)).enumerate() {
+            let lambda = T::from_real(sol.values[j]);
+            col.mapv_inplace(|x| x * lambda);
+        }
+        assert_eq_within_tol!(m.dot(&z), z_lambda, 1e-3.into());
+    }
+}
+
+#[test]
+fn eig_method() {
+    for &method in &[SymEigenMethod::Auto,
+                     SymEigenMethod::QRIteration,
+                     SymEigenMethod::DivideAndConquer,
+                     SymEigenMethod::MRRR] {
+        eig_method_generic::<f32>(method);
+        eig_method_generic::<f64>(method);
+        eig_method_generic::<c32>(method);
+        eig_method_generic::<c64>(method);
+    }
+}
@@ -0,0 +1,77 @@
+#[macro_use]
+extern crate linxal;
+extern crate ndarray;
+extern crate rand;
+
+use linxal::types::{LinxalMatrix, LinxalScalar, Symmetric, c32, c64};
+use linxal::types::error::EigenError;
+use linxal::eigenvalues::GeneralizedProblem;
+use linxal::generate::{RandomSemiPositive, RandomSymmetric};
+use ndarray::{Array, Axis, Ix2};
+use rand::thread_rng;
+
+fn sym_generalized_generic<T: LinxalScalar>(uplo: Symmetric, problem: GeneralizedProblem) {
+    let mut rng = thread_rng();
+    for n in 1..8 {
+        let a: Array<T, Ix2> = RandomSymmetric::new(n, &mut rng).generate().unwrap();
+        let b: Array<T, Ix2> = RandomSemiPositive::new(n, &mut rng).generate().unwrap();
+
+        let sol = a.symmetric_generalized_eigenvalues_vectors(&b, uplo, problem).unwrap();
+        let values = sol.values;
+        let z = sol.right_vectors.unwrap();
+
+        // The eigenvalues are in ascending order.
+        for j in 1..n {
+            assert!(values[j - 1] <= values[j]);
+        }
+
+        let mut z_lambda = z.clone();
+        for (j, mut col) in z_lambda.axis_iter_mut(Axis(1)).enumerate() {
+            let lambda = T::from_real(values[j]);
+            col.mapv_inplace(|x| x * lambda);
+        }
+
+        let eye: Array<T, Ix2> = Array::eye(n);
+        match problem {
+            GeneralizedProblem::AxLambdaBx => {
+                assert_eq_within_tol!(a.dot(&z), b.dot(&z_lambda), 1e-3.into());
+                assert_eq_within_tol!(z.conj_t().dot(&b).dot(&z), eye, 1e-3.into());
+            }
+            GeneralizedProblem::ABxLambdaX => {
+                assert_eq_within_tol!(a.dot(&b).dot(&z), z_lambda, 1e-3.into());
+                assert_eq_within_tol!(z.conj_t().dot(&b).dot(&z), eye, 1e-3.into());
+            }
+            GeneralizedProblem::BAxLambdaX => {
+                let b_inv = b.inverse().unwrap();
+                assert_eq_within_tol!(b.dot(&a).dot(&z), z_lambda, 1e-3.into());
+                assert_eq_within_tol!(z.conj_t().dot(&b_inv).dot(&z), eye, 1e-3.into());
+            }
+        }
+
+        // The eigenvalues alone match the full solution.
+        let values_only = a.symmetric_generalized_eigenvalues(&b, uplo, problem).unwrap();
+        assert_eq_within_tol!(values_only, values, 1e-3.into());
+    }
+}
+
+#[test]
+fn sym_generalized() {
+    for &uplo in &[Symmetric::Upper, Symmetric::Lower] {
+        for &problem in &[GeneralizedProblem::AxLambdaBx,
+                          GeneralizedProblem::ABxLambdaX,
+                          GeneralizedProblem::BAxLambdaX] {
+            sym_generalized_generic::<f32>(uplo, problem);
+            sym_generalized_generic::<f64>(uplo, problem);
+            sym_generalized_generic::<c32>(uplo, problem);
+            sym_generalized_generic::<c64>(uplo, problem);
+        }
+    }
+}
+
+#[test]
+fn sym_generalized_not_positive_definite() {
+    let a: Array<f64, Ix2> = Array::eye(3);
+    let b: Array<f64, Ix2> = -Array::eye(3);
+    let res = a.symmetric_generalized_eigenvalues(&b, Symmetric::Upper, GeneralizedProblem::AxLambdaBx);
+    assert_eq!(res.unwrap_err(), EigenError::NotPositiveDefinite);
+}
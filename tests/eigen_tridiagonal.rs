@@ -0,0 +1,121 @@
+#[macro_use]
+extern crate linxal;
+#[macro_use]
+extern crate ndarray;
+extern crate rand;
+
+use ndarray::{Array, Axis, Ix1, Ix2};
+use rand::thread_rng;
+use linxal::types::{LinxalMatrix, LinxalScalar, Symmetric, c32, c64};
+use linxal::types::error::EigenError;
+use linxal::eigenvalues::{EigenRange, SymEigenMethod, TridiagonalEigen};
+use linxal::generate::RandomSymmetric;
+
+/// Return the diagonal and off-diagonal of the (n x n) second
+/// difference matrix, with eigenvalues `2 - 2 cos(k pi / (n + 1))`.
+fn second_difference<R: From<f32>>(n: usize) -> (Array<R, Ix1>, Array<R, Ix1>) {
+    (Array::from_shape_fn(n, |_| R::from(2.0)),
+     Array::from_shape_fn(n.saturating_sub(1), |_| R::from(-1.0)))
+}
+
+fn second_difference_values(n: usize) -> Array<f64, Ix1> {
+    Array::from_shape_fn(n, |k| {
+        2.0 - 2.0 * ((k + 1) as f64 * std::f64::consts::PI / (n + 1) as f64).cos()
+    })
+}
+
+fn tridiagonal_method_generic<T: LinxalScalar>(method: SymEigenMethod) {
+    for &n in &[0, 1, 2, 5, 9, 120] {
+        let (d, e) = second_difference::<T::RealPart>(n);
+        let sol = <T as TridiagonalEigen>::compute_with_method(&d, &e, true, method).unwrap();
+
+        let expected = second_difference_values(n).mapv(|x| T::RealPart::from(x as f32));
+        assert_eq_within_tol!(sol.values, expected, 1e-3.into());
+
+        // Form the full matrix to check the eigenvectors.
+        let mut t: Array<T::RealPart, Ix2> = Array::zeros((n, n));
+        for i in 0..n {
+            t[(i, i)] = d[i];
+            if i + 1 < n {
+                t[(i, i + 1)] = e[i];
+                t[(i + 1, i)] = e[i];
+            }
+        }
+
+        let z = sol.right_vectors.unwrap();
+        assert_eq_within_tol!(z.t().dot(&z), Array::eye(n), 1e-3.into());
+
+        let mut z_lambda = z.clone();
+        for (j, mut col) in z_lambda.axis_iter_mut(Axis(1)).enumerate() {
+            let lambda = sol.values[j];
+            col.mapv_inplace(|x| x * lambda);
+        }
+        assert_eq_within_tol!(t.dot(&z), z_lambda, 1e-3.into());
+    }
+}
+
+#[test]
+fn tridiagonal_method() {
+    for &method in &[SymEigenMethod::Auto,
+                     SymEigenMethod::QRIteration,
+                     SymEigenMethod::DivideAndConquer,
+                     SymEigenMethod::MRRR] {
+        tridiagonal_method_generic::<f32>(method);
+        tridiagonal_method_generic::<f64>(method);
+        tridiagonal_method_generic::<c32>(method);
+        tridiagonal_method_generic::<c64>(method);
+    }
+}
+
+fn tridiagonal_reduction_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for n in 0..10 {
+        let a: Array<T, Ix2> = RandomSymmetric::new(n, &mut rng).generate().unwrap();
+        let tri = a.tridiagonal(Symmetric::Lower).unwrap();
+
+        let sol = <T as TridiagonalEigen>::compute(tri.diag(), tri.off_diag(), false).unwrap();
+        let expected = a.symmetric_eigenvalues(Symmetric::Lower).unwrap();
+        assert_eq_within_tol!(sol.values, expected, 1e-3.into());
+        assert!(sol.right_vectors.is_none());
+    }
+}
+
+#[test]
+fn tridiagonal_reduction() {
+    tridiagonal_reduction_generic::<f32>();
+    tridiagonal_reduction_generic::<f64>();
+    tridiagonal_reduction_generic::<c32>();
+    tridiagonal_reduction_generic::<c64>();
+}
+
+#[test]
+fn tridiagonal_range() {
+    let n = 20;
+    let (d, e) = second_difference::<f64>(n);
+    let expected = second_difference_values(n);
+
+    let sol = <f64 as TridiagonalEigen>::compute_range(&d, &e, EigenRange::Indices(3, 7), true).unwrap();
+    assert_eq_within_tol!(sol.values, expected.slice(s![3..8]), 1e-8);
+    assert_eq!(sol.right_vectors.unwrap().dim(), (n, 5));
+
+    let sol = <f64 as TridiagonalEigen>::compute_range(&d, &e, EigenRange::Values(1.0, 3.0), false).unwrap();
+    let inside: Vec<f64> = expected.iter().cloned().filter(|&x| (1.0..3.0).contains(&x)).collect();
+    assert_eq_within_tol!(sol.values, Array::from_vec(inside), 1e-8);
+
+    // The interval includes `vl`, but excludes `vu`.
+    let d = Array::from_vec(vec![0.0f64, 1.0, 2.0]);
+    let e = Array::from_vec(vec![0.0f64, 0.0]);
+    let sol = <f64 as TridiagonalEigen>::compute_range(&d, &e, EigenRange::Values(0.0, 2.0), false).unwrap();
+    assert_eq!(sol.values, Array::from_vec(vec![0.0, 1.0]));
+
+    let r = <f64 as TridiagonalEigen>::compute_range(&d, &e, EigenRange::Indices(5, 20), false);
+    assert_eq!(r.err().unwrap(), EigenError::InvalidRange);
+}
+
+#[test]
+fn tridiagonal_inconsistent() {
+    let d = Array::from_elem(4, 1.0f64);
+    let e = Array::from_elem(4, 1.0f64);
+    let r = <f64 as TridiagonalEigen>::compute(&d, &e, false);
+    assert_eq!(r.err().unwrap(), EigenError::InconsistentDimensions);
+}
@@ -0,0 +1,79 @@
+#[macro_use]
+extern crate linxal;
+extern crate ndarray;
+extern crate rand;
+
+use linxal::types::{LinxalMatrix, LinxalScalar, c32, c64};
+use linxal::types::error::ReductionError;
+use linxal::generate::RandomGeneral;
+use ndarray::{Array, Ix1, Ix2, arr1, arr2};
+use rand::thread_rng;
+
+fn hessenberg_reconstruct_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for n in 0..10 {
+        let a: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let hess = a.hessenberg().unwrap();
+
+        assert_eq_within_tol!(hess.reconstruct(), a, 1e-3.into());
+        assert_eq_within_tol!(hess.q().conj_t().dot(hess.q()), Array::eye(n), 1e-3.into());
+
+        for ((i, j), x) in hess.h().indexed_iter() {
+            if i > j + 1 {
+                assert!(x.is_zero());
+            }
+        }
+    }
+}
+
+#[test]
+fn hessenberg_reconstruct() {
+    hessenberg_reconstruct_generic::<f32>();
+    hessenberg_reconstruct_generic::<f64>();
+    hessenberg_reconstruct_generic::<c32>();
+    hessenberg_reconstruct_generic::<c64>();
+}
+
+fn hessenberg_shifted_solve_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for n in 1..10 {
+        let a: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let b: Array<T, Ix1> = RandomGeneral::new(n, 1, &mut rng).generate().unwrap()
+            .into_shape(n).unwrap();
+        let hess = a.hessenberg().unwrap();
+
+        for &s in &[-1.5, 0.0, 0.5, 2.0] {
+            let sigma = T::from_real(s.into());
+            let x = hess.solve_shifted(sigma, &b).unwrap();
+
+            let mut shifted = a.clone();
+            for i in 0..n {
+                shifted[(i, i)] = shifted[(i, i)] - sigma;
+            }
+            assert_eq_within_tol!(shifted.dot(&x), b, 1e-3.into());
+        }
+    }
+}
+
+#[test]
+fn hessenberg_shifted_solve() {
+    hessenberg_shifted_solve_generic::<f32>();
+    hessenberg_shifted_solve_generic::<f64>();
+    hessenberg_shifted_solve_generic::<c32>();
+    hessenberg_shifted_solve_generic::<c64>();
+}
+
+#[test]
+fn hessenberg_shifted_singular() {
+    let a = arr2(&[[2.0f64, 1.0], [0.0, 3.0]]);
+    let hess = a.hessenberg().unwrap();
+
+    let r = hess.solve_shifted(3.0, &arr1(&[1.0, 1.0]));
+    assert_eq!(r.err().unwrap(), ReductionError::Singular);
+}
+
+#[test]
+fn hessenberg_nonsquare() {
+    let a: Array<f32, Ix2> = Array::zeros((3, 2));
+    assert_eq!(a.hessenberg().err().unwrap(), ReductionError::NotSquare);
+}
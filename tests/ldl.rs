@@ -0,0 +1,109 @@
+#[macro_use]
+extern crate linxal;
+
+extern crate ndarray;
+extern crate num_traits;
+extern crate rand;
+
+use ndarray::{Array, Ix2, arr1, arr2};
+use rand::thread_rng;
+use linxal::types::{LinxalScalar, LinxalMatrix, Symmetric, c32, c64};
+use linxal::types::error::LDLError;
+use linxal::factorization::Inertia;
+use linxal::generate::RandomSemiPositive;
+
+fn ldl_indefinite_generic<T: LinxalScalar>(uplo: Symmetric) {
+    let mut rng = thread_rng();
+    for n in 2..8 {
+        for k in 1..n {
+            // The difference of two semi-definite matrices of
+            // complementary rank has exactly `k` positive and `n - k`
+            // negative eigenvalues.
+            let p: Array<T, Ix2> = RandomSemiPositive::new(n, &mut rng).rank(k).generate().unwrap();
+            let q: Array<T, Ix2> = RandomSemiPositive::new(n, &mut rng).rank(n - k).generate().unwrap();
+            let m = &p - &q;
+
+            let ldl = m.ldl(uplo).unwrap();
+            assert_eq!(ldl.uplo(), uplo);
+            assert_eq!(ldl.inertia(),
+                       Inertia {
+                           positive: k,
+                           negative: n - k,
+                           zero: 0,
+                       });
+
+            // The determinant matches the LU-determinant.
+            let det = m.det().unwrap();
+            assert_eq_within_tol!(arr1(&[T::from_real(ldl.det()) / det]),
+                                  arr1(&[T::one()]),
+                                  1e-3.into());
+
+            // The solution to the system matches the original RHS.
+            let b: Array<T, Ix2> = Array::linspace(1.0f32, 2.0, n * 2)
+                .into_shape((n, 2)).unwrap()
+                .mapv(|x| T::from_real(x.into()));
+            let x = ldl.solve_multi(&b).unwrap();
+            assert_eq_within_tol!(m.dot(&x), b, 1e-3.into());
+
+            let x0 = ldl.solve(&b.column(0)).unwrap();
+            assert_eq_within_tol!(x0, x.column(0), 1e-3.into());
+
+            // The inverse is filled in both triangles.
+            let inv = ldl.inverse().unwrap();
+            let eye: Array<T, Ix2> = Array::eye(n);
+            assert_eq_within_tol!(m.dot(&inv), eye, 1e-3.into());
+        }
+    }
+}
+
+#[test]
+fn ldl_indefinite() {
+    ldl_indefinite_generic::<f32>(Symmetric::Upper);
+    ldl_indefinite_generic::<f32>(Symmetric::Lower);
+    ldl_indefinite_generic::<f64>(Symmetric::Upper);
+    ldl_indefinite_generic::<f64>(Symmetric::Lower);
+    ldl_indefinite_generic::<c32>(Symmetric::Upper);
+    ldl_indefinite_generic::<c32>(Symmetric::Lower);
+    ldl_indefinite_generic::<c64>(Symmetric::Upper);
+    ldl_indefinite_generic::<c64>(Symmetric::Lower);
+}
+
+#[test]
+fn ldl_two_by_two_pivot() {
+    // A zero diagonal forces a 2 x 2 pivot block.
+    let m = arr2(&[[0.0f64, 2.0], [2.0, 0.0]]);
+    for &uplo in &[Symmetric::Upper, Symmetric::Lower] {
+        let ldl = m.ldl(uplo).unwrap();
+        assert_eq!(ldl.inertia(),
+                   Inertia {
+                       positive: 1,
+                       negative: 1,
+                       zero: 0,
+                   });
+        assert_eq_within_tol!(arr1(&[ldl.det()]), arr1(&[-4.0f64]), 1e-12);
+
+        let inv = ldl.inverse().unwrap();
+        assert_eq_within_tol!(inv, arr2(&[[0.0f64, 0.5], [0.5, 0.0]]), 1e-12);
+    }
+}
+
+#[test]
+fn ldl_singular() {
+    let m = arr2(&[[1.0f64, 1.0], [1.0, 1.0]]);
+    let ldl = m.ldl(Symmetric::Upper).unwrap();
+    assert_eq!(ldl.inertia(),
+               Inertia {
+                   positive: 1,
+                   negative: 0,
+                   zero: 1,
+               });
+    assert_eq!(ldl.det(), 0.0);
+    assert_eq!(ldl.solve(&arr1(&[1.0, 2.0])).err(), Some(LDLError::Singular));
+    assert_eq!(ldl.inverse().err(), Some(LDLError::Singular));
+}
+
+#[test]
+fn ldl_fail_not_square() {
+    let m: Array<f64, Ix2> = Array::zeros((3, 4));
+    assert_eq!(m.ldl(Symmetric::Upper).err(), Some(LDLError::NotSquare));
+}
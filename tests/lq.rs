@@ -0,0 +1,113 @@
+#[macro_use]
+extern crate linxal;
+#[macro_use]
+extern crate ndarray;
+extern crate num_traits;
+
+use std::cmp;
+use ndarray::{Array, Ix2, ShapeBuilder, arr1, arr2};
+use num_traits::Zero;
+use linxal::factorization::{LQ, QRError};
+use linxal::types::{LinxalMatrix, LinxalScalar, c32, c64};
+
+fn min_norm_generic<T: LinxalScalar>() {
+    let m: Array<T, Ix2> = arr2(&[[1.0f32, 2.0, 0.0, -1.0],
+                                  [0.0, 1.0, 3.0, 2.0]])
+        .mapv(|x| T::from_real(x.into()));
+    let b: Array<T, Ix2> = arr2(&[[4.0f32, 1.0],
+                                  [-2.0, 0.5]])
+        .mapv(|x| T::from_real(x.into()));
+
+    let lq = LQ::compute(&m).unwrap();
+    assert_eq!(lq.rows(), 2);
+    assert_eq!(lq.cols(), 4);
+
+    let x = lq.solve_min_norm_multi(&b).unwrap();
+    assert_eq!(x.dim(), (4, 2));
+
+    // The solution satisfies the system exactly, and matches the
+    // minimum-norm least squares solution.
+    assert_eq_within_tol!(m.dot(&x), b, 1e-4.into());
+    let truth = m.multi_least_squares(&b, None).unwrap().solution;
+    assert_eq_within_tol!(x, truth, 1e-4.into());
+
+    let x0 = lq.solve_min_norm(&b.column(0)).unwrap();
+    assert_eq_within_tol!(x0, truth.column(0), 1e-4.into());
+}
+
+#[test]
+fn lq_min_norm() {
+    min_norm_generic::<f32>();
+    min_norm_generic::<f64>();
+    min_norm_generic::<c32>();
+    min_norm_generic::<c64>();
+}
+
+#[test]
+fn lq_min_norm_column_major() {
+    let m = arr2(&[[1.0, 2.0, 3.0],
+                   [4.0, 5.0, 6.0]]);
+    let mut mf = Array::zeros((2, 3).f());
+    mf.assign(&m);
+    let b = arr1(&[1.0, 2.0]);
+
+    let x = LQ::compute(&mf).unwrap().solve_min_norm(&b).unwrap();
+    let truth = m.least_squares(&b, None).unwrap().solution;
+    assert_eq_within_tol!(x, truth, 1e-8);
+}
+
+#[test]
+fn lq_min_norm_overdetermined() {
+    let m = arr2(&[[1.0, 2.0],
+                   [3.0, 4.0],
+                   [5.0, 6.0]]);
+    let b = arr1(&[1.0, 2.0, 3.0]);
+
+    let lq = LQ::compute(&m).unwrap();
+    assert_eq!(lq.solve_min_norm(&b).unwrap_err(), QRError::Overdetermined);
+}
+
+fn lq_generic<T: LinxalScalar>(m: &Array<T, Ix2>) {
+    let lq = m.lq().unwrap();
+    let (rows, cols) = m.dim();
+    let p = cmp::min(rows, cols);
+
+    let l = lq.l();
+    let q = lq.q();
+    assert_eq!(l.dim(), (rows, p));
+    assert_eq!(q.dim(), (p, cols));
+
+    // L is lower triangular.
+    for ((i, j), x) in l.indexed_iter() {
+        if j > i {
+            assert_eq!(x.mag(), T::RealPart::zero());
+        }
+    }
+
+    // The rows of the full Q are orthonormal.
+    let q_full = lq.qk(cols).unwrap();
+    let qqh = q_full.dot(&q_full.conj_t());
+    let eye: Array<T, Ix2> = Array::eye(cols);
+    assert_eq_within_tol!(qqh, eye, 1e-4.into());
+    assert_eq_within_tol!(q_full.slice(s![..p as isize, ..]), q, 1e-4.into());
+
+    assert_eq_within_tol!(lq.reconstruct(), m, 1e-4.into());
+    assert_eq_within_tol!(l.dot(&q), m, 1e-4.into());
+}
+
+#[test]
+fn lq_factors() {
+    fn run<T: LinxalScalar>() {
+        let m: Array<T, Ix2> = arr2(&[[1.0f32, 2.0, 0.0, -1.0],
+                                      [0.0, 1.0, 3.0, 2.0],
+                                      [4.0, -2.0, 1.0, 1.0]])
+            .mapv(|x| T::from_real(x.into()));
+        lq_generic(&m);
+        lq_generic(&m.t().to_owned());
+    }
+
+    run::<f32>();
+    run::<f64>();
+    run::<c32>();
+    run::<c64>();
+}
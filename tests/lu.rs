@@ -4,9 +4,9 @@ extern crate linxal;
 extern crate ndarray;
 extern crate num_traits;
 
-use ndarray::{Array, ArrayBase, Data, Ix2};
+use ndarray::{Array, ArrayBase, Data, Ix2, arr1, arr2};
 use linxal::factorization::{LUError, LUFactors};
-use linxal::types::{LinxalMatrix};
+use linxal::types::{LinxalMatrix, Transpose, c32};
 use std::cmp;
 
 /// Check that all the properties of the lu factorization are
@@ -97,3 +97,52 @@ fn lu_perm_diag() {
 
     check_lu(&m, &lu.unwrap(), true);
 }
+
+#[test]
+fn lu_solve() {
+    let m = arr2(&[[2.0f32, 1.0, 0.0],
+                   [1.0, 3.0, 1.0],
+                   [0.0, -1.0, 4.0]]);
+    let lu = m.lu().unwrap();
+
+    let x = arr1(&[1.0f32, -2.0, 0.5]);
+
+    // A * x = b
+    let b = m.dot(&x);
+    let x1 = lu.solve(&b, Transpose::None).unwrap();
+    assert_eq_within_tol!(x1, x, 0.001);
+
+    // A^T * x = b
+    let bt = m.t().dot(&x);
+    let x2 = lu.solve_into(bt, Transpose::Transpose).unwrap();
+    assert_eq_within_tol!(x2, x, 0.001);
+
+    // Multiple right-hand sides, in a layout different from the
+    // factored matrix.
+    let xs = Array::linspace(-1.0f32, 1.0, 6).into_shape((2, 3)).unwrap().reversed_axes();
+    let bs = m.dot(&xs);
+    let x3 = lu.solve_multi(&bs, Transpose::None).unwrap();
+    assert_eq_within_tol!(x3, xs, 0.001);
+}
+
+#[test]
+fn lu_solve_complex() {
+    let m = arr2(&[[c32::new(1.0, 1.0), c32::new(0.0, 2.0)],
+                   [c32::new(-1.0, 0.0), c32::new(3.0, -1.0)]]);
+    let lu = m.lu().unwrap();
+
+    let x = arr1(&[c32::new(0.5, -1.0), c32::new(2.0, 1.0)]);
+
+    let b = m.conj_t().dot(&x);
+    let x1 = lu.solve(&b, Transpose::ConjTranspose).unwrap();
+    assert_eq_within_tol!(x1, x, 0.001);
+}
+
+#[test]
+fn lu_solve_bad_dims() {
+    let m = arr2(&[[2.0f32, 1.0], [1.0, 3.0]]);
+    let lu = m.lu().unwrap();
+
+    let b = arr1(&[1.0f32, 2.0, 3.0]);
+    assert_eq!(lu.solve(&b, Transpose::None).err().unwrap(), LUError::InconsistentDimensions);
+}
@@ -0,0 +1,249 @@
+#[macro_use]
+extern crate linxal;
+extern crate ndarray;
+extern crate rand;
+
+use ndarray::{Array, Ix2, arr1, arr2};
+use rand::thread_rng;
+use linxal::types::{LinxalMatrix, LinxalScalar, LinxalImplScalar, c32, c64};
+use linxal::norms::NormType;
+use linxal::solve_linear::SolveLinear;
+use linxal::matrix_equations::MatrixEquationError;
+use linxal::generate::{RandomGeneral, RandomSemiPositive};
+
+/// Return `a - shift * I`.
+fn shifted<T: LinxalScalar>(a: &Array<T, Ix2>, shift: T::RealPart) -> Array<T, Ix2> {
+    let mut s = a.clone();
+    for i in 0..a.rows() {
+        s[(i, i)] = s[(i, i)] - T::from_real(shift);
+    }
+    s
+}
+
+fn sylvester_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for &(m, n) in &[(0, 3), (1, 1), (3, 2), (2, 5), (7, 7)] {
+        let a: Array<T, Ix2> = RandomGeneral::new(m, m, &mut rng).generate().unwrap();
+        let b: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let c: Array<T, Ix2> = RandomGeneral::new(m, n, &mut rng).generate().unwrap();
+
+        // Separate the spectra of `A` and `-B`.
+        let a = shifted(&a, (-10.0).into());
+        let b = shifted(&b, (-10.0).into());
+
+        let x = a.sylvester(&b, &c).unwrap();
+        assert_eq_within_tol!(a.dot(&x) + x.dot(&b), c, 1e-3.into());
+    }
+}
+
+#[test]
+fn sylvester() {
+    sylvester_generic::<f32>();
+    sylvester_generic::<f64>();
+    sylvester_generic::<c32>();
+    sylvester_generic::<c64>();
+}
+
+fn lyapunov_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for n in 0..8 {
+        let a: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let q: Array<T, Ix2> = RandomSemiPositive::new(n, &mut rng).generate().unwrap();
+
+        // Shift the spectrum of `A` into the left half-plane.
+        let norm = a.norm(NormType::Frobenius).unwrap();
+        let a = shifted(&a, norm + 1.0.into());
+
+        let x = a.lyapunov(&q).unwrap();
+        let residual = a.dot(&x) + x.dot(&a.conj_t()) + &q;
+        assert_eq_within_tol!(residual, Array::zeros((n, n)), 1e-3.into());
+        assert_eq_within_tol!(x.conj_t(), x, 1e-3.into());
+    }
+}
+
+#[test]
+fn lyapunov() {
+    lyapunov_generic::<f32>();
+    lyapunov_generic::<f64>();
+    lyapunov_generic::<c32>();
+    lyapunov_generic::<c64>();
+}
+
+fn discrete_lyapunov_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for n in 0..8 {
+        let a: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let q: Array<T, Ix2> = RandomSemiPositive::new(n, &mut rng).generate().unwrap();
+
+        // Scale the spectrum of `A` into the unit circle.
+        let norm = a.norm(NormType::Frobenius).unwrap();
+        let a = a.mapv(|x| x / T::from_real(norm + 1.0.into()));
+
+        let x = a.discrete_lyapunov(&q).unwrap();
+        let residual = a.dot(&x).dot(&a.conj_t()) - &x + &q;
+        assert_eq_within_tol!(residual, Array::zeros((n, n)), 1e-3.into());
+        assert_eq_within_tol!(x.conj_t(), x, 1e-3.into());
+    }
+}
+
+#[test]
+fn discrete_lyapunov() {
+    discrete_lyapunov_generic::<f32>();
+    discrete_lyapunov_generic::<f64>();
+    discrete_lyapunov_generic::<c32>();
+    discrete_lyapunov_generic::<c64>();
+}
+
+#[test]
+fn lyapunov_scalar() {
+    // -2 x + 1 = 0
+    let x = arr2(&[[-1.0f64]]).lyapunov(&arr2(&[[1.0]])).unwrap();
+    assert_eq_within_tol!(x, arr2(&[[0.5]]), 1e-10);
+
+    // 0.25 x - x + 3 = 0
+    let x = arr2(&[[0.5f64]]).discrete_lyapunov(&arr2(&[[3.0]])).unwrap();
+    assert_eq_within_tol!(x, arr2(&[[4.0]]), 1e-10);
+}
+
+/// Return random weights `Q` (n x n) and `R` (m x m) for a Riccati
+/// equation, with `Q` positive definite.
+fn riccati_weights<T: LinxalScalar>(n: usize, m: usize) -> (Array<T, Ix2>, Array<T, Ix2>) {
+    let mut rng = thread_rng();
+    let q: Array<T, Ix2> = RandomSemiPositive::new(n, &mut rng).generate().unwrap();
+    let r: Array<T, Ix2> = RandomSemiPositive::new(m, &mut rng).generate().unwrap();
+    (q + &Array::eye(n), r + &Array::eye(m))
+}
+
+fn care_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for &(n, m) in &[(0, 1), (1, 1), (3, 1), (4, 2), (6, 3)] {
+        let a: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let b: Array<T, Ix2> = RandomGeneral::new(n, m, &mut rng).generate().unwrap();
+        let (q, r) = riccati_weights::<T>(n, m);
+
+        let sol = a.care(&b, &q, &r).unwrap();
+        let x = &sol.solution;
+        assert_eq_within_tol!(x.conj_t(), x, 1e-3.into());
+        assert_eq_within_tol!(arr1(&[sol.residual]), arr1(&[0.0.into()]), 1e-2.into());
+
+        let k = SolveLinear::compute_multi(&r, &b.conj_t().dot(x)).unwrap();
+        let residual = a.conj_t().dot(x) + x.dot(&a) - x.dot(&b).dot(&k) + &q;
+        assert_eq_within_tol!(residual, Array::zeros((n, n)), 1e-2.into());
+
+        assert_eq!(sol.closed_loop_eigenvalues.dim(), n);
+        assert!(sol.closed_loop_eigenvalues.iter().all(|v| v.re() < 0.0.into()));
+    }
+}
+
+#[test]
+fn care() {
+    care_generic::<f32>();
+    care_generic::<f64>();
+    care_generic::<c32>();
+    care_generic::<c64>();
+}
+
+fn dare_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for &(n, m) in &[(0, 1), (1, 1), (3, 1), (4, 2), (6, 3)] {
+        let a: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let b: Array<T, Ix2> = RandomGeneral::new(n, m, &mut rng).generate().unwrap();
+        let (q, r) = riccati_weights::<T>(n, m);
+
+        let sol = a.dare(&b, &q, &r).unwrap();
+        let x = &sol.solution;
+        assert_eq_within_tol!(x.conj_t(), x, 1e-3.into());
+
+        let s = b.conj_t().dot(x).dot(&b) + &r;
+        let k = SolveLinear::compute_multi(&s, &b.conj_t().dot(x).dot(&a)).unwrap();
+        let residual = a.conj_t().dot(x).dot(&a) - x - &a.conj_t().dot(x).dot(&b).dot(&k) + &q;
+        let scale = x.norm(NormType::Frobenius).unwrap() + 1.0.into();
+        assert_eq_within_tol!(residual.mapv(|v| v / T::from_real(scale)),
+                              Array::zeros((n, n)), 1e-3.into());
+
+        assert_eq!(sol.closed_loop_eigenvalues.dim(), n);
+        assert!(sol.closed_loop_eigenvalues.iter().all(|v| v.mag() < 1.0.into()));
+    }
+}
+
+#[test]
+fn dare() {
+    dare_generic::<f32>();
+    dare_generic::<f64>();
+    dare_generic::<c32>();
+    dare_generic::<c64>();
+}
+
+#[test]
+fn riccati_scalar() {
+    let one = arr2(&[[1.0f64]]);
+
+    // -x^2 + 1 = 0
+    let sol = arr2(&[[0.0f64]]).care(&one, &one, &one).unwrap();
+    assert_eq_within_tol!(sol.solution, arr2(&[[1.0]]), 1e-10);
+    assert_eq_within_tol!(sol.closed_loop_eigenvalues, arr1(&[c64::new(-1.0, 0.0)]), 1e-10);
+
+    // x^2 - x - 1 = 0, with the closed loop 1 - x / (1 + x)
+    let golden = (1.0 + 5.0f64.sqrt()) / 2.0;
+    let sol = one.dare(&one, &one, &one).unwrap();
+    assert_eq_within_tol!(sol.solution, arr2(&[[golden]]), 1e-10);
+    assert_eq_within_tol!(sol.closed_loop_eigenvalues,
+                          arr1(&[c64::new(1.0 / (1.0 + golden), 0.0)]), 1e-10);
+    assert!(sol.residual < 1e-10);
+}
+
+#[test]
+fn dare_singular() {
+    // A double integrator with a dead-beat mode: `A` is nilpotent.
+    let a = arr2(&[[0.0f64, 1.0], [0.0, 0.0]]);
+    let b = arr2(&[[0.0f64], [1.0]]);
+    let q = arr2(&[[1.0f64, 0.0], [0.0, 1.0]]);
+    let r = arr2(&[[1.0f64]]);
+
+    let sol = a.dare(&b, &q, &r).unwrap();
+    let x = &sol.solution;
+    assert_eq_within_tol!(x.t(), x, 1e-10);
+    assert!(sol.residual < 1e-10);
+
+    // X = diag(1, 2), and the optimal gain is zero, since `A` is
+    // already stable.
+    assert_eq_within_tol!(x, arr2(&[[1.0, 0.0], [0.0, 2.0]]), 1e-10);
+    assert_eq!(sol.closed_loop_eigenvalues.dim(), 2);
+    assert!(sol.closed_loop_eigenvalues.iter().all(|v| v.mag() < 1e-6));
+}
+
+#[test]
+fn riccati_not_stabilizable() {
+    // The unstable mode is not controllable.
+    let b = arr2(&[[0.0f64]]);
+    let q = arr2(&[[0.0f64]]);
+    let r = arr2(&[[1.0f64]]);
+    assert_eq!(arr2(&[[1.0f64]]).care(&b, &q, &r).err().unwrap(),
+               MatrixEquationError::NoStabilizingSolution);
+    assert_eq!(arr2(&[[2.0f64]]).dare(&b, &q, &r).err().unwrap(),
+               MatrixEquationError::NoStabilizingSolution);
+}
+
+#[test]
+fn lyapunov_singular() {
+    // The eigenvalues 1 and -1 sum to zero.
+    let a = arr2(&[[1.0f64, 0.0], [0.0, -1.0]]);
+    let q = arr2(&[[1.0, 0.0], [0.0, 1.0]]);
+    assert_eq!(a.lyapunov(&q).err().unwrap(), MatrixEquationError::Singular);
+}
+
+#[test]
+fn matrix_equations_bad_dimensions() {
+    let a: Array<f64, Ix2> = Array::eye(3);
+    let b: Array<f64, Ix2> = Array::eye(2);
+    let c: Array<f64, Ix2> = Array::zeros((2, 3));
+    assert_eq!(a.sylvester(&b, &c).err().unwrap(), MatrixEquationError::InconsistentDimensions);
+    assert_eq!(a.lyapunov(&b).err().unwrap(), MatrixEquationError::InconsistentDimensions);
+
+    let r: Array<f64, Ix2> = Array::zeros((3, 2));
+    assert_eq!(r.lyapunov(&c).err().unwrap(), MatrixEquationError::NotSquare);
+
+    let q: Array<f64, Ix2> = Array::eye(3);
+    assert_eq!(a.care(&c, &q, &b).err().unwrap(), MatrixEquationError::InconsistentDimensions);
+    assert_eq!(a.dare(&r, &q, &c).err().unwrap(), MatrixEquationError::NotSquare);
+}
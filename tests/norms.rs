@@ -0,0 +1,114 @@
+#[macro_use]
+extern crate linxal;
+
+#[macro_use]
+extern crate ndarray;
+
+use ndarray::{Array, Ix2, arr1, arr2};
+use linxal::types::{LinxalMatrix, LinxalScalar, Symmetric, c32, c64};
+use linxal::norms::{NormType, NormError, max_abs};
+
+fn check_norm(value: f64, truth: f64) {
+    assert_eq_within_tol!(arr1(&[value]), arr1(&[truth]), 1e-10);
+}
+
+#[test]
+fn norm_general() {
+    let m = arr2(&[[1.0f64, -2.0, 0.0],
+                   [3.0, 4.0, -6.0]]);
+
+    check_norm(m.norm(NormType::One).unwrap(), 6.0);
+    check_norm(m.norm(NormType::Infinity).unwrap(), 13.0);
+    check_norm(m.norm(NormType::Frobenius).unwrap(), 66.0f64.sqrt());
+    check_norm(m.norm(NormType::MaxAbs).unwrap(), 6.0);
+
+    // The layout of the matrix doesn't matter.
+    let mt = m.t();
+    check_norm(mt.norm(NormType::One).unwrap(), 13.0);
+    check_norm(mt.norm(NormType::Infinity).unwrap(), 6.0);
+}
+
+#[test]
+fn norm_singular_values() {
+    let m = arr2(&[[3.0f64, 0.0], [0.0, -4.0], [0.0, 0.0]]);
+
+    check_norm(m.norm(NormType::Spectral).unwrap(), 4.0);
+    check_norm(m.norm(NormType::Nuclear).unwrap(), 7.0);
+}
+
+#[test]
+fn norm_symmetric() {
+    // Only the upper portion is read.
+    let m = arr2(&[[2.0f64, -1.0], [100.0, -3.0]]);
+
+    check_norm(m.symmetric_norm(Symmetric::Upper, NormType::One).unwrap(), 4.0);
+    check_norm(m.symmetric_norm(Symmetric::Upper, NormType::MaxAbs).unwrap(), 3.0);
+
+    let full = arr2(&[[2.0f64, -1.0], [-1.0, -3.0]]);
+    let spectral = full.norm(NormType::Spectral).unwrap();
+    let nuclear = full.norm(NormType::Nuclear).unwrap();
+    check_norm(m.symmetric_norm(Symmetric::Upper, NormType::Spectral).unwrap(), spectral);
+    check_norm(m.symmetric_norm(Symmetric::Upper, NormType::Nuclear).unwrap(), nuclear);
+
+    let r = m.slice(s![.., ..1]).symmetric_norm(Symmetric::Upper, NormType::One);
+    assert_eq!(r.err().unwrap(), NormError::NotSquare);
+}
+
+#[test]
+fn norm_triangular() {
+    // Only the lower portion is read.
+    let m = arr2(&[[1.0f64, 100.0, 100.0],
+                   [-2.0, 3.0, 100.0]]);
+
+    check_norm(m.triangular_norm(Symmetric::Lower, NormType::One).unwrap(), 3.0);
+    check_norm(m.triangular_norm(Symmetric::Lower, NormType::Infinity).unwrap(), 5.0);
+
+    let lower = arr2(&[[1.0f64, 0.0, 0.0],
+                       [-2.0, 3.0, 0.0]]);
+    let spectral = lower.norm(NormType::Spectral).unwrap();
+    check_norm(m.triangular_norm(Symmetric::Lower, NormType::Spectral).unwrap(), spectral);
+}
+
+fn norm_identity_generic<T: LinxalScalar>() {
+    let n = 9;
+    let m: Array<T, Ix2> = Array::eye(n);
+
+    let truth = arr1(&[1.0.into(), 1.0.into(), 3.0.into(), 1.0.into(), 1.0.into(), 9.0.into()]);
+    let norms = arr1(&[m.norm(NormType::One).unwrap(),
+                       m.norm(NormType::Infinity).unwrap(),
+                       m.norm(NormType::Frobenius).unwrap(),
+                       m.norm(NormType::MaxAbs).unwrap(),
+                       m.norm(NormType::Spectral).unwrap(),
+                       m.norm(NormType::Nuclear).unwrap()]);
+    assert_eq_within_tol!(norms, truth, 1e-4.into());
+}
+
+#[test]
+fn norm_identity() {
+    norm_identity_generic::<f32>();
+    norm_identity_generic::<f64>();
+    norm_identity_generic::<c32>();
+    norm_identity_generic::<c64>();
+}
+
+#[test]
+fn norm_complex() {
+    let m = arr2(&[[c32::new(3.0, 4.0), c32::new(0.0, 1.0)]]);
+    let norm = m.norm(NormType::Infinity).unwrap();
+    assert!((norm - 6.0).abs() < 1e-5);
+}
+
+#[test]
+fn norm_empty() {
+    let m: Array<f32, Ix2> = Array::zeros((0, 3));
+    assert_eq!(m.norm(NormType::Frobenius).unwrap(), 0.0);
+}
+
+#[test]
+fn norm_nan() {
+    let m = arr2(&[[1.0f64, ::std::f64::NAN], [0.0, 1.0]]);
+    assert_eq!(m.norm(NormType::One).err().unwrap(), NormError::ContainsNaN);
+    assert_eq!(m.norm(NormType::MaxAbs).err().unwrap(), NormError::ContainsNaN);
+    assert!(max_abs(&m).is_nan());
+    assert_eq!(max_abs(&m.slice(s![.., ..1])), 1.0);
+}
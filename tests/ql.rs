@@ -0,0 +1,64 @@
+#[macro_use]
+extern crate linxal;
+#[macro_use]
+extern crate ndarray;
+extern crate num_traits;
+
+use std::cmp;
+use ndarray::{Array, Ix2, arr2};
+use num_traits::Zero;
+use linxal::types::{LinxalMatrix, LinxalScalar, c32, c64};
+
+fn check_ql<T: LinxalScalar>(m: &Array<T, Ix2>) {
+    let ql = m.ql().unwrap();
+    let (rows, cols) = m.dim();
+    let p = cmp::min(rows, cols);
+
+    let q = ql.q();
+    let l = ql.l();
+    assert_eq!(q.dim(), (rows, p));
+    assert_eq!(l.dim(), (p, cols));
+
+    // L is lower triangular, aligned with the bottom right corner.
+    for ((i, j), x) in l.indexed_iter() {
+        if j > i + cols - p {
+            assert_eq!(x.mag(), T::RealPart::zero());
+        }
+    }
+
+    // The columns of Q are orthonormal.
+    let eye: Array<T, Ix2> = Array::eye(p);
+    assert_eq_within_tol!(q.conj_t().dot(&q), eye, 1e-4.into());
+
+    assert_eq_within_tol!(ql.reconstruct(), m, 1e-4.into());
+
+    // The full Q is unitary, and ends with the columns of the thin Q.
+    let q_full = ql.qk(rows).unwrap();
+    let eye: Array<T, Ix2> = Array::eye(rows);
+    assert_eq_within_tol!(q_full.conj_t().dot(&q_full), eye, 1e-4.into());
+    assert_eq_within_tol!(q_full.slice(s![.., (rows - p) as isize..]), q, 1e-4.into());
+    assert!(ql.qk(rows + 1).is_err());
+
+    // Partial factors are the trailing columns of Q and rows of L.
+    assert_eq_within_tol!(ql.qk(1).unwrap(), q.slice(s![.., -1..]), 1e-4.into());
+    assert_eq_within_tol!(ql.lk(1).unwrap(), l.slice(s![-1.., ..]), 1e-4.into());
+    assert!(ql.lk(p + 1).is_err());
+}
+
+fn ql_generic<T: LinxalScalar>() {
+    let m: Array<T, Ix2> = arr2(&[[1.0f32, 2.0, 0.0, -1.0],
+                                  [0.0, 1.0, 3.0, 2.0],
+                                  [4.0, -2.0, 1.0, 1.0]])
+        .mapv(|x| T::from_real(x.into()));
+    check_ql(&m);
+    check_ql(&m.t().to_owned());
+    check_ql(&m.slice(s![.., ..3]).to_owned());
+}
+
+#[test]
+fn ql_factors() {
+    ql_generic::<f32>();
+    ql_generic::<f64>();
+    ql_generic::<c32>();
+    ql_generic::<c64>();
+}
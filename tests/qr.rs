@@ -4,10 +4,14 @@ extern crate linxal;
 #[macro_use]
 extern crate ndarray;
 extern crate num_traits;
+extern crate rand;
 
-use ndarray::{Array, ArrayBase, arr1, Data, Ix2};
-use linxal::factorization::{QRFactors};
-use linxal::types::{LinxalMatrix};
+use ndarray::{Array, ArrayBase, Axis, arr1, arr2, Data, Ix2};
+use std::cmp;
+use linxal::factorization::{QRFactors, QRPFactors, QRError};
+use linxal::generate::RandomGeneral;
+use rand::thread_rng;
+use linxal::types::{LinxalMatrix, LinxalScalar, Side, Symmetric, c32, c64};
 
 /// Check that all the properties of the qr factorization are
 /// reasonable.
@@ -92,3 +96,277 @@ fn qr_basic_linspace() {
     }
 
 }
+
+/// Check that the properties of the pivoted qr factorization are
+/// reasonable.
+fn check_qrp<D1: Data<Elem=f64>>(m: &ArrayBase<D1, Ix2>, qrp: &QRPFactors<f64>) {
+    let q = qrp.q();
+    let r = qrp.r();
+    let p = qrp.p();
+
+    assert_eq!(p.dim(), (m.cols(), m.cols()));
+
+    // A * P = Q * R
+    assert_eq_within_tol!(m.dot(&p), q.dot(&r), 1e-10);
+    assert_eq_within_tol!(qrp.reconstruct(), m, 1e-10);
+
+    // The diagonal of R is non-increasing in magnitude.
+    let d = r.diag();
+    for i in 1..d.len() {
+        assert!(d[i].abs() <= d[i - 1].abs() + 1e-10);
+    }
+
+    // Q is orthogonal
+    let qtq = q.t().dot(&q);
+    let eye: Array<f64, Ix2> = Array::eye(q.dim().1);
+    assert_eq_within_tol!(qtq, eye, 1e-10);
+}
+
+#[test]
+fn qrp_full_rank() {
+    let m = arr2(&[[1.0, 0.0, 5.0],
+                   [0.0, 2.0, 0.0],
+                   [1.0, 0.0, -5.0],
+                   [0.0, 1.0, 1.0]]);
+
+    let qrp = m.qr_pivoted().unwrap();
+    check_qrp(&m, &qrp);
+
+    // The largest column is chosen first.
+    assert_eq!(qrp.permutation()[0], 2);
+    assert_eq!(qrp.rank(None), 3);
+}
+
+#[test]
+fn qrp_rank_deficient() {
+    // The third column is the sum of the first two, and the last is
+    // a multiple of the first.
+    let m = arr2(&[[1.0, 2.0, 3.0, 2.0],
+                   [4.0, 5.0, 9.0, 8.0],
+                   [7.0, 8.0, 15.0, 14.0]]);
+
+    let qrp = m.qr_pivoted().unwrap();
+    check_qrp(&m, &qrp);
+    assert_eq!(qrp.rank(None), 2);
+
+    let qrp = m.t().qr_pivoted().unwrap();
+    check_qrp(&m.t(), &qrp);
+    assert_eq!(qrp.rank(None), 2);
+}
+
+/// Check that applying Q and Q^H matches multiplying by the explicit
+/// full Q.
+fn check_apply_q<T: LinxalScalar>(m: &Array<T, Ix2>) {
+    let qr = m.qr().unwrap();
+    let rows = m.rows();
+    let q = qr.qk(rows).unwrap();
+    let qh = q.t().mapv(|x| x.cj());
+
+    let c: Array<T, Ix2> = Array::linspace(1.0f32, 2.0, rows * 2)
+        .into_shape((rows, 2)).unwrap()
+        .mapv(|x| T::from_real(x.into()));
+    let tol = 1e-4.into();
+
+    assert_eq_within_tol!(qr.apply_q(&c, Side::Left).unwrap(), q.dot(&c), tol);
+    assert_eq_within_tol!(qr.apply_qt(&c, Side::Left).unwrap(), qh.dot(&c), tol);
+
+    let ct = c.t().to_owned();
+    assert_eq_within_tol!(qr.apply_q(&ct, Side::Right).unwrap(), ct.dot(&q), tol);
+    assert_eq_within_tol!(qr.apply_qt(&ct, Side::Right).unwrap(), ct.dot(&qh), tol);
+
+    // Q^H undoes Q.
+    let qc = qr.apply_q(&c, Side::Left).unwrap();
+    assert_eq_within_tol!(qr.apply_qt_into(qc, Side::Left).unwrap(), c, tol);
+
+    // The dimension of Q must match.
+    assert!(qr.apply_q(&ct, Side::Left).is_err());
+    assert!(qr.apply_qt(&c, Side::Right).is_err());
+}
+
+fn apply_q_generic<T: LinxalScalar>() {
+    let m: Array<T, Ix2> = arr2(&[[1.0f32, 2.0, 0.0],
+                                  [-1.0, 3.0, 1.0],
+                                  [4.0, 0.0, 2.0],
+                                  [0.5, 1.0, -1.0]])
+        .mapv(|x| T::from_real(x.into()));
+    check_apply_q(&m);
+    check_apply_q(&m.t().to_owned());
+}
+
+#[test]
+fn qr_apply_q() {
+    apply_q_generic::<f32>();
+    apply_q_generic::<f64>();
+    apply_q_generic::<c32>();
+    apply_q_generic::<c64>();
+}
+
+#[test]
+fn qr_apply_q_complex() {
+    let m = arr2(&[[c64::new(1.0, 1.0), c64::new(0.0, 2.0)],
+                   [c64::new(-1.0, 0.0), c64::new(3.0, -1.0)],
+                   [c64::new(0.0, 1.0), c64::new(1.0, 0.0)]]);
+    check_apply_q(&m);
+}
+
+fn least_squares_generic<T: LinxalScalar>() {
+    let m: Array<T, Ix2> = arr2(&[[1.0f32, 1.0],
+                                  [1.0, 2.0],
+                                  [1.0, 3.0],
+                                  [1.0, 4.0]])
+        .mapv(|x| T::from_real(x.into()));
+    let b: Array<T, Ix2> = arr2(&[[6.0f32, 1.0],
+                                  [5.0, 2.0],
+                                  [7.0, 3.0],
+                                  [10.0, 4.0]])
+        .mapv(|x| T::from_real(x.into()));
+
+    let qr = m.qr().unwrap();
+    let x = qr.solve_least_squares_multi(&b).unwrap();
+    let truth = m.multi_least_squares(&b, None).unwrap().solution;
+    assert_eq_within_tol!(x, truth, 1e-4.into());
+
+    // Each column can be solved independently with the same factors.
+    let x0 = qr.solve_least_squares(&b.column(0)).unwrap();
+    assert_eq_within_tol!(x0, truth.column(0), 1e-4.into());
+
+    // The transpose is underdetermined.
+    let qr_t = m.t().qr().unwrap();
+    assert_eq!(qr_t.solve_least_squares_multi(&b.slice(s![..2, ..])).unwrap_err(),
+               QRError::Underdetermined);
+}
+
+#[test]
+fn qr_least_squares() {
+    least_squares_generic::<f32>();
+    least_squares_generic::<f64>();
+    least_squares_generic::<c32>();
+    least_squares_generic::<c64>();
+}
+
+#[test]
+fn qr_least_squares_rank_deficient() {
+    let m = arr2(&[[1.0, 0.0],
+                   [0.0, 0.0],
+                   [0.0, 0.0]]);
+    let b = arr1(&[1.0, 2.0, 3.0]);
+
+    let qr = m.qr().unwrap();
+    assert_eq!(qr.solve_least_squares(&b).unwrap_err(), QRError::RankDeficient);
+}
+
+/// Check that the updated factorization matches a fresh factorization
+/// of the modified matrix `a`.
+fn check_updated_qr<T: LinxalScalar>(qr: &QRFactors<T>, a: &Array<T, Ix2>) {
+    let (m, n) = a.dim();
+    let p = cmp::min(m, n);
+    assert_eq!((qr.rows(), qr.cols()), (m, n));
+
+    let q = qr.q();
+    let r = qr.r();
+    assert_eq!((q.dim(), r.dim()), ((m, p), (p, n)));
+    let eye: Array<T, Ix2> = Array::eye(p);
+    assert_eq_within_tol!(q.conj_t().dot(&q), eye, 1e-4.into());
+    assert!(r.is_triangular(Symmetric::Upper, None));
+    assert_eq_within_tol!(qr.reconstruct(), a, 1e-4.into());
+
+    // R is unique up to the phases of its rows.
+    let r_fresh = a.qr().unwrap().r();
+    for i in 0..p {
+        assert_eq_within_tol!(arr1(&[r[(i, i)].mag()]),
+                              arr1(&[r_fresh[(i, i)].mag()]),
+                              1e-4.into());
+    }
+
+    // The full Q, and its application, extend the thin factors.
+    let q_full = qr.qk(m).unwrap();
+    let eye: Array<T, Ix2> = Array::eye(m);
+    assert_eq_within_tol!(q_full.conj_t().dot(&q_full), eye, 1e-4.into());
+    assert_eq_within_tol!(q_full.slice(s![.., ..p as isize]), q, 1e-4.into());
+    assert_eq_within_tol!(qr.apply_q(&eye, Side::Left).unwrap(), q_full, 1e-4.into());
+}
+
+fn qr_update_generic<T: LinxalScalar>(m: usize, n: usize) {
+    let mut rng = thread_rng();
+    let mut a: Array<T, Ix2> = RandomGeneral::new(m, n, &mut rng).generate().unwrap();
+    let mut qr = a.qr().unwrap();
+    check_updated_qr(&qr, &a);
+
+    // Append an observation row at the bottom.
+    let row: Array<T, Ix2> = RandomGeneral::new(1, n, &mut rng).generate().unwrap();
+    qr.insert_row(m, &row.row(0)).unwrap();
+    a = stack![Axis(0), a, row];
+    check_updated_qr(&qr, &a);
+
+    // Delete the first row.
+    qr.delete_row(0).unwrap();
+    a = a.slice(s![1.., ..]).to_owned();
+    check_updated_qr(&qr, &a);
+
+    // Insert a column in the middle.
+    let k = n / 2;
+    let col: Array<T, Ix2> = RandomGeneral::new(m, 1, &mut rng).generate().unwrap();
+    qr.insert_col(k, &col.column(0)).unwrap();
+    a = stack![Axis(1), a.slice(s![.., ..k as isize]), col, a.slice(s![.., k as isize..])];
+    check_updated_qr(&qr, &a);
+
+    // Delete the last column.
+    qr.delete_col(n).unwrap();
+    a = a.slice(s![.., ..n as isize]).to_owned();
+    check_updated_qr(&qr, &a);
+
+    // Apply a rank-one update.
+    let uv: Array<T, Ix2> = RandomGeneral::new(m + n, 1, &mut rng).generate().unwrap();
+    let u = uv.slice(s![..m as isize, ..]);
+    let v = uv.slice(s![m as isize.., ..]);
+    qr.rank_one_update(&u.column(0), &v.column(0)).unwrap();
+    a = a + u.dot(&v.conj_t());
+    check_updated_qr(&qr, &a);
+
+    // Least squares still works with the updated factors.
+    if m >= n {
+        let b: Array<T, Ix2> = RandomGeneral::new(m, 2, &mut rng).generate().unwrap();
+        let x = qr.solve_least_squares_multi(&b).unwrap();
+        let truth = a.multi_least_squares(&b, None).unwrap().solution;
+        assert_eq_within_tol!(x, truth, 1e-3.into());
+    }
+}
+
+#[test]
+fn qr_update() {
+    for &(m, n) in &[(6, 4), (4, 4), (3, 5)] {
+        qr_update_generic::<f32>(m, n);
+        qr_update_generic::<f64>(m, n);
+        qr_update_generic::<c32>(m, n);
+        qr_update_generic::<c64>(m, n);
+    }
+}
+
+#[test]
+fn qr_update_rank_deficient() {
+    // The inserted column is already in the range of Q, so Q is
+    // extended by an arbitrary orthogonal direction.
+    let mut a = arr2(&[[1.0f64, 2.0],
+                       [3.0, 4.0],
+                       [5.0, 6.0],
+                       [7.0, 8.0]]);
+    let mut qr = a.qr().unwrap();
+    let col = a.column(0).to_owned();
+    qr.insert_col(2, &col).unwrap();
+    a = stack![Axis(1), a, col.into_shape((4, 1)).unwrap()];
+    check_updated_qr(&qr, &a);
+    assert!(qr.r()[(2, 2)].abs() < 1e-10);
+}
+
+#[test]
+fn qr_update_bad_dimensions() {
+    let m = arr2(&[[1.0, 2.0],
+                   [3.0, 4.0],
+                   [5.0, 6.0]]);
+    let mut qr = m.qr().unwrap();
+    assert_eq!(qr.insert_row(4, &arr1(&[1.0, 2.0])).unwrap_err(), QRError::InconsistentDimensions);
+    assert_eq!(qr.insert_row(0, &arr1(&[1.0])).unwrap_err(), QRError::InconsistentDimensions);
+    assert_eq!(qr.delete_row(3).unwrap_err(), QRError::InconsistentDimensions);
+    assert_eq!(qr.insert_col(0, &arr1(&[1.0, 2.0])).unwrap_err(), QRError::InconsistentDimensions);
+    assert_eq!(qr.delete_col(2).unwrap_err(), QRError::InconsistentDimensions);
+}
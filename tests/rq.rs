@@ -0,0 +1,89 @@
+#[macro_use]
+extern crate linxal;
+#[macro_use]
+extern crate ndarray;
+extern crate num_traits;
+
+use std::cmp;
+use ndarray::{Array, Ix2, arr2};
+use num_traits::Zero;
+use linxal::types::{LinxalMatrix, LinxalScalar, c32, c64};
+
+fn check_rq<T: LinxalScalar>(m: &Array<T, Ix2>) {
+    let rq = m.rq().unwrap();
+    let (rows, cols) = m.dim();
+    let p = cmp::min(rows, cols);
+
+    let r = rq.r();
+    let q = rq.q();
+    assert_eq!(r.dim(), (rows, p));
+    assert_eq!(q.dim(), (p, cols));
+
+    // R is upper triangular, aligned with the bottom right corner.
+    for ((i, j), x) in r.indexed_iter() {
+        if j + rows < i + p {
+            assert_eq!(x.mag(), T::RealPart::zero());
+        }
+    }
+
+    // The rows of Q are orthonormal.
+    let eye: Array<T, Ix2> = Array::eye(p);
+    assert_eq_within_tol!(q.dot(&q.conj_t()), eye, 1e-4.into());
+
+    assert_eq_within_tol!(rq.reconstruct(), m, 1e-4.into());
+
+    // The full Q is unitary, and ends with the rows of the thin Q.
+    let q_full = rq.qk(cols).unwrap();
+    let eye: Array<T, Ix2> = Array::eye(cols);
+    assert_eq_within_tol!(q_full.dot(&q_full.conj_t()), eye, 1e-4.into());
+    assert_eq_within_tol!(q_full.slice(s![(cols - p) as isize.., ..]), q, 1e-4.into());
+    assert!(rq.qk(cols + 1).is_err());
+
+    // Partial factors are the trailing rows of Q and columns of R.
+    assert_eq_within_tol!(rq.qk(1).unwrap(), q.slice(s![-1.., ..]), 1e-4.into());
+    assert_eq_within_tol!(rq.rk(1).unwrap(), r.slice(s![.., -1..]), 1e-4.into());
+    assert!(rq.rk(p + 1).is_err());
+}
+
+fn rq_generic<T: LinxalScalar>() {
+    let m: Array<T, Ix2> = arr2(&[[1.0f32, 2.0, 0.0, -1.0],
+                                  [0.0, 1.0, 3.0, 2.0],
+                                  [4.0, -2.0, 1.0, 1.0]])
+        .mapv(|x| T::from_real(x.into()));
+    check_rq(&m);
+    check_rq(&m.t().to_owned());
+    check_rq(&m.slice(s![.., ..3]).to_owned());
+}
+
+#[test]
+fn rq_factors() {
+    rq_generic::<f32>();
+    rq_generic::<f64>();
+    rq_generic::<c32>();
+    rq_generic::<c64>();
+}
+
+#[test]
+fn rq_camera() {
+    // Decompose a camera matrix into intrinsics and a rotation.
+    let k = arr2(&[[500.0f64, 0.0, 320.0],
+                   [0.0, 500.0, 240.0],
+                   [0.0, 0.0, 1.0]]);
+    let rot = arr2(&[[0.0f64, -1.0, 0.0],
+                     [1.0, 0.0, 0.0],
+                     [0.0, 0.0, 1.0]]);
+    let m = k.dot(&rot);
+
+    let rq = m.rq().unwrap();
+    let r = rq.r();
+    let q = rq.q();
+
+    // The factorization is unique up to the signs of the diagonal of
+    // R, which flip the columns of R and the rows of Q.
+    for i in 0..3 {
+        for j in 0..3 {
+            assert!((r[(i, j)] * r[(j, j)].signum() - k[(i, j)]).abs() < 1e-8);
+            assert!((q[(i, j)] * r[(i, i)].signum() - rot[(i, j)]).abs() < 1e-8);
+        }
+    }
+}
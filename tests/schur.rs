@@ -0,0 +1,90 @@
+#[macro_use]
+extern crate linxal;
+#[macro_use]
+extern crate ndarray;
+extern crate rand;
+
+use linxal::types::{LinxalMatrix, LinxalScalar, LinxalImplScalar, c32, c64};
+use linxal::types::error::EigenError;
+use linxal::generate::RandomGeneral;
+use ndarray::{Array, Ix2, arr1, arr2};
+use rand::thread_rng;
+
+fn schur_reconstruct_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for n in 0..10 {
+        let a: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let schur = a.schur().unwrap();
+
+        assert_eq_within_tol!(schur.reconstruct(), a, 1e-3.into());
+        assert_eq_within_tol!(schur.z().conj_t().dot(schur.z()), Array::eye(n), 1e-3.into());
+
+        // `T` is upper quasi-triangular.
+        let t = schur.t();
+        for i in 0..n {
+            for j in 0..i.saturating_sub(1) {
+                assert!(t[(i, j)].is_zero());
+            }
+        }
+    }
+}
+
+#[test]
+fn schur_reconstruct() {
+    schur_reconstruct_generic::<f32>();
+    schur_reconstruct_generic::<f64>();
+    schur_reconstruct_generic::<c32>();
+    schur_reconstruct_generic::<c64>();
+}
+
+fn schur_reorder_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for n in 1..10 {
+        let a: Array<T, Ix2> = RandomGeneral::new(n, n, &mut rng).generate().unwrap();
+        let mut schur = a.schur().unwrap();
+
+        let stable = schur.values().iter().filter(|v| v.re() < 0.0.into()).count();
+        let k = schur.reorder(|v| v.re() < 0.0.into()).unwrap();
+        assert_eq!(k, stable);
+
+        for (i, v) in schur.values().iter().enumerate() {
+            assert_eq!(i < k, v.re() < 0.0.into());
+        }
+        assert_eq_within_tol!(schur.reconstruct(), a, 1e-3.into());
+
+        // The leading columns of `Z` span an invariant subspace.
+        let z1 = schur.z().slice(s![.., ..k as isize]);
+        let t11 = schur.t().slice(s![..k as isize, ..k as isize]);
+        assert_eq_within_tol!(a.dot(&z1), z1.dot(&t11), 1e-3.into());
+    }
+}
+
+#[test]
+fn schur_reorder() {
+    schur_reorder_generic::<f32>();
+    schur_reorder_generic::<f64>();
+    schur_reorder_generic::<c32>();
+    schur_reorder_generic::<c64>();
+}
+
+#[test]
+fn schur_conjugate_pair() {
+    // Eigenvalues are 1 and -1 +/- 2i.
+    let a = arr2(&[[1.0f64, 0.0, 0.0],
+                   [0.0, -1.0, 2.0],
+                   [0.0, -2.0, -1.0]]);
+    let mut schur = a.schur().unwrap();
+
+    // Selecting either eigenvalue of a pair selects both.
+    let k = schur.reorder(|v| v.im > 0.0).unwrap();
+    assert_eq!(k, 2);
+    let values = arr1(&[c64::new(-1.0, 2.0), c64::new(-1.0, -2.0), c64::new(1.0, 0.0)]);
+    assert_eq_within_tol!(schur.values(), values, 1e-6);
+    assert_eq_within_tol!(schur.reconstruct(), a, 1e-6);
+}
+
+#[test]
+fn schur_nonsquare() {
+    let a: Array<f32, Ix2> = Array::zeros((3, 2));
+    assert_eq!(a.schur().err().unwrap(), EigenError::NotSquare);
+}
@@ -58,3 +58,62 @@ pub fn solve_linear_vector_view() {
         assert_eq_within_tol!(&values, &xv, 1e-5);
     }
 }
+
+
+#[test]
+pub fn solve_linear_expert_scaled() {
+    // The rows of `a` differ wildly in scale.
+    let a: Array<f64, Ix2> = Array::from_shape_vec((2, 2), vec![1e10, 2e10, 3e-10, 4e-10]).unwrap();
+    let b: Array<f64, Ix1> = Array::from_vec(vec![3e10, 7e-10]);
+
+    let sol = a.solve_linear_expert(&b).unwrap();
+
+    let truth = Array::from_vec(vec![1.0, 1.0]);
+    assert_eq_within_tol!(&sol.solution, &truth, 1e-10);
+
+    assert!(sol.row_scale.is_some());
+    assert!(!sol.singular_to_working_precision);
+    assert!(sol.rcond > 1e-3);
+    assert_eq!(sol.forward_error.dim(), 1);
+    assert_eq!(sol.backward_error.dim(), 1);
+    assert!(sol.forward_error[0] < 1e-10);
+}
+
+#[test]
+pub fn solve_linear_expert_matrix() {
+    let a: Array<f32, Ix2> = Array::from_shape_vec((2, 2), vec![1.0, 2.0, 3.0, 4.0]).unwrap();
+    let b_vec = vec![3.0, -1.0, 2.0, 7.0, -1.0, 6.0];
+    let b: Array<f32, Ix2> = Array::from_shape_vec((2, 3), b_vec).unwrap();
+
+    let sol = a.solve_multi_linear_expert(&b).unwrap();
+
+    let truth_vec = vec![1.0, 1.0, 2.0, 1.0, -1.0, 0.0];
+    let truth = Array::from_shape_vec((2, 3), truth_vec).unwrap();
+    assert_eq_within_tol!(&sol.solution, &truth, 1e-5);
+    assert_eq!(sol.forward_error.dim(), 3);
+    assert_eq!(sol.backward_error.dim(), 3);
+}
+
+#[test]
+pub fn solve_linear_expert_singular() {
+    // Nonsingular, but the condition number is roughly `4 / eps`.
+    let a: Array<f64, Ix2> = Array::from_shape_vec((2, 2), vec![1.0, 1.0, 1.0, 1.0 + f64::EPSILON]).unwrap();
+    let b: Array<f64, Ix1> = Array::from_vec(vec![1.0, 1.0]);
+
+    let sol = a.solve_linear_expert(&b).unwrap();
+    assert!(sol.singular_to_working_precision);
+    assert!(sol.rcond < f64::EPSILON);
+}
+
+#[test]
+pub fn solve_linear_column_major() {
+    let a: Array<f32, Ix2> = Array::from_shape_vec((2, 2), vec![1.0, 3.0, 2.0, 4.0]).unwrap().reversed_axes();
+    let b: Array<f32, Ix1> = Array::from_vec(vec![3.0, 7.0]);
+
+    let x = a.solve_linear(&b).unwrap();
+    let truth = Array::from_vec(vec![1.0, 1.0]);
+    assert_eq_within_tol!(&x, &truth, 1e-5);
+
+    let sol = a.solve_linear_expert(&b).unwrap();
+    assert_eq_within_tol!(&sol.solution, &truth, 1e-5);
+}
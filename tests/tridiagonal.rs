@@ -0,0 +1,52 @@
+#[macro_use]
+extern crate linxal;
+extern crate ndarray;
+extern crate rand;
+
+use linxal::types::{LinxalMatrix, LinxalScalar, Symmetric, c32, c64};
+use linxal::types::error::ReductionError;
+use linxal::generate::RandomSymmetric;
+use ndarray::{Array, Ix2};
+use rand::thread_rng;
+
+fn tridiagonal_reconstruct_generic<T: LinxalScalar>() {
+    let mut rng = thread_rng();
+    for n in 0..10 {
+        let a: Array<T, Ix2> = RandomSymmetric::new(n, &mut rng).generate().unwrap();
+
+        for &uplo in &[Symmetric::Upper, Symmetric::Lower] {
+            let tri = a.tridiagonal(uplo).unwrap();
+            assert_eq!(tri.diag().dim(), n);
+            assert_eq!(tri.off_diag().dim(), n.saturating_sub(1));
+
+            assert_eq_within_tol!(tri.reconstruct(), a, 1e-3.into());
+            assert_eq_within_tol!(tri.q().conj_t().dot(tri.q()), Array::eye(n), 1e-3.into());
+        }
+    }
+}
+
+#[test]
+fn tridiagonal_reconstruct() {
+    tridiagonal_reconstruct_generic::<f32>();
+    tridiagonal_reconstruct_generic::<f64>();
+    tridiagonal_reconstruct_generic::<c32>();
+    tridiagonal_reconstruct_generic::<c64>();
+}
+
+#[test]
+fn tridiagonal_eigenvalues() {
+    let mut rng = thread_rng();
+    let a: Array<f64, Ix2> = RandomSymmetric::new(6, &mut rng).generate().unwrap();
+    let tri = a.tridiagonal(Symmetric::Upper).unwrap();
+
+    // The reduction is a similarity transform.
+    let ev_a = a.symmetric_eigenvalues(Symmetric::Upper).unwrap();
+    let ev_t = tri.t().symmetric_eigenvalues(Symmetric::Upper).unwrap();
+    assert_eq_within_tol!(ev_a, ev_t, 1e-8);
+}
+
+#[test]
+fn tridiagonal_nonsquare() {
+    let a: Array<f32, Ix2> = Array::zeros((3, 2));
+    assert_eq!(a.tridiagonal(Symmetric::Upper).err().unwrap(), ReductionError::NotSquare);
+}